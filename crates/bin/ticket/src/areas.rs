@@ -0,0 +1,119 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `areas` subcommand.
+//!
+//! Tags a change with the areas of the code base it touches, ex: `ui` or `backend`, for routing reviews and notifications
+//! in a CODEOWNERS-like way. Areas are configured in the `[areas]` table of the configuration file, matched against the
+//! staged paths and the commit scope.
+
+use crate::{Error, config::Area, git};
+use globset::{Glob, GlobBuilder};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+};
+
+/// Prints the areas touched by the staged changes and scope, one per line in alphabetical order.
+///
+/// # Arguments
+/// * `areas` - The configured areas.
+/// * `scope` - The scope of the commit, if any.
+/// * `out` - Where the areas are written.
+///
+/// # Returns
+/// * `Ok(())` if the areas were printed.
+/// * `Err(Error)` if git failed.
+pub(crate) fn run(areas: &BTreeMap<String, Area>, scope: Option<&str>, out: &mut impl Write) -> Result<(), Error> {
+    let changes = git::staged_changes()?;
+    let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+
+    for tag in tags(areas, scope, &paths) {
+        writeln!(out, "{tag}")?;
+    }
+
+    Ok(())
+}
+
+/// Returns the areas with a path pattern matching any of the paths, or listing the scope.
+///
+/// Scopes are compared case-insensitively. Path patterns are globs where `*` doesn't cross directories and `**` does.
+pub(crate) fn tags(areas: &BTreeMap<String, Area>, scope: Option<&str>, paths: &[&str]) -> BTreeSet<String> {
+    areas
+        .iter()
+        .filter(|(_, area)| {
+            scope.is_some_and(|scope| area.scopes.iter().any(|s| s.eq_ignore_ascii_case(scope)))
+                || area.paths.iter().filter_map(|p| glob(p).ok()).any(|g| {
+                    let matcher = g.compile_matcher();
+                    paths.iter().any(|path| matcher.is_match(path))
+                })
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// Builds the glob for a path pattern.
+///
+/// # Returns
+/// * `Ok(Glob)` if the pattern is valid.
+/// * `Err(globset::Error)` otherwise.
+pub(crate) fn glob(pattern: &str) -> Result<Glob, globset::Error> {
+    GlobBuilder::new(pattern).literal_separator(true).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn areas() -> BTreeMap<String, Area> {
+        BTreeMap::from([
+            (
+                "ui".to_string(),
+                Area {
+                    paths: vec!["web/**".into(), "*.css".into()],
+                    scopes: vec!["ui".into(), "web".into()],
+                },
+            ),
+            (
+                "backend".to_string(),
+                Area {
+                    paths: vec!["server/**".into()],
+                    scopes: vec!["api".into()],
+                },
+            ),
+            (
+                "docs".to_string(),
+                Area {
+                    paths: vec!["docs/*.md".into()],
+                    scopes: vec![],
+                },
+            ),
+        ])
+    }
+
+    #[rstest]
+    #[case::nothing(None, &[], &[])]
+    #[case::path(None, &["web/src/app.ts"], &["ui"])]
+    #[case::scope(Some("api"), &[], &["backend"])]
+    #[case::scope_case(Some("Web"), &[], &["ui"])]
+    #[case::scope_and_path(Some("api"), &["web/index.html"], &["backend", "ui"])]
+    #[case::single_star_does_not_cross_directories(None, &["docs/user/guide.md", "themes/dark.css"], &[])]
+    #[case::single_star(None, &["docs/guide.md", "main.css"], &["docs", "ui"])]
+    #[case::unmapped(Some("parser"), &["src/lib.rs"], &[])]
+    fn test_tags_areas(#[case] scope: Option<&str>, #[case] paths: &[&str], #[case] expect: &[&str]) {
+        let expect: BTreeSet<String> = expect.iter().map(ToString::to_string).collect();
+        assert_eq!(expect, tags(&areas(), scope, paths));
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Variables captured from branch names.
+//!
+//! The branch pattern can name its capture groups, ex: `(?<type>\w+)/(?<ticket>[A-Z]+-\d+)/(?<slug>.+)` for
+//! `feature/PROJ-123/short-desc`, and templates reference them as `{name}`. `{ticket}` is always available: it is the
+//! `ticket` capture when the pattern has one, the whole match otherwise.
+
+use regex::{Captures, Regex};
+use regex_syntax::ast::{Ast, GroupKind, Span, parse::Parser};
+use std::{collections::BTreeMap, sync::LazyLock};
+
+/// The variable holding the ticket.
+pub(crate) const TICKET: &str = "ticket";
+
+/// The variable holding the commit type, when the branch pattern captures one.
+pub(crate) const TYPE: &str = "type";
+
+/// A reference to a variable in a template, ex: `{ticket}`.
+static VARIABLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{([A-Za-z_][A-Za-z0-9_]*)\}").expect("should have compiled the variable pattern"));
+
+/// The values captured by a match of the branch pattern, by variable name.
+pub(crate) type Variables = BTreeMap<String, String>;
+
+/// Returns the variables templates can reference with the branch pattern: `ticket` then its other named captures.
+pub(crate) fn names(pattern: &Regex) -> Vec<&str> {
+    let mut names = vec![TICKET];
+    names.extend(pattern.capture_names().flatten().filter(|name| *name != TICKET));
+    names
+}
+
+/// Returns the variables of each match of the branch pattern, in the order they appear, skipping the matches of a
+/// ticket already found and those without a ticket.
+///
+/// A named capture that doesn't take part in a match is empty.
+pub(crate) fn variables(pattern: &Regex, branch: &str) -> Vec<Variables> {
+    let mut found: Vec<Variables> = Vec::new();
+
+    for captures in pattern.captures_iter(branch) {
+        let variables = capture(pattern, &captures);
+        if !variables[TICKET].is_empty() && !found.iter().any(|v| v[TICKET] == variables[TICKET]) {
+            found.push(variables);
+        }
+    }
+
+    found
+}
+
+/// Matches a ticket given on its own, ex: from the `--ticket` flag, against the part of the branch pattern it captures.
+///
+/// The ticket must match the `ticket` capture whole when the pattern has one, the whole pattern otherwise.
+///
+/// # Returns
+/// * `Some(Variables)` with the ticket, the other named captures being empty.
+/// * `None` if the ticket doesn't match.
+pub(crate) fn ticket(pattern: &Regex, ticket: &str) -> Option<Variables> {
+    let ast = Parser::new().parse(pattern.as_str()).ok();
+    let source = ast
+        .as_ref()
+        .and_then(ticket_capture)
+        .map_or(pattern.as_str(), |span| &pattern.as_str()[span.start.offset..span.end.offset]);
+    let whole = Regex::new(&format!("^(?:{source})$")).ok()?;
+    if !whole.is_match(ticket) {
+        return None;
+    }
+
+    let mut variables: Variables = pattern.capture_names().flatten().map(|name| (name.to_string(), String::new())).collect();
+    variables.insert(TICKET.into(), ticket.to_string());
+    Some(variables)
+}
+
+/// Checks that a template only references the given variables.
+///
+/// # Returns
+/// * `Ok(())` if every variable the template references is available.
+/// * `Err(String)` naming the first unknown variable and listing the available ones.
+pub(crate) fn validate(template: &str, available: &[&str]) -> Result<(), String> {
+    match VARIABLE.captures_iter(template).map(|c| c.extract::<1>().1[0]).find(|name| !available.contains(name)) {
+        Some(name) => {
+            let available = available.iter().map(|name| format!("`{{{name}}}`")).collect::<Vec<_>>().join(", ");
+            Err(format!("references unknown variable `{{{name}}}`, available variables are {available}"))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Replaces the variables the template references with their values, leaving unknown variables as they are.
+pub(crate) fn substitute(template: &str, variables: &Variables) -> String {
+    VARIABLE
+        .replace_all(template, |c: &Captures| variables.get(&c[1]).cloned().unwrap_or_else(|| c[0].to_string()))
+        .into_owned()
+}
+
+/// Finds the `ticket` capture group in the branch pattern.
+///
+/// # Returns
+/// The span of the group's expression in the pattern, without its parentheses and name, if it has one.
+fn ticket_capture(ast: &Ast) -> Option<&Span> {
+    match ast {
+        Ast::Group(group) => match &group.kind {
+            GroupKind::CaptureName { name, .. } if name.name == TICKET => Some(group.ast.span()),
+            _ => ticket_capture(&group.ast),
+        },
+        Ast::Repetition(repetition) => ticket_capture(&repetition.ast),
+        Ast::Alternation(alternation) => alternation.asts.iter().find_map(ticket_capture),
+        Ast::Concat(concat) => concat.asts.iter().find_map(ticket_capture),
+        _ => None,
+    }
+}
+
+/// Collects the named captures of a match, with `ticket` falling back to the whole match.
+fn capture(pattern: &Regex, captures: &Captures) -> Variables {
+    let mut variables: Variables = pattern
+        .capture_names()
+        .flatten()
+        .map(|name| (name.to_string(), captures.name(name).map_or("", |m| m.as_str()).to_string()))
+        .collect();
+    variables.entry(TICKET.into()).or_insert_with(|| captures[0].to_string());
+
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    const NAMED: &str = r"(?<type>[a-z]+)/(?<ticket>[A-Z]+-\d+)(?:/(?<slug>[a-z-]+))?";
+
+    fn vars(pairs: &[(&str, &str)]) -> Variables {
+        pairs.iter().map(|(name, value)| ((*name).to_string(), (*value).to_string())).collect()
+    }
+
+    #[rstest]
+    #[case::whole_match(r"[A-Z]+-\d+", "feature/PROJ-1+PROJ-2", vec![vars(&[("ticket", "PROJ-1")]), vars(&[("ticket", "PROJ-2")])])]
+    #[case::repeated(r"[A-Z]+-\d+", "PROJ-1/PROJ-1-follow-up", vec![vars(&[("ticket", "PROJ-1")])])]
+    #[case::named(NAMED, "feature/PROJ-123/short-desc", vec![vars(&[("slug", "short-desc"), ("ticket", "PROJ-123"), ("type", "feature")])])]
+    #[case::missing_capture(NAMED, "fix/PROJ-4", vec![vars(&[("slug", ""), ("ticket", "PROJ-4"), ("type", "fix")])])]
+    #[case::unnamed_group(r"(PROJ)-\d+", "PROJ-5", vec![vars(&[("ticket", "PROJ-5")])])]
+    #[case::none(NAMED, "main", vec![])]
+    fn test_captures_variables(#[case] pattern: &str, #[case] branch: &str, #[case] expect: Vec<Variables>) {
+        let pattern = Regex::new(pattern).expect("should have compiled the pattern");
+        assert_eq!(expect, variables(&pattern, branch));
+    }
+
+    #[rstest]
+    #[case::whole_pattern(r"[A-Z]+-\d+", "PROJ-1", Some(vars(&[("ticket", "PROJ-1")])))]
+    #[case::named(NAMED, "PROJ-1", Some(vars(&[("slug", ""), ("ticket", "PROJ-1"), ("type", "")])))]
+    #[case::nested(r"(?:(?<type>[a-z]+)/)?(?:(?<ticket>[A-Z]+-\d+)|none)", "PROJ-1", Some(vars(&[("ticket", "PROJ-1"), ("type", "")])))]
+    #[case::whole_branch(NAMED, "feature/PROJ-1", None)]
+    #[case::partial(r"[A-Z]+-\d+", "PROJ-1 and more", None)]
+    #[case::invalid(NAMED, "proj 1", None)]
+    fn test_matches_ticket(#[case] pattern: &str, #[case] given: &str, #[case] expect: Option<Variables>) {
+        let pattern = Regex::new(pattern).expect("should have compiled the pattern");
+        assert_eq!(expect, ticket(&pattern, given));
+    }
+
+    #[rstest]
+    #[case::unnamed(r"[A-Z]+-\d+", vec!["ticket"])]
+    #[case::named(NAMED, vec!["ticket", "type", "slug"])]
+    fn test_names_available_variables(#[case] pattern: &str, #[case] expect: Vec<&str>) {
+        let pattern = Regex::new(pattern).expect("should have compiled the pattern");
+        assert_eq!(expect, names(&pattern));
+    }
+
+    #[rstest]
+    #[case::known("{ticket} ({slug})", Ok(()))]
+    #[case::no_variables("PROJ", Ok(()))]
+    #[case::not_a_variable("{ticket-id}", Ok(()))]
+    #[case::unknown("{ticket} {scope}", Err("references unknown variable `{scope}`, available variables are `{ticket}`, `{slug}`".into()))]
+    fn test_validates_template(#[case] template: &str, #[case] expect: Result<(), String>) {
+        assert_eq!(expect, validate(template, &["ticket", "slug"]));
+    }
+
+    #[rstest]
+    #[case::ticket("{ticket}", "PROJ-1")]
+    #[case::several("{ticket}: {slug}", "PROJ-1: short-desc")]
+    #[case::unknown("{ticket} {scope}", "PROJ-1 {scope}")]
+    #[case::literal("see {ticket", "see {ticket")]
+    fn test_substitutes_variables(#[case] template: &str, #[case] expect: &str) {
+        assert_eq!(expect, substitute(template, &vars(&[("ticket", "PROJ-1"), ("slug", "short-desc")])));
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `check` subcommand.
+//!
+//! Validates a commit message against the conventional commits specification and the lint rules the tool enforces.
+//! Problems that can be fixed automatically can be printed as a unified diff the user can apply. For integration with
+//! other tools, a JSON array of messages can be checked at once, reporting the problems of each as JSON. Whole revision
+//! ranges can be checked too, optionally verifying that revert commits reference a commit of their history.
+
+use crate::{Error, config::Config, git};
+use conventional_commit::{
+    errors::{ConventionalError, Errors},
+    lint::{LintOptions, ScopePolicy},
+    model::{Commit, ValidationError},
+};
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+/// The length SHAs are abbreviated to when reporting the problems of a range.
+const SHORT_SHA_LENGTH: usize = 7;
+
+/// The name the message is shown under in diffs when it is read from standard input.
+const STDIN_NAME: &str = "message";
+
+/// The lint rules enforced on top of the specification and the configured rules.
+const LINT_OPTIONS: LintOptions = LintOptions {
+    types: None,
+    max_description_length: None,
+    require_breaking_description: false,
+    forbid_trailing_period: true,
+    max_errors: None,
+    scope_policy: ScopePolicy::Optional,
+};
+
+/// A message to check, read from the JSON input, ex: `{"message": "feat: add lexer"}`.
+#[derive(Debug, Deserialize)]
+struct Input {
+    message: String,
+}
+
+/// The result of checking one message, written to the JSON output.
+#[derive(Debug, PartialEq, Serialize)]
+struct Outcome {
+    /// Whether the message is valid.
+    valid: bool,
+    /// The problems found in the message, empty when it is valid.
+    errors: Vec<String>,
+}
+
+/// Checks the commit message in the file, or standard input when there is none.
+///
+/// # Arguments
+/// * `config` - The resolved configuration, whose rules are enforced on top of the lint rules.
+/// * `file` - The file holding the commit message.
+/// * `suggest` - Whether to print a unified diff fixing the problems that can be fixed automatically.
+/// * `explain` - Whether to print the rule each problem comes from, as `rule: message` lines.
+/// * `out` - Where the diff and the explanations are written.
+///
+/// # Returns
+/// * `Ok(())` if the message is valid.
+/// * `Err(Error)` if the message could not be read or is invalid.
+pub(crate) fn run(config: &Config, file: Option<&Path>, suggest: bool, explain: bool, out: &mut impl Write) -> Result<(), Error> {
+    let message = read(file)?;
+    let result = match Commit::parse(&message) {
+        Ok(commit) => {
+            if suggest {
+                let name = file.map_or(STDIN_NAME.into(), |f| f.display().to_string());
+                write!(out, "{}", diff(&name, &message, &commit.normalized().to_string()))?;
+            }
+            enforce(config, &commit)
+        }
+        Err(ConventionalError::Validation(errs)) => Err(errs),
+        Err(err) => return Err(err.into()),
+    };
+
+    if explain && let Err(errs) = &result {
+        for err in errs.iter() {
+            writeln!(out, "{}: {err}", err.rule())?;
+        }
+    }
+
+    Ok(result?)
+}
+
+/// Checks each message of a JSON array read from standard input, ex: `[{"message": "feat: add lexer"}]`.
+///
+/// # Arguments
+/// * `config` - The resolved configuration, whose rules are enforced on top of the lint rules.
+/// * `out` - Where the JSON array of outcomes is written, in the order of the input.
+///
+/// # Returns
+/// * `Ok(())` if every message was checked, whether or not it is valid.
+/// * `Err(Error)` if the input could not be read or is not a JSON array of messages.
+pub(crate) fn run_json(config: &Config, out: &mut impl Write) -> Result<(), Error> {
+    let outcomes = check_json(config, &read(None)?)?;
+    writeln!(out, "{}", serde_json::to_string(&outcomes).map_err(io::Error::from)?)?;
+    Ok(())
+}
+
+/// Checks the message of every commit in a revision range.
+///
+/// # Arguments
+/// * `config` - The resolved configuration, whose rules are enforced on top of the lint rules.
+/// * `range` - The revision range, ex: `main..HEAD`.
+/// * `verify_reverts` - Whether to also report revert commits referencing a commit outside of their history.
+///
+/// # Returns
+/// * `Ok(())` if every commit is valid.
+/// * `Err(Error::InvalidCommits)` listing the problems of each invalid commit, oldest first.
+pub(crate) fn run_range(config: &Config, range: &str, verify_reverts: bool) -> Result<(), Error> {
+    let mut report = Vec::new();
+
+    for commit in git::log(range)?.iter().rev() {
+        let mut problems = lint(config, &commit.message).err().unwrap_or_default();
+
+        if verify_reverts && let Ok(parsed) = Commit::parse(&commit.message) {
+            problems.extend(
+                parsed
+                    .reverted_shas()
+                    .into_iter()
+                    .filter(|sha| !git::is_ancestor(sha, &commit.sha))
+                    .map(|sha| format!("reverts '{sha}', which is not in its history")),
+            );
+        }
+
+        if !problems.is_empty() {
+            let header = commit.message.lines().next().unwrap_or_default();
+            let sha = commit.sha.get(..SHORT_SHA_LENGTH).unwrap_or(&commit.sha);
+            report.push(format!("{sha} {header}\n{}", problems.iter().map(|p| format!("  {p}")).collect::<Vec<_>>().join("\n")));
+        }
+    }
+
+    if report.is_empty() { Ok(()) } else { Err(Error::InvalidCommits(report.join("\n"))) }
+}
+
+/// Checks each message of a JSON array of messages.
+fn check_json(config: &Config, input: &str) -> Result<Vec<Outcome>, Error> {
+    let inputs: Vec<Input> = serde_json::from_str(input).map_err(|e| Error::InvalidInput(e.to_string()))?;
+
+    Ok(inputs
+        .iter()
+        .map(|input| match lint(config, &input.message) {
+            Ok(()) => Outcome { valid: true, errors: vec![] },
+            Err(errors) => Outcome { valid: false, errors },
+        })
+        .collect())
+}
+
+/// Parses the message and enforces the lint rules and the configured rules on it.
+///
+/// # Returns
+/// * `Ok(())` if the message is valid.
+/// * `Err(Vec<String>)` describing each problem found in the message.
+fn lint(config: &Config, message: &str) -> Result<(), Vec<String>> {
+    let errs = match Commit::parse(message) {
+        Ok(commit) => enforce(config, &commit).err(),
+        Err(ConventionalError::Validation(errs)) => Some(errs),
+        Err(err) => return Err(vec![err.to_string()]),
+    };
+    errs.map_or(Ok(()), |errs| Err(errs.iter().map(ToString::to_string).collect()))
+}
+
+/// Enforces the lint rules and the configured rules on a commit, the checks shared with the `commit-msg` hook.
+///
+/// # Arguments
+/// * `config` - The resolved configuration.
+/// * `commit` - The commit to check.
+///
+/// # Returns
+/// * `Ok(())` if the commit follows every rule.
+/// * `Err(Errors<ValidationError>)` with the violations of both sets of rules, the lint rules first.
+pub(crate) fn enforce(config: &Config, commit: &Commit) -> Result<(), Errors<ValidationError>> {
+    let errs: Errors<ValidationError> = [commit.validate_with(&LINT_OPTIONS), commit.check(&config.rules)]
+        .into_iter()
+        .filter_map(Result::err)
+        .flat_map(Errors::into_vec)
+        .into();
+    errs.into_result(())
+}
+
+/// Reads the message from the file, or standard input when there is none.
+fn read(file: Option<&Path>) -> Result<String, Error> {
+    match file {
+        Some(file) => fs::read_to_string(file).map_err(|e| Error::Read(file.to_path_buf(), e)),
+        None => {
+            let mut message = String::new();
+            io::stdin().read_to_string(&mut message)?;
+            Ok(message)
+        }
+    }
+}
+
+/// Builds a unified diff from the message to the suggested one, or an empty string if they only differ in trailing
+/// white-space.
+pub(crate) fn diff(name: &str, message: &str, suggested: &str) -> String {
+    let message = format!("{}\n", message.trim_end());
+    let suggested = format!("{}\n", suggested.trim_end());
+
+    if message == suggested {
+        return String::new();
+    }
+
+    TextDiff::from_lines(&message, &suggested)
+        .unified_diff()
+        .header(&format!("a/{name}"), &format!("b/{name}"))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+
+    #[test]
+    fn test_diffs_changed_lines() {
+        assert_eq!(
+            indoc! {"
+                --- a/COMMIT_EDITMSG
+                +++ b/COMMIT_EDITMSG
+                @@ -1,3 +1,3 @@
+                -Feat: add lexer.
+                +feat: add lexer
+                 
+                 Splits the header.
+            "},
+            diff("COMMIT_EDITMSG", "Feat: add lexer.\n\nSplits the header.\n", "feat: add lexer\n\nSplits the header.")
+        );
+    }
+
+    #[test]
+    fn test_checks_each_message_of_json_array() {
+        let outcomes = check_json(
+            &Config::default(),
+            r#"[{"message": "feat: add lexer"}, {"message": "feat: add lexer."}, {"message": "add lexer"}]"#,
+        )
+        .expect("should have checked the messages");
+
+        assert_eq!(
+            vec![
+                Outcome { valid: true, errors: vec![] },
+                Outcome {
+                    valid: false,
+                    errors: vec!["description 'add lexer.' must not end with a full stop".into()],
+                },
+                Outcome {
+                    valid: false,
+                    errors: vec!["header 'add lexer' must be formatted as '<type>[(<scope>)][!]: <description>'".into()],
+                },
+            ],
+            outcomes
+        );
+    }
+
+    #[test]
+    fn test_rejects_json_input_that_is_not_an_array_of_messages() {
+        assert!(matches!(check_json(&Config::default(), r#"{"message": "feat: add lexer"}"#), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_does_not_diff_trailing_white_space() {
+        assert_eq!("", diff("COMMIT_EDITMSG", "feat: add lexer\n\n\n", "feat: add lexer"));
+    }
+}
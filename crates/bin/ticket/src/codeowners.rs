@@ -0,0 +1,113 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Scope candidates derived from the repository's CODEOWNERS file.
+//!
+//! Teams already name the modules they own in CODEOWNERS, ex: `/crates/parser/ @compilers`, so the directories it lists
+//! make good commit scopes. Only the path patterns are read, the owners are ignored.
+
+use crate::{Error, repository, suggest::GENERIC_DIRECTORIES};
+use std::{fs, path::PathBuf};
+
+/// Where forges look for the CODEOWNERS file, relative to the repository root, in the order GitHub looks for it.
+const LOCATIONS: [&str; 4] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS", ".gitlab/CODEOWNERS"];
+
+/// Reads the scope candidates from the CODEOWNERS file of the repository the tool was run from.
+///
+/// # Returns
+/// * `Ok(Vec<String>)` with the candidates, empty if the repository has no CODEOWNERS file.
+/// * `Err(Error)` if the file exists but could not be read.
+pub(crate) fn load() -> Result<Vec<String>, Error> {
+    let Some(path) = find() else {
+        return Ok(Vec::new());
+    };
+
+    let content = fs::read_to_string(&path).map_err(|e| Error::Read(path, e))?;
+    Ok(scopes(&content))
+}
+
+/// Returns the first CODEOWNERS file that exists, if any.
+fn find() -> Option<PathBuf> {
+    let root = repository::root()?;
+    LOCATIONS.iter().map(|location| root.join(location)).find(|path| path.is_file())
+}
+
+/// Derives scope candidates from the path patterns of a CODEOWNERS file, in the order they first appear.
+///
+/// A pattern's candidate is its deepest directory that says something about the code, ex: `parser` for
+/// `/crates/parser/src/**`. Glob segments and file names are dropped, and patterns without such a directory, ex: `*.md`,
+/// give none.
+pub(crate) fn scopes(content: &str) -> Vec<String> {
+    let mut scopes: Vec<String> = Vec::new();
+
+    for pattern in content.lines().filter_map(|line| line.split_whitespace().next()).filter(|p| !p.starts_with('#')) {
+        if let Some(scope) = scope(pattern)
+            && !scopes.iter().any(|s| s == scope)
+        {
+            scopes.push(scope.to_string());
+        }
+    }
+
+    scopes
+}
+
+/// Picks the scope candidate of a path pattern.
+fn scope(pattern: &str) -> Option<&str> {
+    let is_directory = pattern.ends_with('/');
+    let mut components: Vec<&str> = pattern.split('/').filter(|c| !c.is_empty()).take_while(|c| !c.contains(['*', '?', '['])).collect();
+
+    let globbed = components.len() < pattern.split('/').filter(|c| !c.is_empty()).count();
+    if !is_directory && !globbed && components.last().is_some_and(|c| c.contains('.')) {
+        components.pop();
+    }
+
+    components.into_iter().rev().find(|c| !GENERIC_DIRECTORIES.contains(c) && !c.starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use rstest::rstest;
+
+    #[test]
+    fn test_derives_scopes_from_codeowners() {
+        let content = indoc! {"
+            # Default owners
+            *                               @maintainers
+
+            /crates/lib/conventional-commit/ @alice
+            /crates/bin/ticket/src/**       @bob
+            /.github/workflows/             @ops
+            /web/app.config.ts              @web
+            docs/*                          @writers
+            *.md                            @writers
+            /crates/bin/ticket/Cargo.toml   @bob
+        "};
+
+        assert_eq!(vec!["conventional-commit", "ticket", "workflows", "web"], scopes(content));
+    }
+
+    #[rstest]
+    #[case::directory("/parser/", Some("parser"))]
+    #[case::unanchored_directory("parser", Some("parser"))]
+    #[case::glob_segment("/apps/web/**/*.ts", Some("web"))]
+    #[case::file("/apps/web/index.html", Some("web"))]
+    #[case::dotted_directory("/packages/ui.kit/", Some("ui.kit"))]
+    #[case::generic_only("/src/lib/", None)]
+    #[case::extension_glob("*.rs", None)]
+    fn test_picks_scope_of_pattern(#[case] pattern: &str, #[case] expect: Option<&str>) {
+        assert_eq!(expect, scope(pattern));
+    }
+}
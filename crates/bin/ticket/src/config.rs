@@ -0,0 +1,487 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Configuration of `git-ticket`.
+//!
+//! Settings are resolved from, in order of precedence: the command line flags, the first configuration file found, and
+//! the built-in defaults. The configuration file is `git-ticket.toml` in the repository root, or
+//! `$XDG_CONFIG_HOME/git-ticket/config.toml` (`~/.config/git-ticket/config.toml` when `XDG_CONFIG_HOME` is unset).
+//! The `--config` flag skips that search and reads the given file instead, which must exist.
+//!
+//! A leading `~` in the template path stands for the home directory on every platform, the user profile on Windows.
+//!
+//! Setting a closing keyword, `Closes`, `Fixes` or `Resolves`, attaches tickets under it rather than the footer token,
+//! so GitHub and GitLab close them once the commit reaches the default branch.
+//!
+//! The ticket template writes the attached ticket from the variables the branch pattern captures, see [`branch`].
+
+use crate::{Error, areas, branch, repository};
+use clap::ValueEnum;
+use conventional_commit::{
+    lint::RuleConfig,
+    model::{Build, ClosingKeyword, Footer},
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// The name of the configuration file in the repository root.
+const REPOSITORY_FILE: &str = "git-ticket.toml";
+
+/// The path of the configuration file, relative to the user's configuration directory.
+const USER_FILE: &str = "git-ticket/config.toml";
+
+/// The commit message template used when none is configured.
+const DEFAULT_TEMPLATE_PATH: &str = "~/.gitmessage.txt";
+
+/// The pattern used to find tickets in branch names when none is configured, ex: `PROJ-123` in `feature/PROJ-123`.
+const DEFAULT_BRANCH_PATTERN: &str = r"[A-Z][A-Z0-9]+-\d+";
+
+/// The footer token tickets are attached with when none is configured.
+const DEFAULT_FOOTER_TOKEN: &str = "Refs";
+
+/// How tickets are written when no template is configured: as they appear in the branch name.
+const DEFAULT_TICKET_TEMPLATE: &str = "{ticket}";
+
+/// How tickets are attached to commit messages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Style {
+    /// As a footer, ex: `Refs: PROJ-123`.
+    #[default]
+    Footer,
+    /// As a prefix of the subject, ex: `[PROJ-123] feat: add lexer`.
+    Prefix,
+}
+
+/// An area of the code base, ex: `ui`, and how to recognise changes touching it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Area {
+    /// Globs matching the paths in the area, ex: `web/**`.
+    #[serde(default)]
+    pub(crate) paths: Vec<String>,
+    /// The commit scopes in the area, ex: `ui`.
+    #[serde(default)]
+    pub(crate) scopes: Vec<String>,
+}
+
+/// The settings that can be set on the command line, overriding the configuration file.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub(crate) struct Overrides {
+    /// The configuration file to read, instead of searching the repository and user configuration directories
+    #[arg(long = "config", global = true, value_name = "PATH")]
+    config_path: Option<PathBuf>,
+
+    /// The commit message template to attach tickets to
+    #[arg(long, global = true, value_name = "PATH")]
+    template_path: Option<PathBuf>,
+
+    /// The regular expression matching tickets in branch names
+    #[arg(long, global = true, value_name = "PATTERN")]
+    branch_pattern: Option<String>,
+
+    /// The footer token tickets are attached with
+    #[arg(long, global = true, value_name = "TOKEN")]
+    footer_token: Option<String>,
+
+    /// Attaches tickets under a closing keyword instead of the footer token, ex: `Closes`, `Fixes` or `Resolves`
+    #[arg(long, global = true, value_name = "KEYWORD")]
+    closing_keyword: Option<ClosingKeyword>,
+
+    /// How tickets are written, with `{name}` replaced by the named captures of the branch pattern
+    #[arg(long, global = true, value_name = "TEMPLATE")]
+    ticket_template: Option<String>,
+
+    /// How tickets are attached to commit messages
+    #[arg(long, global = true)]
+    style: Option<Style>,
+}
+
+/// The settings read from a configuration file, where every key is optional.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    template_path: Option<PathBuf>,
+    branch_pattern: Option<String>,
+    footer_token: Option<String>,
+    closing_keyword: Option<ClosingKeyword>,
+    ticket_template: Option<String>,
+    style: Option<Style>,
+    prefill_type: Option<bool>,
+    #[serde(default)]
+    areas: BTreeMap<String, Area>,
+    #[serde(default)]
+    rules: RuleConfig,
+}
+
+/// The resolved configuration.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Config {
+    /// The commit message template to attach tickets to.
+    pub(crate) template_path: PathBuf,
+    /// The regular expression matching tickets in branch names.
+    pub(crate) branch_pattern: String,
+    /// The footer token tickets are attached with.
+    pub(crate) footer_token: String,
+    /// The closing keyword tickets are attached with instead of the footer token, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) closing_keyword: Option<ClosingKeyword>,
+    /// How tickets are written, ex: `{ticket}`, with `{name}` replaced by the named captures of the branch pattern.
+    pub(crate) ticket_template: String,
+    /// How tickets are attached to commit messages.
+    pub(crate) style: Style,
+    /// Whether the `prepare-commit-msg` hook writes the `type` the branch pattern captures as the subject of a new
+    /// message. Only configurable in the file.
+    pub(crate) prefill_type: bool,
+    /// The areas of the code base, by name. Only configurable in the file.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) areas: BTreeMap<String, Area>,
+    /// The commitlint rules the `commit-msg` hook enforces, with their commitlint names. Only configurable in the file.
+    #[serde(skip_serializing_if = "is_default")]
+    pub(crate) rules: RuleConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            template_path: DEFAULT_TEMPLATE_PATH.into(),
+            branch_pattern: DEFAULT_BRANCH_PATTERN.into(),
+            footer_token: DEFAULT_FOOTER_TOKEN.into(),
+            closing_keyword: None,
+            ticket_template: DEFAULT_TICKET_TEMPLATE.into(),
+            style: Style::default(),
+            prefill_type: false,
+            areas: BTreeMap::new(),
+            rules: RuleConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the configuration, applying the command line overrides on top of the configuration file.
+    ///
+    /// # Returns
+    /// * `Ok(Config)` with the resolved settings.
+    /// * `Err(Error)` if the configuration file could not be read or is invalid.
+    pub(crate) fn load(overrides: &Overrides) -> Result<Self, Error> {
+        let file = match overrides.config_path.clone().or_else(find) {
+            Some(path) => read(&path)?,
+            None => File::default(),
+        };
+
+        Config::resolve(file, overrides.clone()).map_err(|(key, reason)| Error::Config(format!("`{key}` {reason}")))
+    }
+
+    /// Returns the path of the commit message template, with a leading `~` expanded to the home directory.
+    ///
+    /// # Returns
+    /// The template path, unchanged when it doesn't start with `~` or the home directory is unknown.
+    pub(crate) fn template_file(&self) -> PathBuf {
+        expand_home(&self.template_path, env::home_dir().as_deref())
+    }
+
+    /// Returns the token tickets are attached with, the closing keyword when one is set and the footer token otherwise.
+    pub(crate) fn ticket_token(&self) -> &str {
+        self.closing_keyword.map_or(self.footer_token.as_str(), |keyword| keyword.as_str())
+    }
+
+    /// Builds the footer attaching a ticket, under the closing keyword when one is set and the footer token otherwise.
+    ///
+    /// # Returns
+    /// * `Ok(Footer)` with the ticket as value, ex: `Refs: PROJ-123` or `Closes #42`.
+    /// * `Err(Error::Config)` if the footer token is invalid or the ticket is blank.
+    pub(crate) fn ticket_footer(&self, ticket: &str) -> Result<Footer, Error> {
+        match self.closing_keyword {
+            Some(keyword) => Footer::closing(keyword, ticket).build().map_err(|e| Error::Config(format!("`closing_keyword` {e}"))),
+            None => Footer::builder(self.footer_token.as_str())
+                .value(ticket)
+                .build()
+                .map_err(|e| Error::Config(format!("`footer_token` {e}"))),
+        }
+    }
+
+    /// Merges the settings, preferring the overrides, then the file, then the defaults.
+    ///
+    /// # Returns
+    /// * `Ok(Config)` with the merged settings.
+    /// * `Err((key, reason))` if a setting is invalid.
+    fn resolve(file: File, overrides: Overrides) -> Result<Self, (String, String)> {
+        let branch_pattern = overrides.branch_pattern.or(file.branch_pattern).unwrap_or_else(|| DEFAULT_BRANCH_PATTERN.into());
+        let pattern = Regex::new(&branch_pattern).map_err(|e| ("branch_pattern".to_string(), format!("is not a valid regular expression: {e}")))?;
+
+        let ticket_template = overrides.ticket_template.or(file.ticket_template).unwrap_or_else(|| DEFAULT_TICKET_TEMPLATE.into());
+        branch::validate(&ticket_template, &branch::names(&pattern)).map_err(|reason| ("ticket_template".to_string(), reason))?;
+
+        for (name, area) in &file.areas {
+            if let Some(e) = area.paths.iter().find_map(|p| areas::glob(p).err()) {
+                return Err((format!("areas.{name}.paths"), format!("is not a valid glob: {e}")));
+            }
+        }
+
+        Ok(Config {
+            template_path: overrides.template_path.or(file.template_path).unwrap_or_else(|| DEFAULT_TEMPLATE_PATH.into()),
+            branch_pattern,
+            footer_token: overrides.footer_token.or(file.footer_token).unwrap_or_else(|| DEFAULT_FOOTER_TOKEN.into()),
+            closing_keyword: overrides.closing_keyword.or(file.closing_keyword),
+            ticket_template,
+            style: overrides.style.or(file.style).unwrap_or_default(),
+            prefill_type: file.prefill_type.unwrap_or_default(),
+            areas: file.areas,
+            rules: file.rules,
+        })
+    }
+}
+
+/// Prints the resolved configuration, in the configuration file format.
+///
+/// # Returns
+/// * `Ok(())` if the configuration was printed.
+/// * `Err(Error)` if the configuration is invalid.
+pub(crate) fn run(overrides: &Overrides, out: &mut impl Write) -> Result<(), Error> {
+    let config = Config::load(overrides)?;
+    let content = toml::to_string(&config).map_err(|e| Error::Config(e.to_string()))?;
+
+    write!(out, "{content}")?;
+    Ok(())
+}
+
+/// Returns the first configuration file that exists, if any.
+fn find() -> Option<PathBuf> {
+    let repository = repository::root().map(|root| root.join(REPOSITORY_FILE));
+    let user = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::home_dir().map(|home| home.join(".config")))
+        .map(|dir| dir.join(USER_FILE));
+
+    [repository, user].into_iter().flatten().find(|path| path.is_file())
+}
+
+/// Checks whether the rules are the defaults, which enforce nothing, to leave them out of the printed configuration.
+fn is_default(rules: &RuleConfig) -> bool {
+    *rules == RuleConfig::default()
+}
+
+/// Expands a leading `~`, alone or followed by a separator, to the home directory. `~user` forms are left as they are.
+pub(crate) fn expand_home(path: &Path, home: Option<&Path>) -> PathBuf {
+    match (path.strip_prefix("~"), home) {
+        (Ok(rest), Some(home)) if rest.as_os_str().is_empty() => home.to_path_buf(),
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Reads a configuration file, naming the offending key when it is invalid.
+fn read(path: &Path) -> Result<File, Error> {
+    let content = fs::read_to_string(path).map_err(|e| Error::Read(path.to_path_buf(), e))?;
+    parse(&content).map_err(|reason| Error::Config(format!("{}: {reason}", path.display())))
+}
+
+/// Parses the content of a configuration file.
+fn parse(content: &str) -> Result<File, String> {
+    serde_path_to_error::deserialize(toml::Deserializer::new(content)).map_err(|e| {
+        let key = e.path().to_string();
+        let reason = e.into_inner().message().trim().to_string();
+
+        if key == "." { reason } else { format!("`{key}` {reason}") }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use rstest::rstest;
+
+    #[test]
+    fn test_parses_every_key() {
+        let file = parse(indoc! {r#"
+            template_path = "~/templates/commit.txt"
+            branch_pattern = "GH-\\d+"
+            footer_token = "Closes"
+            closing_keyword = "fixes"
+            ticket_template = "GH-{ticket}"
+            style = "prefix"
+            prefill_type = true
+        "#})
+        .expect("should have parsed the configuration");
+
+        assert_eq!(
+            File {
+                template_path: Some("~/templates/commit.txt".into()),
+                branch_pattern: Some(r"GH-\d+".into()),
+                footer_token: Some("Closes".into()),
+                closing_keyword: Some(ClosingKeyword::Fixes),
+                ticket_template: Some("GH-{ticket}".into()),
+                style: Some(Style::Prefix),
+                prefill_type: Some(true),
+                areas: BTreeMap::new(),
+                rules: RuleConfig::default(),
+            },
+            file
+        );
+    }
+
+    #[rstest]
+    #[case::wrong_type("footer_token = 1", "`footer_token` invalid type: integer `1`, expected a string")]
+    #[case::unknown_style(r#"style = "suffix""#, "`style` unknown variant `suffix`, expected `footer` or `prefix`")]
+    #[case::unknown_closing_keyword(
+        r#"closing_keyword = "Refs""#,
+        "`closing_keyword` field 'closing keyword' has invalid value: 'Refs' must be one of: Closes, Fixes, Resolves"
+    )]
+    #[case::unknown_key(
+        r#"ticket = "PROJ-1""#,
+        "`ticket` unknown field `ticket`, expected one of `template_path`, `branch_pattern`, `footer_token`, `closing_keyword`, `ticket_template`, `style`, `prefill_type`, `areas`, `rules`"
+    )]
+    fn test_names_offending_key_when_parsing_fails(#[case] content: &str, #[case] expect: &str) {
+        assert_eq!(expect, parse(content).expect_err("should have failed"));
+    }
+
+    #[rstest]
+    #[case::home_only("~", Some("/home/alice"), "/home/alice")]
+    #[case::in_home("~/.gitmessage.txt", Some("/home/alice"), "/home/alice/.gitmessage.txt")]
+    #[case::nested_in_home("~/templates/commit.txt", Some("/home/alice"), "/home/alice/templates/commit.txt")]
+    #[case::other_user("~bob/.gitmessage.txt", Some("/home/alice"), "~bob/.gitmessage.txt")]
+    #[case::absolute("/etc/gitmessage.txt", Some("/home/alice"), "/etc/gitmessage.txt")]
+    #[case::relative("templates/~/commit.txt", Some("/home/alice"), "templates/~/commit.txt")]
+    #[case::unknown_home("~/.gitmessage.txt", None, "~/.gitmessage.txt")]
+    fn test_expands_home_in_template_path(#[case] path: &str, #[case] home: Option<&str>, #[case] expect: &str) {
+        assert_eq!(PathBuf::from(expect), expand_home(Path::new(path), home.map(Path::new)));
+    }
+
+    #[test]
+    fn test_uses_defaults_without_file_or_overrides() {
+        assert_eq!(
+            Config::default(),
+            Config::resolve(File::default(), Overrides::default()).expect("should have resolved the configuration")
+        );
+    }
+
+    #[test]
+    fn test_prefers_overrides_then_file_then_defaults() {
+        let file = File {
+            footer_token: Some("Closes".into()),
+            style: Some(Style::Prefix),
+            ..File::default()
+        };
+        let overrides = Overrides {
+            style: Some(Style::Footer),
+            ..Overrides::default()
+        };
+
+        let config = Config::resolve(file, overrides).expect("should have resolved the configuration");
+
+        assert_eq!(Path::new(DEFAULT_TEMPLATE_PATH), config.template_path);
+        assert_eq!("Closes", config.footer_token);
+        assert_eq!(Style::Footer, config.style);
+    }
+
+    #[test]
+    fn test_parses_areas() {
+        let file = parse(indoc! {r#"
+            [areas.ui]
+            paths = ["web/**"]
+            scopes = ["ui"]
+
+            [areas.backend]
+            paths = ["server/**"]
+        "#})
+        .expect("should have parsed the configuration");
+
+        assert_eq!(
+            BTreeMap::from([
+                (
+                    "backend".to_string(),
+                    Area {
+                        paths: vec!["server/**".into()],
+                        scopes: vec![],
+                    }
+                ),
+                (
+                    "ui".to_string(),
+                    Area {
+                        paths: vec!["web/**".into()],
+                        scopes: vec!["ui".into()],
+                    }
+                ),
+            ]),
+            file.areas
+        );
+    }
+
+    #[test]
+    fn test_parses_rules() {
+        let file = parse(indoc! {r#"
+            [rules]
+            type-enum = ["feat", "fix"]
+            trailer-exists = ["Refs"]
+        "#})
+        .expect("should have parsed the configuration");
+
+        assert_eq!(
+            RuleConfig {
+                type_enum: Some(vec!["feat".into(), "fix".into()]),
+                trailer_exists: vec!["Refs".into()],
+                ..RuleConfig::default()
+            },
+            file.rules
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_area_glob() {
+        let file = parse(indoc! {r#"
+            [areas.ui]
+            paths = ["web/[**"]
+        "#})
+        .expect("should have parsed the configuration");
+
+        let (key, _) = Config::resolve(file, Overrides::default()).expect_err("should have failed");
+        assert_eq!("areas.ui.paths", key);
+    }
+
+    #[test]
+    fn test_rejects_invalid_branch_pattern() {
+        let file = File {
+            branch_pattern: Some("(".into()),
+            ..File::default()
+        };
+
+        let (key, _) = Config::resolve(file, Overrides::default()).expect_err("should have failed");
+        assert_eq!("branch_pattern", key);
+    }
+
+    #[rstest]
+    #[case::default_pattern(None, "{ticket} {slug}", "references unknown variable `{slug}`, available variables are `{ticket}`")]
+    #[case::named_pattern(
+        Some(r"(?<type>[a-z]+)/(?<ticket>[A-Z]+-\d+)"),
+        "{ticket} {scope}",
+        "references unknown variable `{scope}`, available variables are `{ticket}`, `{type}`"
+    )]
+    fn test_rejects_unknown_ticket_template_variable(#[case] branch_pattern: Option<&str>, #[case] template: &str, #[case] expect: &str) {
+        let file = File {
+            branch_pattern: branch_pattern.map(String::from),
+            ticket_template: Some(template.into()),
+            ..File::default()
+        };
+
+        assert_eq!(Err(("ticket_template".to_string(), expect.to_string())), Config::resolve(file, Overrides::default()));
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `detect` subcommand.
+//!
+//! Reports the tickets found in the branch name along with the settings used to attach them and the repository they
+//! were found in, as text for people or as JSON for editor integrations.
+
+use crate::{Error, Format, branch, config::Config, repository::Repository};
+use regex::Regex;
+use serde::Serialize;
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// What `git-ticket` would attach to the next commit.
+#[derive(Debug, PartialEq, Serialize)]
+struct Detection<'a> {
+    /// The tickets found in the branch name, in the order they appear.
+    tickets: Vec<String>,
+    /// The footer token tickets are attached with, the closing keyword when one is set.
+    footer_token: &'a str,
+    /// The commit message template tickets are attached to.
+    template_path: &'a Path,
+    /// The git directory of the repository, `None` outside of a repository.
+    git_dir: Option<PathBuf>,
+}
+
+/// Prints the tickets found in the branch name, the footer token, the template path and the git directory.
+///
+/// # Arguments
+/// * `config` - The resolved configuration.
+/// * `format` - Whether to print text or a JSON object.
+/// * `out` - Where the report is written.
+///
+/// # Returns
+/// * `Ok(())` if the report was printed, even when no ticket was found.
+/// * `Err(Error)` if the branch pattern is invalid.
+pub(crate) fn run(config: &Config, format: Format, out: &mut impl Write) -> Result<(), Error> {
+    let pattern = Regex::new(&config.branch_pattern).map_err(|e| Error::Config(format!("`branch_pattern` {e}")))?;
+    let repository = Repository::discover().ok();
+    let detection = Detection {
+        tickets: repository
+            .as_ref()
+            .and_then(Repository::current_branch)
+            .map(|branch| tickets(&pattern, &branch))
+            .unwrap_or_default(),
+        footer_token: config.ticket_token(),
+        template_path: &config.template_path,
+        git_dir: repository.as_ref().map(|r| r.git_dir().to_path_buf()),
+    };
+
+    match format {
+        Format::Text => {
+            let tickets = if detection.tickets.is_empty() { "none".into() } else { detection.tickets.join(", ") };
+            writeln!(out, "tickets: {tickets}")?;
+            writeln!(out, "footer token: {}", detection.footer_token)?;
+            writeln!(out, "template path: {}", detection.template_path.display())?;
+            let git_dir = detection.git_dir.as_deref().map_or("none".into(), |d| d.display().to_string());
+            writeln!(out, "git dir: {git_dir}")?;
+        }
+        Format::Json => writeln!(out, "{}", serde_json::to_string(&detection).map_err(io::Error::from)?)?,
+    }
+
+    Ok(())
+}
+
+/// Returns the distinct tickets in the branch name, in the order they appear.
+///
+/// A ticket is the `ticket` capture of the branch pattern when it has one, the whole match otherwise.
+pub(crate) fn tickets(pattern: &Regex, branch: &str) -> Vec<String> {
+    branch::variables(pattern, branch)
+        .into_iter()
+        .filter_map(|mut variables| variables.remove(branch::TICKET))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::one("feature/PROJ-123", vec!["PROJ-123"])]
+    #[case::several("PROJ-1+OPS-22/fix-build", vec!["PROJ-1", "OPS-22"])]
+    #[case::repeated("PROJ-1/PROJ-1-follow-up", vec!["PROJ-1"])]
+    #[case::none("main", vec![])]
+    fn test_finds_tickets_in_branch_name(#[case] branch: &str, #[case] expect: Vec<&str>) {
+        let pattern = Regex::new(&Config::default().branch_pattern).expect("should have compiled the pattern");
+        assert_eq!(expect, tickets(&pattern, branch));
+    }
+
+    #[test]
+    fn test_finds_ticket_capture_in_branch_name() {
+        let pattern = Regex::new(r"(?<type>[a-z]+)/(?<ticket>[A-Z]+-\d+)/(?<slug>.+)").expect("should have compiled the pattern");
+        assert_eq!(vec!["PROJ-123"], tickets(&pattern, "feature/PROJ-123/short-desc"));
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `doctor` subcommand.
+//!
+//! Diagnoses the setup `git-ticket` relies on and prints a checklist, each problem followed by a hint on fixing it.
+//! Problems breaking `git commit` or `git-ticket` itself, an invalid configuration, running outside a repository or a
+//! `commit.template` pointing at a missing file, fail the run. The others, ex: no hook installed or no ticket in the
+//! branch name, are warnings since `git-ticket` can be used without them.
+
+use crate::{
+    Error,
+    config::{self, Config, Overrides},
+    detect, git, hook,
+    repository::Repository,
+};
+use regex::Regex;
+use std::{env, fmt, fs, io::Write};
+
+/// How a check turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// The setup is as expected.
+    Pass,
+    /// The setup works but is missing something, ex: a hook.
+    Warn,
+    /// The setup is broken.
+    Fail,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Pass => "pass",
+            Status::Warn => "warn",
+            Status::Fail => "fail",
+        })
+    }
+}
+
+/// The outcome of a check, with a hint on fixing it unless it passed.
+struct Check {
+    status: Status,
+    message: String,
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(message: impl Into<String>) -> Self {
+        Check {
+            status: Status::Pass,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(message: impl Into<String>, hint: impl Into<String>) -> Self {
+        Check {
+            status: Status::Warn,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(message: impl Into<String>, hint: impl Into<String>) -> Self {
+        Check {
+            status: Status::Fail,
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Runs the checks and prints each outcome, as `[pass] message` lines followed by a hint when they didn't pass.
+///
+/// # Arguments
+/// * `overrides` - The command line overrides, checked along with the configuration file.
+/// * `out` - Where the checklist is written.
+///
+/// # Returns
+/// * `Ok(())` if no critical check failed, even when some warned.
+/// * `Err(Error::Unhealthy)` with the number of critical checks that failed.
+/// * `Err(Error::Io)` if the checklist could not be written.
+pub(crate) fn run(overrides: &Overrides, out: &mut impl Write) -> Result<(), Error> {
+    let checks = diagnose(overrides);
+    for check in &checks {
+        writeln!(out, "[{}] {}", check.status, check.message)?;
+        if let Some(hint) = &check.hint {
+            writeln!(out, "       hint: {hint}")?;
+        }
+    }
+
+    match checks.iter().filter(|c| c.status == Status::Fail).count() {
+        0 => Ok(()),
+        failed => Err(Error::Unhealthy(failed)),
+    }
+}
+
+/// Runs every check that applies, skipping the ones depending on a repository or configuration that is missing.
+fn diagnose(overrides: &Overrides) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    let config = match Config::load(overrides) {
+        Ok(config) => {
+            checks.push(Check::pass("the configuration is valid"));
+            Some(config)
+        }
+        Err(e) => {
+            checks.push(Check::fail(
+                e.to_string(),
+                "fix the configuration file, `git-ticket config` prints the settings once it loads",
+            ));
+            None
+        }
+    };
+
+    let Ok(repository) = Repository::discover() else {
+        checks.push(Check::fail(
+            "not in a git repository",
+            "run git-ticket from inside a repository, or create one with `git init`",
+        ));
+        return checks;
+    };
+    checks.push(Check::pass("in a git repository"));
+
+    checks.push(template(&repository));
+    checks.extend(hooks());
+    if let Some(config) = config {
+        checks.extend(branch(&config, &repository));
+    }
+
+    checks
+}
+
+/// Checks that `commit.template` is set and points at a file, git refusing to commit when it is missing.
+fn template(repository: &Repository) -> Check {
+    let Some(value) = repository.commit_template() else {
+        return Check::warn("commit.template is not set", "run `git-ticket init` to write the template and set it");
+    };
+
+    let path = config::expand_home(&value, env::home_dir().as_deref());
+    if path.is_file() {
+        Check::pass(format!("commit.template is set to {}", path.display()))
+    } else {
+        Check::fail(
+            format!("commit.template points to {}, which does not exist", path.display()),
+            "run `git-ticket init` to write it again, or unset it with `git config --unset commit.template`",
+        )
+    }
+}
+
+/// Checks that each hook is installed, and written for `git-ticket` rather than by another tool.
+fn hooks() -> Vec<Check> {
+    let dir = match git::hooks_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            return vec![Check::warn(
+                format!("the hooks directory could not be found: {e}"),
+                "check `core.hooksPath` with `git config core.hooksPath`",
+            )];
+        }
+    };
+
+    hook::NAMES
+        .iter()
+        .map(|name| {
+            let path = dir.join(name);
+            match fs::read_to_string(&path) {
+                Ok(script) if hook::is_ours(&script) => Check::pass(format!("the {name} hook is installed at {}", path.display())),
+                Ok(_) => Check::warn(
+                    format!("the {name} hook at {} was not installed by git-ticket", path.display()),
+                    format!("make sure the tool managing it runs `git-ticket hook {name}`"),
+                ),
+                Err(_) => Check::warn(format!("the {name} hook is not installed"), "run `git-ticket install` to install the hooks"),
+            }
+        })
+        .collect()
+}
+
+/// Checks that the branch matches the branch pattern and that a ticket can be found in its name.
+fn branch(config: &Config, repository: &Repository) -> Vec<Check> {
+    let Some(branch) = repository.current_branch() else {
+        return vec![Check::warn(
+            "HEAD is detached, there is no branch name to find tickets in",
+            "check out a branch, ex: `git switch -c feature/PROJ-123`",
+        )];
+    };
+    let Ok(pattern) = Regex::new(&config.branch_pattern) else {
+        return Vec::new();
+    };
+
+    if !pattern.is_match(&branch) {
+        return vec![Check::warn(
+            format!("the branch `{branch}` does not match the branch pattern `{}`", config.branch_pattern),
+            "name the branch after its ticket, ex: `feature/PROJ-123`, or set `branch_pattern` in the configuration",
+        )];
+    }
+
+    let tickets = detect::tickets(&pattern, &branch);
+    vec![
+        Check::pass(format!("the branch `{branch}` matches the branch pattern `{}`", config.branch_pattern)),
+        if tickets.is_empty() {
+            Check::warn(
+                format!("no ticket was found in the branch `{branch}`"),
+                "make sure the `ticket` capture of `branch_pattern` takes part in the match",
+            )
+        } else {
+            Check::pass(format!("tickets found: {}", tickets.join(", ")))
+        },
+    ]
+}
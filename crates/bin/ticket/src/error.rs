@@ -0,0 +1,214 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Errors reported by the `git-ticket` command line.
+
+use crate::git::{MINIMUM_VERSION, Version};
+use conventional_commit::{
+    errors::{ConventionalError, Errors},
+    model::ValidationError,
+};
+use serde_json::json;
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+/// The exit code used when git is missing or too old.
+const GIT_UNAVAILABLE_EXIT_CODE: u8 = 3;
+
+/// Errors that can occur while running a `git-ticket` subcommand.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// A git command exited unsuccessfully.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The git command that was run
+    /// * `1` - The error output of the command
+    #[error("git {0} failed: {1}")]
+    Git(String, String),
+
+    /// The git binary could not be found on the `PATH`.
+    #[error("git was not found, please install git {MINIMUM_VERSION} or later and make sure it is on your PATH")]
+    GitNotFound,
+
+    /// The installed git is older than the minimum supported version.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The installed version
+    #[error("git {0} is too old, please upgrade to git {MINIMUM_VERSION} or later")]
+    GitTooOld(Version),
+
+    /// The directory is not in a git repository.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The directory the repository was searched from
+    /// * `1` - Why no repository could be opened
+    #[error("{path} is not in a git repository", path = .0.display())]
+    NotARepository(PathBuf, #[source] Box<gix::error::Error>),
+
+    /// There are no staged changes to work with.
+    #[error("there are no staged changes, stage some files with `git add` first")]
+    NothingStaged,
+
+    /// The commit message doesn't follow the conventional commits specification or the enforced lint rules.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The problems found in the message
+    #[error("the commit message is not a valid conventional commit\n{0}")]
+    InvalidMessage(#[from] Errors<ValidationError>),
+
+    /// Some commits of a range don't follow the conventional commits specification or the enforced lint rules.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The problems found, grouped by commit
+    #[error("some commits are not valid conventional commits\n{0}")]
+    InvalidCommits(String),
+
+    /// The configuration is invalid.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The offending key and why its value is invalid
+    #[error("invalid configuration, {0}")]
+    Config(String),
+
+    /// The git identity, `user.name` and `user.email`, is missing or invalid.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - What is wrong with the identity
+    #[error("invalid git identity, {0}")]
+    Identity(String),
+
+    /// The input read from standard input is malformed.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - Why the input is malformed
+    #[error("invalid input, {0}")]
+    InvalidInput(String),
+
+    /// Some critical checks of `git-ticket doctor` failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The number of critical checks that failed
+    #[error("{0} critical check(s) failed, see the hints above")]
+    Unhealthy(usize),
+
+    /// A file could not be read.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The path of the file
+    /// * `1` - The reason it could not be read
+    #[error("could not read {path}: {source}", path = .0.display(), source = .1)]
+    Read(PathBuf, #[source] io::Error),
+
+    /// A file could not be written.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The path of the file
+    /// * `1` - The reason it could not be written
+    #[error("could not write {path}: {source}", path = .0.display(), source = .1)]
+    Write(PathBuf, #[source] io::Error),
+
+    /// Reading or writing failed.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl Error {
+    /// Returns a stable identifier of the kind of error, for tools consuming the JSON output.
+    ///
+    /// # Returns
+    /// A `snake_case` name that doesn't change when the message does, ex: `nothing_staged`.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Git(..) => "git",
+            Error::GitNotFound => "git_not_found",
+            Error::GitTooOld(_) => "git_too_old",
+            Error::NotARepository(..) => "not_a_repository",
+            Error::NothingStaged => "nothing_staged",
+            Error::InvalidMessage(_) => "invalid_message",
+            Error::InvalidCommits(_) => "invalid_commits",
+            Error::Config(_) => "config",
+            Error::Identity(_) => "identity",
+            Error::InvalidInput(_) => "invalid_input",
+            Error::Unhealthy(_) => "unhealthy",
+            Error::Read(..) => "read",
+            Error::Write(..) => "write",
+            Error::Io(_) => "io",
+        }
+    }
+
+    /// Formats the error as a JSON object, ex: `{"error":{"kind":"nothing_staged","message":"...","exit_code":1}}`.
+    ///
+    /// # Returns
+    /// The error on a single line, with its kind, message and exit code.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        json!({
+            "error": {
+                "kind": self.kind(),
+                "message": self.to_string(),
+                "exit_code": self.exit_code(),
+            }
+        })
+        .to_string()
+    }
+
+    /// Formats the error for a terminal, like `Display` does but with the validation problems colored when enabled.
+    ///
+    /// # Arguments
+    /// * `use_color` - Whether to color the output with ANSI escape codes.
+    ///
+    /// # Returns
+    /// The error message, on several lines when it lists problems.
+    #[must_use]
+    pub fn render(&self, use_color: bool) -> String {
+        match self {
+            Error::InvalidMessage(errs) => format!("the commit message is not a valid conventional commit\n{}", errs.report(use_color)),
+            err => err.to_string(),
+        }
+    }
+
+    /// Returns the process exit code matching the error.
+    ///
+    /// # Returns
+    /// The exit code the binary should terminate with.
+    #[must_use]
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::GitNotFound | Error::GitTooOld(_) => GIT_UNAVAILABLE_EXIT_CODE,
+            _ => 1,
+        }
+    }
+}
+
+/// Reports an invalid message as an `Error::InvalidMessage`, so the problems are rendered the same whichever way it was parsed.
+impl From<ConventionalError> for Error {
+    fn from(err: ConventionalError) -> Self {
+        match err {
+            ConventionalError::Validation(errs) => Error::InvalidMessage(errs),
+            ConventionalError::Parse(reason) => Error::InvalidInput(reason),
+            ConventionalError::Io(e) => Error::Io(e),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Thin wrapper around the `git` binary.
+//!
+//! Commands are run in the current working directory, so they act on the repository the user invoked the tool from.
+
+use crate::Error;
+use std::{io::ErrorKind, path::PathBuf, process::Command};
+
+/// The oldest git version the subcommands are known to work with.
+pub(crate) const MINIMUM_VERSION: Version = Version(2, 20, 0);
+
+/// A git version, as `major.minor.patch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version(pub u32, pub u32, pub u32);
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.0, self.1, self.2)
+    }
+}
+
+/// The status of a staged file, as reported by `git diff --name-status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChangeStatus {
+    /// The file is new.
+    Added,
+    /// The file was modified, or its type changed.
+    Modified,
+    /// The file was removed.
+    Deleted,
+    /// The file was renamed or copied.
+    Renamed,
+}
+
+/// A file staged for the next commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct StagedChange {
+    /// What happened to the file.
+    pub(crate) status: ChangeStatus,
+    /// The path of the file relative to the repository root. For renames, this is the new path.
+    pub(crate) path: String,
+}
+
+/// Runs git with the given arguments and returns its standard output.
+///
+/// # Returns
+/// * `Ok(String)` with the standard output if git exited successfully.
+/// * `Err(Error)` if git could not be run or exited with an error.
+pub(crate) fn run(args: &[&str]) -> Result<String, Error> {
+    let output = Command::new("git").args(args).output().map_err(|e| match e.kind() {
+        ErrorKind::NotFound => Error::GitNotFound,
+        _ => Error::Io(e),
+    })?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(Error::Git(args.join(" "), String::from_utf8_lossy(&output.stderr).trim().to_string()))
+    }
+}
+
+/// Checks that git is installed and recent enough for the subcommands that rely on it.
+///
+/// # Returns
+/// * `Ok(())` if git is on the `PATH` and at least `MINIMUM_VERSION`.
+/// * `Err(Error::GitNotFound)` if git could not be found.
+/// * `Err(Error::GitTooOld)` if git is older than `MINIMUM_VERSION`.
+pub(crate) fn ensure_available() -> Result<(), Error> {
+    let output = run(&["--version"])?;
+
+    match parse_version(&output) {
+        Some(version) if version < MINIMUM_VERSION => Err(Error::GitTooOld(version)),
+        _ => Ok(()),
+    }
+}
+
+/// Parses the output of `git --version`, ex: `git version 2.39.5` or `git version 2.37.1.windows.1`.
+fn parse_version(output: &str) -> Option<Version> {
+    let version = output.trim().strip_prefix("git version ")?.split_whitespace().next()?;
+    let mut numbers = version.split('.').map(str::parse::<u32>);
+
+    let major = numbers.next()?.ok()?;
+    let minor = numbers.next().and_then(Result::ok).unwrap_or_default();
+    let patch = numbers.next().and_then(Result::ok).unwrap_or_default();
+
+    Some(Version(major, minor, patch))
+}
+
+/// Reads a git setting, ex: `user.email`.
+///
+/// # Returns
+/// * `Some(String)` with the value of the setting.
+/// * `None` if the setting is unset or empty, or git could not be run.
+pub(crate) fn config(key: &str) -> Option<String> {
+    let value = run(&["config", "--get", key]).ok()?;
+    Some(value.trim_end().to_string()).filter(|v| !v.is_empty())
+}
+
+/// Returns the directory git runs the hooks from, ex: `.git/hooks`, or the `core.hooksPath` setting when set.
+///
+/// # Returns
+/// * `Ok(PathBuf)` with the directory, relative to the current directory unless configured as an absolute path.
+/// * `Err(Error)` if git could not be run or the current directory is not in a repository.
+pub(crate) fn hooks_dir() -> Result<PathBuf, Error> {
+    Ok(PathBuf::from(run(&["rev-parse", "--git-path", "hooks"])?.trim_end()))
+}
+
+/// A commit of the history, as listed by `git log`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LoggedCommit {
+    /// The full SHA of the commit.
+    pub(crate) sha: String,
+    /// The raw commit message.
+    pub(crate) message: String,
+}
+
+/// Lists the commits in a revision range, newest first.
+///
+/// # Arguments
+/// * `range` - The revision range, ex: `main..HEAD`.
+///
+/// # Returns
+/// * `Ok(Vec<LoggedCommit>)` with one entry per commit in the range.
+/// * `Err(Error)` if git could not be run or the range is invalid.
+pub(crate) fn log(range: &str) -> Result<Vec<LoggedCommit>, Error> {
+    Ok(parse_log(&run(&["log", "-z", "--format=%H%n%B", range, "--"])?))
+}
+
+/// Checks whether a commit is an ancestor of, or the same as, another.
+///
+/// # Returns
+/// `true` if `ancestor` exists and is in the history of `descendant`, `false` otherwise.
+pub(crate) fn is_ancestor(ancestor: &str, descendant: &str) -> bool {
+    run(&["merge-base", "--is-ancestor", ancestor, descendant]).is_ok()
+}
+
+/// Lists the changes staged in the index.
+///
+/// # Returns
+/// * `Ok(Vec<StagedChange>)` with one entry per staged file.
+/// * `Err(Error)` if git could not be run.
+pub(crate) fn staged_changes() -> Result<Vec<StagedChange>, Error> {
+    Ok(parse_name_status(&run(&["diff", "--cached", "--name-status", "-z"])?))
+}
+
+/// Parses the NUL separated output of `git log -z --format=%H%n%B`, where each entry is a SHA followed by the message.
+fn parse_log(output: &str) -> Vec<LoggedCommit> {
+    output
+        .split('\0')
+        .filter_map(|entry| entry.split_once('\n'))
+        .map(|(sha, message)| LoggedCommit {
+            sha: sha.to_string(),
+            message: message.to_string(),
+        })
+        .collect()
+}
+
+/// Parses the NUL separated output of `git diff --name-status -z`.
+///
+/// Each entry is a status followed by one path, or two paths for renames and copies.
+fn parse_name_status(output: &str) -> Vec<StagedChange> {
+    let mut fields = output.split('\0').filter(|f| !f.is_empty());
+    let mut changes = Vec::new();
+
+    while let Some(status) = fields.next() {
+        let status = match status.chars().next() {
+            Some('A') => ChangeStatus::Added,
+            Some('D') => ChangeStatus::Deleted,
+            Some('R' | 'C') => {
+                fields.next();
+                ChangeStatus::Renamed
+            }
+            _ => ChangeStatus::Modified,
+        };
+
+        if let Some(path) = fields.next() {
+            changes.push(StagedChange { status, path: path.to_string() });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::empty("", vec![])]
+    #[case::added("A\0src/lib.rs\0", vec![StagedChange { status: ChangeStatus::Added, path: "src/lib.rs".into() }])]
+    #[case::modified_and_deleted(
+        "M\0a.rs\0D\0b.rs\0",
+        vec![StagedChange { status: ChangeStatus::Modified, path: "a.rs".into() }, StagedChange { status: ChangeStatus::Deleted, path: "b.rs".into() }]
+    )]
+    #[case::renamed("R100\0old.rs\0new.rs\0", vec![StagedChange { status: ChangeStatus::Renamed, path: "new.rs".into() }])]
+    fn test_parses_name_status_output(#[case] output: &str, #[case] expect: Vec<StagedChange>) {
+        assert_eq!(expect, parse_name_status(output));
+    }
+
+    #[rstest]
+    #[case::empty("", vec![])]
+    #[case::commits(
+        "aaa\nfeat: add lexer\n\nRefs: PROJ-1\n\0bbb\nfix: handle tabs\n",
+        vec![LoggedCommit { sha: "aaa".into(), message: "feat: add lexer\n\nRefs: PROJ-1\n".into() }, LoggedCommit { sha: "bbb".into(), message: "fix: handle tabs\n".into() }]
+    )]
+    fn test_parses_log_output(#[case] output: &str, #[case] expect: Vec<LoggedCommit>) {
+        assert_eq!(expect, parse_log(output));
+    }
+
+    #[rstest]
+    #[case::linux("git version 2.39.5\n", Some(Version(2, 39, 5)))]
+    #[case::windows("git version 2.37.1.windows.1\n", Some(Version(2, 37, 1)))]
+    #[case::apple("git version 2.37.1 (Apple Git-137.1)\n", Some(Version(2, 37, 1)))]
+    #[case::no_patch("git version 2.40\n", Some(Version(2, 40, 0)))]
+    #[case::unrecognised("hub version 2.14.2\n", None)]
+    fn test_parses_git_version(#[case] output: &str, #[case] expect: Option<Version>) {
+        assert_eq!(expect, parse_version(output));
+    }
+}
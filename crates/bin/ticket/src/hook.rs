@@ -0,0 +1,458 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `hook` subcommand.
+//!
+//! Runs as a git hook. `prepare-commit-msg` attaches the tickets found in the branch name to the message git is about to
+//! open in the editor, every one of them like `init` does, as footers or subject prefixes depending on the configured
+//! style. When amending, a ticket the commit already carries is kept as it is rather than detected again, since the
+//! branch may have changed since. It can also sign the message off with a `Signed-off-by` trailer from the git identity,
+//! once. The tickets are written with the configured ticket template, and a new message can have its subject started
+//! with the `type` the first match of the branch pattern captures when `prefill_type` is set. When the branch name holds
+//! no ticket, it can prompt for one on the terminal. A dry run prints the change as a unified diff instead of writing it.
+//!
+//! `commit-msg` rejects the finished message when it isn't a conventional commit, or breaks the configured rules, so git
+//! aborts the commit. Merge commits and the `fixup!`, `squash!` and `amend!` commits of `git rebase --autosquash` are
+//! let through, since git writes or rewrites their messages.
+//!
+//! Both hooks can be bypassed for a one-off commit. Setting `GIT_TICKET_DISABLE` to anything but `0` or an empty value
+//! disables them entirely: the message is left as it is, and neither the configuration nor the repository is read. A
+//! message can also opt out with a `Git-Ticket: skip` trailer, which the hooks honor once they have read it and leave in
+//! the message. The environment variable takes precedence, then the trailer.
+//!
+//! A hook script running `git-ticket` is recognised as ours by a line holding the `# git-ticket: managed hook` marker
+//! comment, which `install` writes, so `uninstall` only ever removes hooks written for the tool, not ones managed by husky
+//! or pre-commit.
+
+use crate::{
+    Error, branch, check,
+    config::{Config, Style},
+    git, init, prompt,
+    repository::Repository,
+};
+use conventional_commit::{
+    model::{Build, Commit, CommitKind, Footers, Person},
+    scissors,
+};
+use regex::Regex;
+use std::{env, fs, io::Write, path::Path};
+
+/// The environment variable disabling the hooks when set to anything but `0` or an empty value.
+const DISABLE_VARIABLE: &str = "GIT_TICKET_DISABLE";
+
+/// The token and value of the trailer opting a message out of the hooks, matched case-insensitively.
+const SKIP_TRAILER: (&str, &str) = ("Git-Ticket", "skip");
+
+/// The names of the git hooks `git-ticket` runs as.
+pub(crate) const NAMES: [&str; 2] = ["prepare-commit-msg", "commit-msg"];
+
+/// The comment line marking a hook script as written for `git-ticket`.
+pub(crate) const MARKER: &str = "# git-ticket: managed hook";
+
+/// The message source git passes to `prepare-commit-msg` when amending a commit.
+const AMEND_SOURCE: &str = "commit";
+
+/// The character starting comment lines when `core.commentChar` is unset, as git does.
+const DEFAULT_COMMENT_CHAR: char = '#';
+
+/// The prefix of the merge commit messages git writes, which `commit-msg` lets through without checking them.
+const MERGE_PREFIX: &str = "Merge ";
+
+/// Attaches the tickets found in the branch name to the commit message in the file, and signs it off when asked to.
+///
+/// # Arguments
+/// * `config` - The resolved configuration.
+/// * `file` - The file holding the commit message, the first argument git passes to the hook.
+/// * `source` - Where the message comes from, the second argument git passes to the hook, if any.
+/// * `sign_off` - Whether to add a `Signed-off-by` trailer from the git identity.
+/// * `interactive` - Whether to prompt for a ticket on the terminal when the branch name holds none.
+/// * `dry_run` - Whether to print the change as a unified diff rather than writing it.
+/// * `out` - Where the diff is written.
+///
+/// # Returns
+/// * `Ok(())` if the message was updated, or would be on a dry run, or left as it is because there is nothing to
+///   attach or it opts out with the skip trailer, which is looked for before anything else is read.
+/// * `Err(Error)` if not in a repository, the file could not be read or written, the identity is missing or invalid, or
+///   the configuration is invalid.
+pub(crate) fn prepare_commit_msg(config: &Config, file: &Path, source: Option<&str>, sign_off: bool, interactive: bool, dry_run: bool, out: &mut impl Write) -> Result<(), Error> {
+    let message = fs::read_to_string(file).map_err(|e| Error::Read(file.to_path_buf(), e))?;
+    let comment_char = comment_char().unwrap_or(DEFAULT_COMMENT_CHAR);
+    if is_skipped(&message, comment_char) {
+        return Ok(());
+    }
+
+    let pattern = Regex::new(&config.branch_pattern).map_err(|e| Error::Config(format!("`branch_pattern` {e}")))?;
+    let mut variables = Repository::discover()?.current_branch().map(|name| branch::variables(&pattern, &name)).unwrap_or_default();
+    if variables.is_empty() && interactive {
+        variables.extend(prompt::ticket(&pattern)?);
+    }
+    let sign_off = if sign_off { Some(init::sign_off()?) } else { None };
+    if variables.is_empty() && sign_off.is_none() {
+        return Ok(());
+    }
+
+    let amend = source == Some(AMEND_SOURCE);
+    let tickets: Vec<String> = variables.iter().map(|variables| branch::substitute(&config.ticket_template, variables)).collect();
+    let mut updated = attach(config, &pattern, &message, &tickets, amend, comment_char)?;
+    if let Some(person) = sign_off {
+        updated = sign(updated.as_deref().unwrap_or(&message), &person, comment_char).or(updated);
+    }
+    let commit_type = variables
+        .first()
+        .and_then(|v| v.get(branch::TYPE))
+        .filter(|t| config.prefill_type && !amend && !t.is_empty());
+    if let Some(commit_type) = commit_type {
+        updated = prefill(updated.as_deref().unwrap_or(&message), commit_type).or(updated);
+    }
+
+    match updated {
+        Some(updated) if dry_run => write!(out, "{}", check::diff(&file.display().to_string(), &message, &updated))?,
+        Some(updated) => fs::write(file, updated).map_err(|e| Error::Write(file.to_path_buf(), e))?,
+        None => {}
+    }
+
+    Ok(())
+}
+
+/// Checks that the finished commit message in the file is a conventional commit following the rules `check` enforces.
+///
+/// # Arguments
+/// * `config` - The resolved configuration.
+/// * `file` - The file holding the commit message, the argument git passes to the hook.
+///
+/// # Returns
+/// * `Ok(())` if the message is valid, or a merge, fixup, squash or amend commit.
+/// * `Err(Error::InvalidMessage)` with the problems found if it is not, which makes git abort the commit.
+/// * `Err(Error)` if the file could not be read.
+pub(crate) fn commit_msg(config: &Config, file: &Path) -> Result<(), Error> {
+    let message = fs::read_to_string(file).map_err(|e| Error::Read(file.to_path_buf(), e))?;
+    let comment_char = comment_char();
+    let message = Commit::clean_git_message(&message, comment_char);
+    if message.starts_with(MERGE_PREFIX) || is_skipped(&message, comment_char.unwrap_or(DEFAULT_COMMENT_CHAR)) {
+        return Ok(());
+    }
+
+    if let CommitKind::Conventional(commit) = Commit::parse_kind(&message)? {
+        check::enforce(config, &commit)?;
+    }
+    Ok(())
+}
+
+/// Checks whether the hooks are disabled by the `GIT_TICKET_DISABLE` environment variable.
+///
+/// # Returns
+/// `true` if the variable is set to anything but `0` or an empty value.
+pub(crate) fn is_disabled() -> bool {
+    env::var_os(DISABLE_VARIABLE).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Returns the script of a hook running `git-ticket hook`, carrying the `MARKER` line.
+///
+/// # Arguments
+/// * `name` - The name of the hook, one of `NAMES`.
+pub(crate) fn script(name: &str) -> String {
+    format!("#!/bin/sh\n{MARKER}\nexec git-ticket hook {name} \"$@\"\n")
+}
+
+/// Checks whether a hook script was written for `git-ticket`, by looking for the `MARKER` line.
+pub(crate) fn is_ours(script: &str) -> bool {
+    script.lines().any(|line| line.trim() == MARKER)
+}
+
+/// Checks whether the message opts out of the hooks with a `Git-Ticket: skip` trailer, above its comment lines.
+fn is_skipped(message: &str, comment_char: char) -> bool {
+    let (token, value) = SKIP_TRAILER;
+    Footers::parse(split_comments(message, comment_char).0)
+        .iter()
+        .any(|f| f.token().eq_ignore_ascii_case(token) && f.value().trim().eq_ignore_ascii_case(value))
+}
+
+/// Reads the character starting the comment lines git strips from messages, from the `core.commentChar` git setting.
+///
+/// # Returns
+/// * `Some(char)` if the setting is a single character.
+/// * `None` if it is unset, `auto` or several characters long, for the default `#` to be used.
+fn comment_char() -> Option<char> {
+    let value = git::config("core.commentChar")?;
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Attaches the tickets to the message, in the order they were found, keeping the comments git appends after it.
+///
+/// # Returns
+/// * `Ok(Some(String))` with the updated message.
+/// * `Ok(None)` if every ticket is already attached, or when amending a message that already carries a ticket.
+/// * `Err(Error)` if the configured footer token is invalid.
+fn attach(config: &Config, pattern: &Regex, message: &str, tickets: &[String], amend: bool, comment_char: char) -> Result<Option<String>, Error> {
+    let (content, comments) = split_comments(message, comment_char);
+
+    let content = match config.style {
+        Style::Footer => {
+            let footers = Footers::parse(content);
+            let mut missing = Vec::new();
+            for ticket in tickets {
+                let footer = config.ticket_footer(ticket)?;
+                if amend && footers.has_token(footer.token()) {
+                    return Ok(None);
+                }
+                if !footers.contains(&footer) && !missing.contains(&footer) {
+                    missing.push(footer);
+                }
+            }
+            if missing.is_empty() {
+                return Ok(None);
+            }
+
+            let missing = missing.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
+            match (content.is_empty(), footers.is_empty()) {
+                (true, _) => format!("\n\n{missing}"),
+                (false, true) => format!("{content}\n\n{missing}"),
+                (false, false) => format!("{content}\n{missing}"),
+            }
+        }
+        Style::Prefix => {
+            let (header, rest) = content.split_once('\n').unwrap_or((content, ""));
+            if amend && pattern.is_match(header) {
+                return Ok(None);
+            }
+            let missing: String = tickets.iter().filter(|ticket| !header.contains(ticket.as_str())).map(|t| format!("[{t}] ")).collect();
+            if missing.is_empty() {
+                return Ok(None);
+            }
+
+            match rest {
+                "" => format!("{missing}{header}"),
+                rest => format!("{missing}{header}\n{rest}"),
+            }
+        }
+    };
+
+    Ok(Some(format!("{content}\n{comments}")))
+}
+
+/// Appends the sign-off trailer to the message, keeping the comments git appends after it.
+///
+/// A trailer is already there when it has the same relationship and names the same person, with an email only
+/// differing in case, so signing off twice leaves the message as it is.
+///
+/// # Returns
+/// * `Some(String)` with the updated message.
+/// * `None` if the message is already signed off by the person.
+fn sign(message: &str, person: &Person, comment_char: char) -> Option<String> {
+    let (content, comments) = split_comments(message, comment_char);
+    let footers = Footers::parse(content);
+
+    let signed = footers.iter().filter(|f| f.token().eq_ignore_ascii_case(person.relationship())).any(|f| {
+        Person::builder("")
+            .from_spec(f.value())
+            .relationship(person.relationship())
+            .build()
+            .is_ok_and(|p| &p == person)
+    });
+    if signed {
+        return None;
+    }
+
+    let content = match (content.is_empty(), footers.is_empty()) {
+        (true, _) => format!("\n\n{person}"),
+        (false, true) => format!("{content}\n\n{person}"),
+        (false, false) => format!("{content}\n{person}"),
+    };
+
+    Some(format!("{content}\n{comments}"))
+}
+
+/// Starts the empty subject of the message with the commit type, ex: `feat: `, for the description to be written after.
+///
+/// # Returns
+/// * `Some(String)` with the updated message.
+/// * `None` if the message already has a subject.
+fn prefill(message: &str, commit_type: &str) -> Option<String> {
+    let (subject, rest) = message.split_once('\n').unwrap_or((message, ""));
+    if !subject.trim().is_empty() {
+        return None;
+    }
+
+    Some(format!("{commit_type}: \n{rest}"))
+}
+
+/// Splits the message from the trailing comment lines git adds for the editor, ex: `# Please enter the commit message`.
+///
+/// Only the part above the scissors line of `git commit --verbose` is looked at, the diff below it being discarded by
+/// git, so the scissors section always ends up with the comments.
+///
+/// # Arguments
+/// * `message` - The content of the commit message file.
+/// * `comment_char` - The character starting the comment lines and the scissors line, from `core.commentChar`.
+///
+/// # Returns
+/// The message without trailing white-space, and the comments with the blank lines before them and the scissors section.
+fn split_comments(message: &str, comment_char: char) -> (&str, &str) {
+    let cut_line = &scissors::SCISSORS[DEFAULT_COMMENT_CHAR.len_utf8()..];
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in message.split_inclusive('\n') {
+        if line.strip_prefix(comment_char).is_some_and(|rest| rest.trim_end() == cut_line) {
+            break;
+        }
+        offset += line.len();
+        if !line.trim().is_empty() && !line.starts_with(comment_char) {
+            end = offset;
+        }
+    }
+
+    (message[..end].trim_end(), &message[end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn config(style: Style) -> Config {
+        Config { style, ..Config::default() }
+    }
+
+    fn attach_ticket(style: Style, message: &str, amend: bool) -> Option<String> {
+        let config = config(style);
+        let pattern = Regex::new(&config.branch_pattern).expect("should have compiled the pattern");
+
+        attach(&config, &pattern, message, &["PROJ-2".into()], amend, DEFAULT_COMMENT_CHAR).expect("should have attached the ticket")
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer\n", Some("feat: add lexer\n\nRefs: PROJ-2\n"))]
+    #[case::body("feat: add lexer\n\nSplits the header.\n", Some("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-2\n"))]
+    #[case::other_footers("feat: add lexer\n\nReviewed-by: Alice\n", Some("feat: add lexer\n\nReviewed-by: Alice\nRefs: PROJ-2\n"))]
+    #[case::other_ticket("feat: add lexer\n\nRefs: PROJ-1\n", Some("feat: add lexer\n\nRefs: PROJ-1\nRefs: PROJ-2\n"))]
+    #[case::already_attached("feat: add lexer\n\nRefs: PROJ-2\n", None)]
+    #[case::empty("\n# Please enter the commit message\n", Some("\n\nRefs: PROJ-2\n\n# Please enter the commit message\n"))]
+    #[case::comments(
+        "feat: add lexer\n\n# Please enter the commit message\n# with '#' ignored\n",
+        Some("feat: add lexer\n\nRefs: PROJ-2\n\n# Please enter the commit message\n# with '#' ignored\n")
+    )]
+    #[case::verbose(
+        "feat: add lexer\n\n# Please enter the commit message\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\ndiff --git a/hello b/hello\n+hello\n",
+        Some(
+            "feat: add lexer\n\nRefs: PROJ-2\n\n# Please enter the commit message\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\ndiff --git a/hello b/hello\n+hello\n"
+        )
+    )]
+    fn test_attaches_footer(#[case] message: &str, #[case] expect: Option<&str>) {
+        assert_eq!(expect.map(String::from), attach_ticket(Style::Footer, message, false));
+    }
+
+    #[rstest]
+    #[case::footer_present("feat: add lexer\n\nRefs: PROJ-1\n\n# Please enter the commit message\n", None)]
+    #[case::footer_missing(
+        "feat: add lexer\n\n# Please enter the commit message\n",
+        Some("feat: add lexer\n\nRefs: PROJ-2\n\n# Please enter the commit message\n")
+    )]
+    #[case::other_footers("feat: add lexer\n\nReviewed-by: Alice\n", Some("feat: add lexer\n\nReviewed-by: Alice\nRefs: PROJ-2\n"))]
+    fn test_keeps_existing_footer_when_amending(#[case] message: &str, #[case] expect: Option<&str>) {
+        assert_eq!(expect.map(String::from), attach_ticket(Style::Footer, message, true));
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer\n", Some("feat: add lexer\n\nSigned-off-by: Alice <alice@test.io>\n"))]
+    #[case::other_footers("feat: add lexer\n\nRefs: PROJ-2\n", Some("feat: add lexer\n\nRefs: PROJ-2\nSigned-off-by: Alice <alice@test.io>\n"))]
+    #[case::other_person(
+        "feat: add lexer\n\nSigned-off-by: Bob <bob@test.io>\n",
+        Some("feat: add lexer\n\nSigned-off-by: Bob <bob@test.io>\nSigned-off-by: Alice <alice@test.io>\n")
+    )]
+    #[case::co_author(
+        "feat: add lexer\n\nCo-authored-by: Alice <alice@test.io>\n",
+        Some("feat: add lexer\n\nCo-authored-by: Alice <alice@test.io>\nSigned-off-by: Alice <alice@test.io>\n")
+    )]
+    #[case::already_signed_off("feat: add lexer\n\nSigned-off-by: Alice <alice@test.io>\n", None)]
+    #[case::email_case("feat: add lexer\n\nSigned-off-by: Alice <Alice@Test.io>\n", None)]
+    #[case::comments(
+        "feat: add lexer\n\n# Please enter the commit message\n",
+        Some("feat: add lexer\n\nSigned-off-by: Alice <alice@test.io>\n\n# Please enter the commit message\n")
+    )]
+    fn test_signs_off(#[case] message: &str, #[case] expect: Option<&str>) {
+        let person = Person::builder("Alice")
+            .email("alice@test.io")
+            .relationship(init::SIGN_OFF_RELATIONSHIP)
+            .build()
+            .expect("should have built a person");
+
+        assert_eq!(expect.map(String::from), sign(message, &person, DEFAULT_COMMENT_CHAR));
+    }
+
+    #[rstest]
+    #[case::empty("\n# Please enter the commit message\n", Some("feat: \n# Please enter the commit message\n"))]
+    #[case::ticket_attached("\n\nRefs: PROJ-2\n", Some("feat: \n\nRefs: PROJ-2\n"))]
+    #[case::blank_subject("  \n\nRefs: PROJ-2\n", Some("feat: \n\nRefs: PROJ-2\n"))]
+    #[case::subject("fix: handle tabs\n", None)]
+    fn test_prefills_type(#[case] message: &str, #[case] expect: Option<&str>) {
+        assert_eq!(expect.map(String::from), prefill(message, "feat"));
+    }
+
+    #[rstest]
+    #[case::trailer("feat: add lexer\n\nGit-Ticket: skip\n", true)]
+    #[case::case_insensitive("feat: add lexer\n\nRefs: PROJ-1\ngit-ticket: SKIP\n", true)]
+    #[case::before_comments("Update README\n\nGit-Ticket: skip\n\n# Please enter the commit message\n", true)]
+    #[case::other_value("feat: add lexer\n\nGit-Ticket: keep\n", false)]
+    #[case::in_body("feat: add lexer\n\nGit-Ticket: skip\n\nRefs: PROJ-1\n", false)]
+    #[case::none("feat: add lexer\n", false)]
+    fn test_detects_skip_trailer(#[case] message: &str, #[case] expect: bool) {
+        assert_eq!(expect, is_skipped(message, DEFAULT_COMMENT_CHAR));
+    }
+
+    #[rstest]
+    #[case::footers(Style::Footer, "feat: add lexer\n", Some("feat: add lexer\n\nRefs: PROJ-2\nRefs: PROJ-3\n"))]
+    #[case::footers_one_attached(Style::Footer, "feat: add lexer\n\nRefs: PROJ-3\n", Some("feat: add lexer\n\nRefs: PROJ-3\nRefs: PROJ-2\n"))]
+    #[case::footers_all_attached(Style::Footer, "feat: add lexer\n\nRefs: PROJ-2\nRefs: PROJ-3\n", None)]
+    #[case::prefixes(Style::Prefix, "feat: add lexer\n", Some("[PROJ-2] [PROJ-3] feat: add lexer\n"))]
+    #[case::prefixes_one_attached(Style::Prefix, "[PROJ-3] feat: add lexer\n", Some("[PROJ-2] [PROJ-3] feat: add lexer\n"))]
+    fn test_attaches_every_ticket(#[case] style: Style, #[case] message: &str, #[case] expect: Option<&str>) {
+        let config = config(style);
+        let pattern = Regex::new(&config.branch_pattern).expect("should have compiled the pattern");
+
+        assert_eq!(
+            expect.map(String::from),
+            attach(&config, &pattern, message, &["PROJ-2".into(), "PROJ-3".into()], false, DEFAULT_COMMENT_CHAR).expect("should have attached the tickets")
+        );
+    }
+
+    #[rstest]
+    #[case::default_comments('#', "feat: add lexer\n\n# Please enter the commit message\n", ("feat: add lexer", "\n# Please enter the commit message\n"))]
+    #[case::custom_comments(';', "feat: add lexer\n\n; Please enter the commit message\n", ("feat: add lexer", "\n; Please enter the commit message\n"))]
+    #[case::default_char_kept(';', "feat: add lexer\n\n# Not a comment\n", ("feat: add lexer\n\n# Not a comment", ""))]
+    #[case::custom_scissors(
+        ';',
+        "feat: add lexer\n; ------------------------ >8 ------------------------\ndiff --git a/hello b/hello\n",
+        ("feat: add lexer", "; ------------------------ >8 ------------------------\ndiff --git a/hello b/hello\n")
+    )]
+    fn test_splits_comments(#[case] comment_char: char, #[case] message: &str, #[case] expect: (&str, &str)) {
+        assert_eq!(expect, split_comments(message, comment_char));
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer\n", false, Some("[PROJ-2] feat: add lexer\n"))]
+    #[case::body("feat: add lexer\n\nSplits the header.\n", false, Some("[PROJ-2] feat: add lexer\n\nSplits the header.\n"))]
+    #[case::already_attached("[PROJ-2] feat: add lexer\n", false, None)]
+    #[case::other_ticket("[PROJ-1] feat: add lexer\n", false, Some("[PROJ-2] [PROJ-1] feat: add lexer\n"))]
+    #[case::amend_other_ticket("[PROJ-1] feat: add lexer\n", true, None)]
+    #[case::amend_without_ticket("feat: add lexer\n", true, Some("[PROJ-2] feat: add lexer\n"))]
+    fn test_attaches_prefix(#[case] message: &str, #[case] amend: bool, #[case] expect: Option<&str>) {
+        assert_eq!(expect.map(String::from), attach_ticket(Style::Prefix, message, amend));
+    }
+}
@@ -0,0 +1,265 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `init` subcommand.
+//!
+//! Writes the commit message template with the tickets found in the branch name already attached, and points git's
+//! `commit.template` setting at it so `git commit` opens the editor with them. Teams requiring a Developer Certificate
+//! of Origin can have a `Signed-off-by` trailer added from the git identity too. When the branch name holds no ticket, it
+//! can prompt for one on the terminal. A dry run prints the template instead.
+//!
+//! The tickets are followed by a scaffold: commented hints on writing a conventional commit by default, the content of
+//! a user-provided file, or nothing for a minimal template. The hints only use `#` comment lines, so git strips them from
+//! the message.
+
+use crate::{
+    Error, branch,
+    config::{Config, Style},
+    git, prompt,
+    repository::Repository,
+};
+use conventional_commit::model::{Build, CommitType, CommitTypeSet, Person};
+use regex::Regex;
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// The relationship of the sign-off trailer certifying the Developer Certificate of Origin.
+pub(crate) const SIGN_OFF_RELATIONSHIP: &str = "Signed-off-by";
+
+/// The commented hints on writing a conventional commit following the tickets by default, `{types}` standing for the
+/// allowed types.
+const HINTS: &str = "\
+# <type>[(<scope>)][!]: <description>
+#
+# Types: {types}
+# Mark breaking changes with a ! before the colon, or a `BREAKING CHANGE: <description>` footer.
+#
+# [optional body, explaining what changed and why]
+#
+# [optional footers, ex: Refs: <ticket>]
+";
+
+/// What follows the tickets and sign-off in the template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Scaffold {
+    /// The built-in commented hints on writing a conventional commit.
+    Hints,
+    /// Nothing, leaving a blank subject and the trailers.
+    Minimal,
+    /// The content of a user-provided file.
+    File(PathBuf),
+}
+
+impl Scaffold {
+    /// Picks the scaffold from the `init` flags, a user-provided file taking precedence.
+    pub(crate) fn new(minimal: bool, file: Option<PathBuf>) -> Self {
+        match (minimal, file) {
+            (_, Some(path)) => Scaffold::File(path),
+            (true, None) => Scaffold::Minimal,
+            (false, None) => Scaffold::Hints,
+        }
+    }
+
+    /// Reads the content of the scaffold.
+    ///
+    /// # Arguments
+    /// * `config` - The resolved configuration, whose `type-enum` rule lists the types the hints show when set.
+    ///
+    /// # Returns
+    /// * `Ok(String)` with the content, empty for a minimal template.
+    /// * `Err(Error::Read)` if the user-provided file could not be read.
+    fn content(&self, config: &Config) -> Result<String, Error> {
+        match self {
+            Scaffold::Hints => Ok(hints(config)),
+            Scaffold::Minimal => Ok(String::new()),
+            Scaffold::File(path) => read(path),
+        }
+    }
+}
+
+/// Writes the commit message template and sets `commit.template` to it in the repository's git configuration.
+///
+/// # Arguments
+/// * `config` - The resolved configuration.
+/// * `dco` - Whether to add a `Signed-off-by` trailer from the git identity.
+/// * `scaffold` - What follows the tickets and sign-off.
+/// * `interactive` - Whether to prompt for a ticket on the terminal when the branch name holds none.
+/// * `dry_run` - Whether to print the path and content of the template rather than writing and configuring it.
+/// * `out` - Where the path of the template, and its content on a dry run, are written.
+///
+/// # Returns
+/// * `Ok(())` if the template was written and configured, or would be on a dry run.
+/// * `Err(Error)` if not in a repository, the identity is missing or invalid, the scaffold could not be read, or the
+///   template could not be written or configured.
+pub(crate) fn run(config: &Config, dco: bool, scaffold: &Scaffold, interactive: bool, dry_run: bool, out: &mut impl Write) -> Result<(), Error> {
+    let pattern = Regex::new(&config.branch_pattern).map_err(|e| Error::Config(format!("`branch_pattern` {e}")))?;
+    let repository = Repository::discover()?;
+    let mut variables = repository.current_branch().map(|branch| branch::variables(&pattern, &branch)).unwrap_or_default();
+    if variables.is_empty() && interactive {
+        variables.extend(prompt::ticket(&pattern)?);
+    }
+    let tickets: Vec<String> = variables.iter().map(|variables| branch::substitute(&config.ticket_template, variables)).collect();
+    let sign_off = if dco { Some(sign_off()?) } else { None };
+
+    let content = template(config, &tickets, sign_off.as_ref(), &scaffold.content(config)?)?;
+    let path = config.template_file();
+
+    if dry_run {
+        writeln!(out, "would write {}:", path.display())?;
+        write!(out, "{content}")?;
+        writeln!(out, "would set commit.template to {}", path.display())?;
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| Error::Write(parent.to_path_buf(), e))?;
+    }
+    fs::write(&path, content).map_err(|e| Error::Write(path.clone(), e))?;
+    if repository.commit_template().as_deref() != Some(path.as_path()) {
+        git::run(&["config", "commit.template", &path.to_string_lossy()])?;
+    }
+
+    writeln!(out, "{}", path.display())?;
+    Ok(())
+}
+
+/// Fills the hints with the types the configured `type-enum` rule allows, or the standard types when it is unset.
+fn hints(config: &Config) -> String {
+    let types = match &config.rules.type_enum {
+        Some(types) => types.join(", "),
+        None => CommitTypeSet::default().iter().map(CommitType::as_str).collect::<Vec<_>>().join(", "),
+    };
+    HINTS.replace("{types}", &types)
+}
+
+/// Builds the content of the template: an empty subject to write, followed by the tickets and sign-off, then the scaffold
+/// after a blank line.
+fn template(config: &Config, tickets: &[String], sign_off: Option<&Person>, scaffold: &str) -> Result<String, Error> {
+    let mut subject = String::new();
+    let mut trailers = Vec::new();
+
+    match config.style {
+        Style::Footer => {
+            for ticket in tickets {
+                trailers.push(config.ticket_footer(ticket)?.to_string());
+            }
+        }
+        Style::Prefix => subject = tickets.iter().map(|t| format!("[{t}] ")).collect(),
+    }
+
+    trailers.extend(sign_off.map(ToString::to_string));
+
+    let mut content = if trailers.is_empty() {
+        format!("{subject}\n")
+    } else {
+        format!("{subject}\n\n{}\n", trailers.join("\n"))
+    };
+
+    let scaffold = scaffold.trim_end();
+    if !scaffold.is_empty() {
+        content = format!("{content}\n{scaffold}\n");
+    }
+
+    Ok(content)
+}
+
+/// Reads the user-provided scaffold.
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::Read(path.to_path_buf(), e))
+}
+
+/// Reads the sign-off trailer from the `user.name` and `user.email` git settings.
+///
+/// # Returns
+/// * `Ok(Person)` with the `Signed-off-by` relationship.
+/// * `Err(Error::Identity)` if either setting is unset, or the email is invalid.
+pub(crate) fn sign_off() -> Result<Person, Error> {
+    let (Some(name), Some(email)) = (git::config("user.name"), git::config("user.email")) else {
+        return Err(Error::Identity("set user.name and user.email with `git config` to sign off commits".into()));
+    };
+
+    Person::builder(name)
+        .email(email)
+        .relationship(SIGN_OFF_RELATIONSHIP)
+        .build()
+        .map_err(|e| Error::Identity(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use conventional_commit::lint::RuleConfig;
+    use rstest::rstest;
+
+    fn tickets(tickets: &[&str]) -> Vec<String> {
+        tickets.iter().map(ToString::to_string).collect()
+    }
+
+    #[rstest]
+    #[case::no_ticket(Style::Footer, &[], false, "\n")]
+    #[case::footer(Style::Footer, &["PROJ-1"], false, "\n\nRefs: PROJ-1\n")]
+    #[case::several_footers(Style::Footer, &["PROJ-1", "OPS-2"], false, "\n\nRefs: PROJ-1\nRefs: OPS-2\n")]
+    #[case::prefix(Style::Prefix, &["PROJ-1"], false, "[PROJ-1] \n")]
+    #[case::footer_and_sign_off(Style::Footer, &["PROJ-1"], true, "\n\nRefs: PROJ-1\nSigned-off-by: Alice <alice@test.io>\n")]
+    #[case::sign_off_only(Style::Prefix, &[], true, "\n\nSigned-off-by: Alice <alice@test.io>\n")]
+    fn test_builds_template(#[case] style: Style, #[case] detected: &[&str], #[case] dco: bool, #[case] expect: &str) {
+        let config = Config { style, ..Config::default() };
+        let sign_off = Person::builder("Alice")
+            .email("alice@test.io")
+            .relationship(SIGN_OFF_RELATIONSHIP)
+            .build()
+            .expect("should have built a person");
+
+        let template = template(&config, &tickets(detected), dco.then_some(&sign_off), "").expect("should have built the template");
+
+        assert_eq!(expect, template);
+    }
+
+    #[rstest]
+    #[case::no_ticket(&[], "\n\n# Write a subject\n")]
+    #[case::footer(&["PROJ-1"], "\n\nRefs: PROJ-1\n\n# Write a subject\n")]
+    fn test_appends_scaffold(#[case] detected: &[&str], #[case] expect: &str) {
+        let template = template(&Config::default(), &tickets(detected), None, "# Write a subject\n\n").expect("should have built the template");
+
+        assert_eq!(expect, template);
+    }
+
+    #[test]
+    fn test_hints_are_comments_with_placeholders() {
+        let hints = Scaffold::Hints.content(&Config::default()).expect("should have read the hints");
+
+        assert!(hints.lines().all(|line| line.starts_with('#')), "every line should be a comment:\n{hints}");
+        for placeholder in ["<type>", "<scope>", "<description>", "BREAKING CHANGE", "Refs: <ticket>"] {
+            assert!(hints.contains(placeholder), "should contain {placeholder}:\n{hints}");
+        }
+    }
+
+    #[rstest]
+    #[case::standard_types(None, "# Types: feat, fix, build, chore, ci, docs, style, refactor, perf, test, revert\n")]
+    #[case::configured_types(Some(vec!["feat".into(), "fix".into(), "wip".into()]), "# Types: feat, fix, wip\n")]
+    fn test_hints_list_allowed_types(#[case] type_enum: Option<Vec<String>>, #[case] expect: &str) {
+        let config = Config {
+            rules: RuleConfig {
+                type_enum,
+                ..RuleConfig::default()
+            },
+            ..Config::default()
+        };
+
+        assert!(hints(&config).contains(expect), "should list the types:\n{}", hints(&config));
+    }
+}
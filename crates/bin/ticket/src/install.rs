@@ -0,0 +1,73 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `install` subcommand.
+//!
+//! Writes the `prepare-commit-msg` and `commit-msg` hooks of the repository, to `core.hooksPath` when set, as scripts
+//! running `git-ticket hook` and carrying the `git-ticket` marker, so `doctor` recognises them and `uninstall` removes
+//! them. A hook managed by another tool, ex: husky or pre-commit, is left in place with a warning unless overwriting it
+//! is forced.
+
+use crate::{Error, git, hook, repository::Repository};
+use std::{fs, io::Write, path::Path};
+
+/// Writes the hooks running `git-ticket`, replacing the ones written for it before.
+///
+/// # Arguments
+/// * `force` - Whether to overwrite the hooks even when they were not written for `git-ticket`.
+/// * `out` - Where each hook installed is reported.
+///
+/// # Returns
+/// * `Ok(())` if every hook that is ours, missing, or forced, was written.
+/// * `Err(Error)` if not in a repository, or a hook could not be read or written.
+pub(crate) fn run(force: bool, out: &mut impl Write) -> Result<(), Error> {
+    Repository::discover()?;
+    let dir = git::hooks_dir()?;
+    fs::create_dir_all(&dir).map_err(|e| Error::Write(dir.clone(), e))?;
+
+    for name in hook::NAMES {
+        let path = dir.join(name);
+        if path.is_file() {
+            let script = fs::read_to_string(&path).map_err(|e| Error::Read(path.clone(), e))?;
+            if !force && !hook::is_ours(&script) {
+                eprintln!(
+                    "warning: {} was not installed by git-ticket, leaving it in place, use --force to overwrite it",
+                    path.display()
+                );
+                continue;
+            }
+        }
+
+        fs::write(&path, hook::script(name)).map_err(|e| Error::Write(path.clone(), e))?;
+        make_executable(&path).map_err(|e| Error::Write(path.clone(), e))?;
+        writeln!(out, "installed {}", path.display())?;
+    }
+
+    Ok(())
+}
+
+/// Lets git run the hook, which it skips unless the script is executable.
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    fs::set_permissions(path, permissions)
+}
+
+/// Lets git run the hook, scripts being run through the shell git ships with whatever their permissions.
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
@@ -11,8 +11,29 @@
  * If not, see https://www.gnu.org/licenses/.
  */
 
-use clap::Parser;
-use std::ffi::OsString;
+use clap::{Parser, Subcommand, ValueEnum};
+use config::{Config, Overrides};
+use std::{ffi::OsString, io::Write, path::PathBuf};
+
+mod areas;
+mod branch;
+mod check;
+mod codeowners;
+mod config;
+mod detect;
+mod doctor;
+mod error;
+mod git;
+mod hook;
+mod init;
+mod install;
+mod list;
+mod prompt;
+mod repository;
+mod suggest;
+mod uninstall;
+
+pub use error::Error;
 
 #[derive(Parser)]
 #[command(name = "Git Ticket")]
@@ -21,8 +42,174 @@ use std::ffi::OsString;
 #[command(long_about = Some("Attaches ticket(s) to your commit messages. This is done via the git commit message template.
 Please ensure to set the path to this file in your git configuration using \
 `git config --global commit.template ~/.gitmessage.txt`.
-By default the file will be created in your home directory with the name ~/.gitmessage.txt, but this can be overridden."))]
-pub struct Args {}
+By default the file will be created in your home directory, your user profile on Windows, with the name ~/.gitmessage.txt, \
+but this can be overridden. A leading ~ in the path is expanded to your home directory on every platform."))]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    overrides: Overrides,
+
+    /// How results and errors are printed
+    #[arg(long, global = true, value_enum, default_value_t)]
+    format: Format,
+}
+
+/// How `git-ticket` prints its results and errors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable text.
+    #[default]
+    Text,
+    /// JSON, for editors and other tools.
+    Json,
+}
+
+/// The subcommands supported by `git-ticket`.
+#[derive(Subcommand)]
+enum Command {
+    /// Lists the configured areas touched by the staged changes
+    Areas {
+        /// The scope of the commit, tagging the areas listing it
+        #[arg(long)]
+        scope: Option<String>,
+    },
+    /// Checks that a commit message follows the conventional commits specification
+    Check {
+        /// The file holding the commit message, read from standard input when omitted
+        file: Option<PathBuf>,
+
+        /// Prints a unified diff fixing the problems that can be fixed automatically
+        #[arg(long)]
+        suggest: bool,
+
+        /// Prints the rule each problem comes from, as `rule: message` lines
+        #[arg(long)]
+        explain: bool,
+
+        /// Reads a JSON array of `{"message": ...}` objects from standard input and prints the result of each as JSON
+        #[arg(long, conflicts_with_all = ["file", "suggest", "explain"])]
+        json_input: bool,
+
+        /// Checks the message of every commit in a revision range, ex: `main..HEAD`
+        #[arg(long, value_name = "RANGE", conflicts_with_all = ["file", "suggest", "explain", "json_input"])]
+        range: Option<String>,
+
+        /// Also reports revert commits referencing a commit that is not in the history of the reverting commit
+        #[arg(long, requires = "range")]
+        verify_reverts: bool,
+    },
+    /// Prints the resolved configuration
+    Config,
+    /// Prints the tickets found in the branch name and how they are attached
+    Detect,
+    /// Checks the repository, template, hooks and branch are set up for git-ticket, with hints fixing what isn't
+    Doctor,
+    /// Runs as a git hook
+    Hook {
+        #[command(subcommand)]
+        hook: Hook,
+    },
+    /// Writes the commit message template with the branch's tickets and sets `commit.template` to it
+    Init {
+        /// Adds a `Signed-off-by` trailer from `user.name` and `user.email`, for the Developer Certificate of Origin
+        #[arg(long, visible_alias = "sign-off")]
+        dco: bool,
+
+        /// Leaves out the commented hints, writing a blank subject followed by the tickets only
+        #[arg(long)]
+        minimal: bool,
+
+        /// Follows the tickets with the content of this file instead of the commented hints
+        #[arg(long, value_name = "PATH", conflicts_with = "minimal")]
+        template_file: Option<PathBuf>,
+
+        /// Prompts for a ticket on the terminal when none is found in the branch name
+        #[arg(long)]
+        interactive: bool,
+
+        /// Prints the path and content of the template instead of writing and configuring it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Installs the git hooks running git-ticket, leaving hooks managed by other tools in place
+    Install {
+        /// Overwrites the hooks even when they were not installed for git-ticket
+        #[arg(long)]
+        force: bool,
+    },
+    /// Lists the tickets that would be attached, and whether they match the branch pattern
+    List {
+        /// A ticket to attach on top of the ones found in the branch name, can be repeated
+        #[arg(long = "ticket", value_name = "TICKET")]
+        tickets: Vec<String>,
+    },
+    /// Suggests a conventional commit header from the staged changes
+    Suggest,
+    /// Removes the git hooks installed for git-ticket, leaving hooks managed by other tools in place
+    Uninstall {
+        /// Also unsets `commit.template` in the repository's git configuration
+        #[arg(long)]
+        unset_template: bool,
+
+        /// Removes the hooks even when they were not installed for git-ticket
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// The git hooks supported by `git-ticket hook`.
+#[derive(Subcommand)]
+enum Hook {
+    /// Attaches the ticket found in the branch name to the commit message being prepared
+    PrepareCommitMsg {
+        /// The file holding the commit message
+        file: PathBuf,
+
+        /// Where the message comes from, ex: `commit` when amending
+        source: Option<String>,
+
+        /// The commit being amended or reused
+        sha: Option<String>,
+
+        /// Adds a `Signed-off-by` trailer from `user.name` and `user.email`, unless the message is already signed off
+        #[arg(long)]
+        sign_off: bool,
+
+        /// Prompts for a ticket on the terminal when none is found in the branch name
+        #[arg(long)]
+        interactive: bool,
+
+        /// Prints a unified diff of the change to the message instead of writing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Rejects the commit message when it is not a conventional commit or breaks the configured rules
+    CommitMsg {
+        /// The file holding the commit message
+        file: PathBuf,
+    },
+}
+
+impl Command {
+    /// Returns whether the subcommand shells out to git, and so needs it installed.
+    fn requires_git(&self) -> bool {
+        matches!(
+            self,
+            Command::Areas { .. }
+                | Command::Check { range: Some(_), .. }
+                | Command::Detect
+                | Command::Doctor
+                | Command::Hook { .. }
+                | Command::Init { .. }
+                | Command::Install { .. }
+                | Command::List { .. }
+                | Command::Suggest
+                | Command::Uninstall { .. }
+        )
+    }
+}
 
 impl Args {
     pub fn parse_from_args<ITER, ARG>(args: ITER) -> Self
@@ -32,4 +219,72 @@ impl Args {
     {
         Args::parse_from(args)
     }
+
+    /// Returns how results and errors should be printed.
+    #[must_use]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Runs the selected subcommand, writing its output to `out`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if the subcommand fails.
+    pub fn run(self, out: &mut impl Write) -> Result<(), Error> {
+        let Some(command) = self.command else {
+            return Ok(());
+        };
+
+        if matches!(command, Command::Hook { .. }) && hook::is_disabled() {
+            return Ok(());
+        }
+        if command.requires_git() {
+            git::ensure_available()?;
+        }
+
+        match command {
+            Command::Areas { scope } => areas::run(&Config::load(&self.overrides)?.areas, scope.as_deref(), out),
+            Command::Check { json_input: true, .. } => check::run_json(&Config::load(&self.overrides)?, out),
+            Command::Check {
+                range: Some(range),
+                verify_reverts,
+                ..
+            } => check::run_range(&Config::load(&self.overrides)?, &range, verify_reverts),
+            Command::Check { file, suggest, explain, .. } => check::run(&Config::load(&self.overrides)?, file.as_deref(), suggest, explain, out),
+            Command::Config => config::run(&self.overrides, out),
+            Command::Detect => detect::run(&Config::load(&self.overrides)?, self.format, out),
+            Command::Doctor => doctor::run(&self.overrides, out),
+            Command::Hook {
+                hook:
+                    Hook::PrepareCommitMsg {
+                        file,
+                        source,
+                        sign_off,
+                        interactive,
+                        dry_run,
+                        ..
+                    },
+            } => hook::prepare_commit_msg(&Config::load(&self.overrides)?, &file, source.as_deref(), sign_off, interactive, dry_run, out),
+            Command::Hook { hook: Hook::CommitMsg { file } } => hook::commit_msg(&Config::load(&self.overrides)?, &file),
+            Command::Init {
+                dco,
+                minimal,
+                template_file,
+                interactive,
+                dry_run,
+            } => init::run(
+                &Config::load(&self.overrides)?,
+                dco,
+                &init::Scaffold::new(minimal, template_file),
+                interactive,
+                dry_run,
+                out,
+            ),
+            Command::Install { force } => install::run(force, out),
+            Command::List { tickets } => list::run(&Config::load(&self.overrides)?, &tickets, self.format, out),
+            Command::Suggest => suggest::run(out),
+            Command::Uninstall { unset_template, force } => uninstall::run(unset_template, force, out),
+        }
+    }
 }
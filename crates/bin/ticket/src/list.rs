@@ -0,0 +1,156 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `list` subcommand.
+//!
+//! Lists every ticket `git-ticket` would attach, from the branch name and the `--ticket` flags, along with whether each
+//! matches the configured branch pattern. It only reports: tickets that don't match are flagged but never rejected.
+
+use crate::{Error, Format, branch, config::Config, detect, repository::Repository};
+use regex::Regex;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Where a listed ticket comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Source {
+    /// Found in the name of the current branch.
+    Branch,
+    /// Given with the `--ticket` flag.
+    Flag,
+}
+
+/// A ticket that would be attached to the next commit.
+#[derive(Debug, PartialEq, Serialize)]
+struct Ticket {
+    /// The ticket, ex: `PROJ-123`.
+    ticket: String,
+    /// Where the ticket comes from.
+    source: Source,
+    /// Whether the whole ticket matches the `ticket` capture of the branch pattern.
+    valid: bool,
+}
+
+/// The tickets that would be attached to the next commit, and how.
+#[derive(Debug, PartialEq, Serialize)]
+struct Listing<'a> {
+    /// The tickets, the ones from the branch first, without duplicates.
+    tickets: Vec<Ticket>,
+    /// The footer token tickets are attached with, the closing keyword when one is set.
+    footer_token: &'a str,
+}
+
+/// Prints the tickets found in the branch name and given as flags, with the footer token attaching them.
+///
+/// # Arguments
+/// * `config` - The resolved configuration.
+/// * `flags` - The tickets given with the `--ticket` flag.
+/// * `format` - Whether to print text or a JSON object.
+/// * `out` - Where the listing is written.
+///
+/// # Returns
+/// * `Ok(())` if the listing was printed, even when some tickets don't match the branch pattern.
+/// * `Err(Error)` if the branch pattern is invalid.
+pub(crate) fn run(config: &Config, flags: &[String], format: Format, out: &mut impl Write) -> Result<(), Error> {
+    let pattern = Regex::new(&config.branch_pattern).map_err(|e| Error::Config(format!("`branch_pattern` {e}")))?;
+    let branch = Repository::discover().ok().as_ref().and_then(Repository::current_branch).unwrap_or_default();
+    let listing = Listing {
+        tickets: list(&pattern, &branch, flags),
+        footer_token: config.ticket_token(),
+    };
+
+    match format {
+        Format::Text => {
+            if listing.tickets.is_empty() {
+                writeln!(out, "tickets: none")?;
+            } else {
+                writeln!(out, "tickets:")?;
+            }
+            for ticket in &listing.tickets {
+                let source = match ticket.source {
+                    Source::Branch => "branch",
+                    Source::Flag => "flag",
+                };
+                write!(out, "  {} from {source}", ticket.ticket)?;
+                if !ticket.valid {
+                    write!(out, ", warning: does not match the branch pattern `{}`", config.branch_pattern)?;
+                }
+                writeln!(out)?;
+            }
+            writeln!(out, "footer token: {}", listing.footer_token)?;
+        }
+        Format::Json => writeln!(out, "{}", serde_json::to_string(&listing).map_err(io::Error::from)?)?,
+    }
+
+    Ok(())
+}
+
+/// Lists the distinct tickets of the branch name then of the flags, checking each against the pattern.
+fn list(pattern: &Regex, branch: &str, flags: &[String]) -> Vec<Ticket> {
+    let mut tickets: Vec<Ticket> = Vec::new();
+    let found = detect::tickets(pattern, branch).into_iter().map(|t| (t, Source::Branch));
+
+    for (ticket, source) in found.chain(flags.iter().map(|t| (t.trim().to_string(), Source::Flag))) {
+        if !ticket.is_empty() && !tickets.iter().any(|t| t.ticket == ticket) {
+            let valid = source == Source::Branch || branch::ticket(pattern, &ticket).is_some();
+            tickets.push(Ticket { ticket, source, valid });
+        }
+    }
+
+    tickets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn ticket(ticket: &str, source: Source, valid: bool) -> Ticket {
+        Ticket {
+            ticket: ticket.into(),
+            source,
+            valid,
+        }
+    }
+
+    #[rstest]
+    #[case::none("main", vec![], vec![])]
+    #[case::branch_only("feature/PROJ-1+OPS-2", vec![], vec![ticket("PROJ-1", Source::Branch, true), ticket("OPS-2", Source::Branch, true)])]
+    #[case::flags_only("main", vec!["PROJ-3"], vec![ticket("PROJ-3", Source::Flag, true)])]
+    #[case::branch_and_flags("feature/PROJ-1", vec!["OPS-2", "PROJ-1"], vec![ticket("PROJ-1", Source::Branch, true), ticket("OPS-2", Source::Flag, true)])]
+    #[case::invalid_flag("main", vec!["proj-1", "PROJ-1-extra"], vec![ticket("proj-1", Source::Flag, false), ticket("PROJ-1-extra", Source::Flag, false)])]
+    #[case::blank_flag("main", vec!["  "], vec![])]
+    fn test_lists_tickets(#[case] branch: &str, #[case] flags: Vec<&str>, #[case] expect: Vec<Ticket>) {
+        let pattern = Regex::new(&Config::default().branch_pattern).expect("should have compiled the pattern");
+        let flags: Vec<String> = flags.into_iter().map(String::from).collect();
+
+        assert_eq!(expect, list(&pattern, branch, &flags));
+    }
+
+    #[test]
+    fn test_lists_flags_with_named_pattern() {
+        let pattern = Regex::new(r"(?<type>[a-z]+)/(?<ticket>[A-Z]+-\d+)").expect("should have compiled the pattern");
+        let flags = vec!["OPS-2".to_string(), "fix/OPS-3".to_string()];
+
+        assert_eq!(
+            vec![
+                ticket("PROJ-1", Source::Branch, true),
+                ticket("OPS-2", Source::Flag, true),
+                ticket("fix/OPS-3", Source::Flag, false)
+            ],
+            list(&pattern, "feature/PROJ-1", &flags)
+        );
+    }
+}
@@ -11,8 +11,30 @@
  * If not, see https://www.gnu.org/licenses/.
  */
 
-use git_ticket::Args;
+use git_ticket::{Args, Format};
+use std::{
+    env,
+    io::{self, IsTerminal},
+    process::ExitCode,
+};
 
-fn main() {
-    Args::parse_from_args(std::env::args_os());
+fn main() -> ExitCode {
+    let args = Args::parse_from_args(std::env::args_os());
+    let format = args.format();
+
+    match args.run(&mut io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            match format {
+                Format::Text => eprintln!("error: {}", err.render(use_color())),
+                Format::Json => eprintln!("{}", err.to_json()),
+            }
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+/// Checks whether errors should be colored, when standard error is a terminal and `NO_COLOR` is unset or empty.
+fn use_color() -> bool {
+    io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
 }
@@ -0,0 +1,126 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Prompting for a ticket on the terminal, when none is found in the branch name.
+//!
+//! The prompt is written to standard error and only shown when it is a terminal, so scripts and tests never block on
+//! it. Answers are read from standard input when it is a terminal too, or else from the console, as git hooks run with
+//! standard input closed.
+
+use crate::{
+    Error,
+    branch::{self, Variables},
+};
+use regex::Regex;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, IsTerminal, Write},
+};
+
+/// The console device answers are read from when standard input isn't a terminal.
+#[cfg(not(windows))]
+const CONSOLE: &str = "/dev/tty";
+
+/// The console device answers are read from when standard input isn't a terminal.
+#[cfg(windows)]
+const CONSOLE: &str = "CONIN$";
+
+/// Prompts for a ticket on the terminal until a valid one, or none, is entered.
+///
+/// # Arguments
+/// * `pattern` - The branch pattern tickets must match.
+///
+/// # Returns
+/// * `Ok(Some(Variables))` with the variables captured from the ticket entered.
+/// * `Ok(None)` if nothing was entered, or there is no terminal to prompt on.
+/// * `Err(Error)` if the terminal could not be read or written.
+pub(crate) fn ticket(pattern: &Regex) -> Result<Option<Variables>, Error> {
+    if !io::stderr().is_terminal() {
+        return Ok(None);
+    }
+
+    if io::stdin().is_terminal() {
+        ask(pattern, &mut io::stdin().lock(), &mut io::stderr())
+    } else {
+        match File::open(CONSOLE) {
+            Ok(console) => ask(pattern, &mut BufReader::new(console), &mut io::stderr()),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Asks for a ticket until the answer is one the `ticket` capture of the branch pattern matches whole, or is empty.
+fn ask(pattern: &Regex, input: &mut impl BufRead, output: &mut impl Write) -> Result<Option<Variables>, Error> {
+    loop {
+        write!(output, "ticket (leave empty to skip): ")?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(output)?;
+            return Ok(None);
+        }
+
+        let answer = line.trim();
+        if answer.is_empty() {
+            return Ok(None);
+        }
+        if let Some(variables) = branch::ticket(pattern, answer) {
+            return Ok(Some(variables));
+        }
+
+        writeln!(output, "'{answer}' does not match the branch pattern `{}`, try again", pattern.as_str())?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    #[rstest]
+    #[case::valid("PROJ-1\n", Some("PROJ-1"), "ticket (leave empty to skip): ")]
+    #[case::surrounding_white_space("  PROJ-1  \n", Some("PROJ-1"), "ticket (leave empty to skip): ")]
+    #[case::retries(
+        "proj 1\nPROJ-1 and more\nPROJ-1\n",
+        Some("PROJ-1"),
+        "ticket (leave empty to skip): 'proj 1' does not match the branch pattern `[A-Z]+-\\d+`, try again\nticket (leave empty to skip): 'PROJ-1 and more' does not match the branch pattern `[A-Z]+-\\d+`, try again\nticket (leave empty to skip): "
+    )]
+    #[case::empty("\n", None, "ticket (leave empty to skip): ")]
+    #[case::end_of_input("", None, "ticket (leave empty to skip): \n")]
+    fn test_asks_for_ticket(#[case] input: &str, #[case] expect: Option<&str>, #[case] prompt: &str) {
+        let pattern = Regex::new(r"[A-Z]+-\d+").expect("should have compiled the pattern");
+        let mut output = Vec::new();
+
+        let variables = ask(&pattern, &mut Cursor::new(input), &mut output).expect("should have asked for a ticket");
+
+        assert_eq!(expect, variables.as_ref().map(|v| v[branch::TICKET].as_str()));
+        assert_eq!(prompt, String::from_utf8_lossy(&output));
+    }
+
+    #[test]
+    fn test_asks_for_ticket_with_named_pattern() {
+        let pattern = Regex::new(r"(?<type>[a-z]+)/(?<ticket>[A-Z]+-\d+)").expect("should have compiled the pattern");
+        let mut output = Vec::new();
+
+        let variables = ask(&pattern, &mut Cursor::new("feature/PROJ-1\nPROJ-1\n"), &mut output).expect("should have asked for a ticket");
+
+        assert_eq!(Some(Variables::from([("ticket".into(), "PROJ-1".into()), ("type".into(), String::new())])), variables);
+        assert_eq!(
+            "ticket (leave empty to skip): 'feature/PROJ-1' does not match the branch pattern `(?<type>[a-z]+)/(?<ticket>[A-Z]+-\\d+)`, try again\nticket (leave empty to skip): ",
+            String::from_utf8_lossy(&output)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Discovery of the repository `git-ticket` runs in.
+//!
+//! The repository is found by searching the current directory and its parents, the way git does, through `gix` rather
+//! than the git binary. Linked worktrees and submodules, whose `.git` is a file pointing at the actual git directory,
+//! are followed too.
+
+use crate::Error;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// The git setting holding the commit message template git opens the editor with.
+const COMMIT_TEMPLATE_KEY: &str = "commit.template";
+
+/// A git repository, ex: the one holding the current directory.
+pub(crate) struct Repository(gix::Repository);
+
+impl Repository {
+    /// Finds the repository holding the current directory.
+    ///
+    /// # Returns
+    /// * `Ok(Repository)` with the closest repository.
+    /// * `Err(Error::NotARepository)` if neither the current directory nor any of its parents is in a repository.
+    pub(crate) fn discover() -> Result<Self, Error> {
+        Repository::discover_from(&env::current_dir()?)
+    }
+
+    /// Finds the repository holding a directory.
+    ///
+    /// # Arguments
+    /// * `dir` - Where to start searching, upwards.
+    ///
+    /// # Returns
+    /// * `Ok(Repository)` with the closest repository.
+    /// * `Err(Error::NotARepository)` if neither the directory nor any of its parents is in a repository.
+    pub(crate) fn discover_from(dir: &Path) -> Result<Self, Error> {
+        gix::discover(dir).map(Repository).map_err(|e| Error::NotARepository(dir.to_path_buf(), Box::new(e)))
+    }
+
+    /// Returns the name of the branch checked out, ex: `feature/PROJ-123`.
+    ///
+    /// # Returns
+    /// * `Some(String)` with the short name of the branch, even when it has no commits yet.
+    /// * `None` if `HEAD` is detached or can't be read.
+    pub(crate) fn current_branch(&self) -> Option<String> {
+        let name = self.0.head_name().ok()??;
+        Some(name.shorten().to_string()).filter(|b| !b.is_empty())
+    }
+
+    /// Returns the top level directory of the working tree.
+    ///
+    /// # Returns
+    /// * `Some(&Path)` with the root of the working tree, or of the linked worktree when in one.
+    /// * `None` if the repository is bare.
+    pub(crate) fn root(&self) -> Option<&Path> {
+        self.0.workdir()
+    }
+
+    /// Returns the git directory, ex: `.git` or `.git/worktrees/<name>` for a linked worktree.
+    pub(crate) fn git_dir(&self) -> &Path {
+        self.0.git_dir()
+    }
+
+    /// Returns the commit message template git is configured with, if any.
+    ///
+    /// # Returns
+    /// * `Some(PathBuf)` with the value of `commit.template`, as written in the configuration.
+    /// * `None` if the setting is unset or empty.
+    pub(crate) fn commit_template(&self) -> Option<PathBuf> {
+        let value = self.0.config_snapshot().string(COMMIT_TEMPLATE_KEY)?;
+        Some(PathBuf::from(value.to_string())).filter(|p| !p.as_os_str().is_empty())
+    }
+}
+
+/// Returns the top level directory of the working tree holding the current directory, if any.
+pub(crate) fn root() -> Option<PathBuf> {
+    Repository::discover().ok()?.root().map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{fs, process};
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = process::Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@test.io"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("should have run git");
+        assert!(output.status.success(), "git {} failed: {}", args.join(" "), String::from_utf8_lossy(&output.stderr));
+    }
+
+    fn repo(branch: &str) -> TempDir {
+        let repo = tempfile::tempdir().expect("should have created a temp dir");
+        git(repo.path(), &["init", "--quiet"]);
+        git(repo.path(), &["checkout", "--quiet", "-b", branch]);
+        repo
+    }
+
+    fn canonical(path: &Path) -> PathBuf {
+        path.canonicalize().expect("should have canonicalized the path")
+    }
+
+    #[test]
+    fn test_discovers_repository_from_subdirectory() {
+        let repo = repo("feature/PROJ-1");
+        let dir = repo.path().join("src/parser");
+        fs::create_dir_all(&dir).expect("should have created the directory");
+
+        let repository = Repository::discover_from(&dir).expect("should have discovered the repository");
+
+        assert_eq!(Some("feature/PROJ-1".to_string()), repository.current_branch());
+        assert_eq!(canonical(repo.path()), canonical(repository.root().expect("should have a working tree")));
+        assert_eq!(canonical(&repo.path().join(".git")), canonical(repository.git_dir()));
+    }
+
+    #[test]
+    fn test_fails_outside_repository() {
+        let dir = tempfile::tempdir().expect("should have created a temp dir");
+
+        assert!(matches!(Repository::discover_from(dir.path()), Err(Error::NotARepository(..))));
+    }
+
+    #[test]
+    fn test_reads_commit_template() {
+        let repo = repo("main");
+        let repository = Repository::discover_from(repo.path()).expect("should have discovered the repository");
+        assert_eq!(None, repository.commit_template());
+
+        git(repo.path(), &["config", "commit.template", "~/.gitmessage.txt"]);
+        let repository = Repository::discover_from(repo.path()).expect("should have discovered the repository");
+        assert_eq!(Some(PathBuf::from("~/.gitmessage.txt")), repository.commit_template());
+    }
+
+    #[test]
+    fn test_discovers_linked_worktree() {
+        let repo = repo("main");
+        git(repo.path(), &["commit", "--quiet", "--allow-empty", "-m", "feat: add lexer"]);
+        let worktree = repo.path().join("worktree");
+        git(repo.path(), &["worktree", "add", "--quiet", "-b", "feature/PROJ-2", &worktree.to_string_lossy()]);
+
+        let repository = Repository::discover_from(&worktree).expect("should have discovered the worktree");
+
+        assert_eq!(Some("feature/PROJ-2".to_string()), repository.current_branch());
+        assert_eq!(canonical(&worktree), canonical(repository.root().expect("should have a working tree")));
+        assert_eq!(canonical(&repo.path().join(".git/worktrees/worktree")), canonical(repository.git_dir()));
+    }
+
+    #[test]
+    fn test_discovers_repository_with_separate_git_dir() {
+        let dir = tempfile::tempdir().expect("should have created a temp dir");
+        let (work_tree, git_dir) = (dir.path().join("module"), dir.path().join("modules/module"));
+        for dir in [&work_tree, &dir.path().join("modules")] {
+            fs::create_dir_all(dir).expect("should have created the directory");
+        }
+        git(&work_tree, &["init", "--quiet", "--separate-git-dir", &git_dir.to_string_lossy()]);
+        git(&work_tree, &["checkout", "--quiet", "-b", "feature/PROJ-3"]);
+
+        let repository = Repository::discover_from(&work_tree).expect("should have discovered the repository");
+
+        assert_eq!(Some("feature/PROJ-3".to_string()), repository.current_branch());
+        assert_eq!(canonical(&git_dir), canonical(repository.git_dir()));
+    }
+}
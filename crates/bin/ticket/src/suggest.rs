@@ -0,0 +1,236 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `suggest` subcommand.
+//!
+//! Proposes a conventional commit header based on the files staged for the next commit. The commit type is inferred from
+//! the kind of files changed, and the scope from the deepest meaningful directory they share, preferring the ones the
+//! CODEOWNERS file lists.
+
+use crate::{
+    Error, codeowners,
+    git::{self, ChangeStatus, StagedChange},
+};
+use std::{io::Write, path::Path};
+
+/// Directory names that say nothing about the area of the code being changed, skipped when picking a scope.
+pub(crate) const GENERIC_DIRECTORIES: [&str; 9] = ["src", "lib", "bin", "crates", "packages", "test", "tests", "doc", "docs"];
+
+/// File names that belong to the build system or dependency management.
+const BUILD_FILES: [&str; 12] = [
+    "Cargo.toml",
+    "Cargo.lock",
+    "build.rs",
+    "rust-toolchain.toml",
+    "package.json",
+    "package-lock.json",
+    "bun.lock",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "Makefile",
+    "Dockerfile",
+    "go.mod",
+];
+
+/// File extensions for documentation files.
+const DOC_EXTENSIONS: [&str; 5] = ["md", "markdown", "rst", "adoc", "txt"];
+
+/// The kind of file a change touches, used to infer the commit type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Ci,
+    Test,
+    Docs,
+    Build,
+    Chore,
+    Source,
+}
+
+/// Prints a suggested commit header for the staged changes.
+///
+/// # Returns
+/// * `Ok(())` if a suggestion was printed.
+/// * `Err(Error)` if git failed or nothing is staged.
+pub(crate) fn run(out: &mut impl Write) -> Result<(), Error> {
+    let changes = git::staged_changes()?;
+    if changes.is_empty() {
+        return Err(Error::NothingStaged);
+    }
+
+    writeln!(out, "{}", header(&changes, &codeowners::load()?))?;
+    Ok(())
+}
+
+/// Builds the suggested header, ex: `feat(parser): add lexer.rs`.
+fn header(changes: &[StagedChange], candidates: &[String]) -> String {
+    let commit_type = commit_type(changes);
+    let description = description(changes);
+
+    match scope(changes, candidates) {
+        Some(scope) => format!("{commit_type}({scope}): {description}"),
+        None => format!("{commit_type}: {description}"),
+    }
+}
+
+/// Infers the commit type from the categories of the changed files.
+///
+/// Source changes win over everything else: `feat` if a source file was added, `fix` otherwise. When only supporting
+/// files changed and they all share a category, that category's type is used, falling back to `chore` for a mix.
+fn commit_type(changes: &[StagedChange]) -> &'static str {
+    let categories: Vec<Category> = changes.iter().map(|c| categorise(&c.path)).collect();
+
+    if categories.contains(&Category::Source) {
+        let adds_source = changes.iter().zip(&categories).any(|(c, cat)| *cat == Category::Source && c.status == ChangeStatus::Added);
+        return if adds_source { "feat" } else { "fix" };
+    }
+
+    match categories.first() {
+        Some(first) if categories.iter().all(|c| c == first) => match first {
+            Category::Ci => "ci",
+            Category::Test => "test",
+            Category::Docs => "docs",
+            Category::Build => "build",
+            Category::Chore | Category::Source => "chore",
+        },
+        _ => "chore",
+    }
+}
+
+/// Categorises a file by its path.
+fn categorise(path: &str) -> Category {
+    let path = Path::new(path);
+    let components: Vec<&str> = path.iter().filter_map(|c| c.to_str()).collect();
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+
+    if components.starts_with(&[".github", "workflows"]) || components.first() == Some(&".circleci") || file_name == ".gitlab-ci.yml" {
+        Category::Ci
+    } else if components.iter().any(|c| matches!(*c, "test" | "tests" | "__tests__"))
+        || stem.ends_with("_test")
+        || stem.starts_with("test_")
+        || stem.contains(".test")
+        || stem.contains(".spec")
+    {
+        Category::Test
+    } else if components.first().is_some_and(|c| matches!(*c, "doc" | "docs")) || DOC_EXTENSIONS.contains(&extension) || file_name == "LICENSE" {
+        Category::Docs
+    } else if BUILD_FILES.contains(&file_name) {
+        Category::Build
+    } else if components.iter().any(|c| c.starts_with('.')) {
+        Category::Chore
+    } else {
+        Category::Source
+    }
+}
+
+/// Picks the deepest scope candidate shared by every changed file, or the deepest non-generic directory if none is.
+fn scope(changes: &[StagedChange], candidates: &[String]) -> Option<String> {
+    let mut common: Vec<&str> = parent_components(&changes.first()?.path);
+
+    for change in &changes[1..] {
+        let components = parent_components(&change.path);
+        let shared = common.iter().zip(&components).take_while(|(a, b)| a == b).count();
+        common.truncate(shared);
+    }
+
+    common
+        .iter()
+        .rev()
+        .find(|c| candidates.iter().any(|candidate| candidate == *c))
+        .or_else(|| common.iter().rev().find(|c| !GENERIC_DIRECTORIES.contains(c) && !c.starts_with('.')))
+        .map(ToString::to_string)
+}
+
+/// Returns the directories leading to a file.
+fn parent_components(path: &str) -> Vec<&str> {
+    Path::new(path).parent().map(|p| p.iter().filter_map(|c| c.to_str()).collect()).unwrap_or_default()
+}
+
+/// Describes the changes, naming the file when there is only one.
+fn description(changes: &[StagedChange]) -> String {
+    let verb = match changes.first().map(|c| c.status) {
+        Some(status) if changes.iter().all(|c| c.status == status) => match status {
+            ChangeStatus::Added => "add",
+            ChangeStatus::Modified => "update",
+            ChangeStatus::Deleted => "remove",
+            ChangeStatus::Renamed => "rename",
+        },
+        _ => "update",
+    };
+
+    if let [change] = changes {
+        let name = Path::new(&change.path).file_name().and_then(|n| n.to_str()).unwrap_or(&change.path);
+        format!("{verb} {name}")
+    } else {
+        format!("{verb} {} files", changes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn change(status: ChangeStatus, path: &str) -> StagedChange {
+        StagedChange { status, path: path.into() }
+    }
+
+    #[rstest]
+    #[case::workflow(".github/workflows/ci.yml", Category::Ci)]
+    #[case::tests_directory("crates/bin/ticket/tests/help.rs", Category::Test)]
+    #[case::spec_file("web/app.spec.ts", Category::Test)]
+    #[case::markdown("README.md", Category::Docs)]
+    #[case::docs_directory("docs/contributor/vision.md", Category::Docs)]
+    #[case::manifest("crates/lib/conventional-commit/Cargo.toml", Category::Build)]
+    #[case::dotfile(".config/.rustfmt.toml", Category::Chore)]
+    #[case::source("crates/lib/conventional-commit/src/lib.rs", Category::Source)]
+    fn test_categorises_paths(#[case] path: &str, #[case] expect: Category) {
+        assert_eq!(expect, categorise(path));
+    }
+
+    #[rstest]
+    #[case::added_source(vec![change(ChangeStatus::Added, "src/footer.rs"), change(ChangeStatus::Modified, "README.md")], "feat")]
+    #[case::modified_source(vec![change(ChangeStatus::Modified, "src/footer.rs")], "fix")]
+    #[case::docs_only(vec![change(ChangeStatus::Added, "docs/a.md"), change(ChangeStatus::Modified, "README.md")], "docs")]
+    #[case::mixed_support_files(vec![change(ChangeStatus::Modified, "Cargo.toml"), change(ChangeStatus::Modified, "README.md")], "chore")]
+    fn test_infers_commit_type(#[case] changes: Vec<StagedChange>, #[case] expect: &str) {
+        assert_eq!(expect, commit_type(&changes));
+    }
+
+    #[rstest]
+    #[case::root_file(vec![change(ChangeStatus::Modified, "README.md")], None)]
+    #[case::skips_generic_directories(vec![change(ChangeStatus::Modified, "crates/bin/ticket/src/lib.rs")], Some("ticket"))]
+    #[case::common_directory(vec![change(ChangeStatus::Modified, "src/model/person.rs"), change(ChangeStatus::Added, "src/model/footer.rs")], Some("model"))]
+    #[case::nothing_shared(vec![change(ChangeStatus::Modified, "a/x.rs"), change(ChangeStatus::Modified, "b/y.rs")], None)]
+    fn test_infers_scope(#[case] changes: Vec<StagedChange>, #[case] expect: Option<&str>) {
+        assert_eq!(expect.map(ToString::to_string), scope(&changes, &[]));
+    }
+
+    #[rstest]
+    #[case::prefers_codeowners_directory(vec![change(ChangeStatus::Modified, "crates/parser/src/lexer/tokens.rs")], Some("parser"))]
+    #[case::falls_back_to_deepest_directory(vec![change(ChangeStatus::Modified, "crates/ticket/src/hook/prepare.rs")], Some("hook"))]
+    fn test_infers_scope_from_codeowners(#[case] changes: Vec<StagedChange>, #[case] expect: Option<&str>) {
+        let candidates = vec!["parser".to_string(), "web".to_string()];
+        assert_eq!(expect.map(ToString::to_string), scope(&changes, &candidates));
+    }
+
+    #[rstest]
+    #[case::single_file(vec![change(ChangeStatus::Deleted, "src/old.rs")], "remove old.rs")]
+    #[case::same_status(vec![change(ChangeStatus::Added, "a.rs"), change(ChangeStatus::Added, "b.rs")], "add 2 files")]
+    #[case::mixed_status(vec![change(ChangeStatus::Added, "a.rs"), change(ChangeStatus::Deleted, "b.rs")], "update 2 files")]
+    fn test_describes_changes(#[case] changes: Vec<StagedChange>, #[case] expect: &str) {
+        assert_eq!(expect, description(&changes));
+    }
+}
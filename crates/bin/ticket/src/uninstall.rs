@@ -0,0 +1,59 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The `uninstall` subcommand.
+//!
+//! Removes the `prepare-commit-msg` and `commit-msg` hooks of the repository, from `core.hooksPath` when set, and can
+//! unset the `commit.template` setting `init` wrote. Only hooks carrying the `git-ticket` marker are removed: a hook
+//! managed by another tool, ex: husky or pre-commit, is left in place with a warning unless removal is forced.
+
+use crate::{Error, git, hook, repository::Repository};
+use std::{fs, io::Write};
+
+/// Removes the hooks written for `git-ticket`, and unsets the repository's `commit.template` when asked to.
+///
+/// # Arguments
+/// * `unset_template` - Whether to unset `commit.template` in the repository's git configuration.
+/// * `force` - Whether to remove the hooks even when they were not written for `git-ticket`.
+/// * `out` - Where each hook removed and setting unset is reported.
+///
+/// # Returns
+/// * `Ok(())` if every hook ours, or forced, was removed, and the template was unset when asked to.
+/// * `Err(Error)` if not in a repository, a hook could not be read or removed, or the setting could not be unset.
+pub(crate) fn run(unset_template: bool, force: bool, out: &mut impl Write) -> Result<(), Error> {
+    Repository::discover()?;
+    let dir = git::hooks_dir()?;
+
+    for name in hook::NAMES {
+        let path = dir.join(name);
+        if !path.is_file() {
+            continue;
+        }
+
+        let script = fs::read_to_string(&path).map_err(|e| Error::Read(path.clone(), e))?;
+        if !force && !hook::is_ours(&script) {
+            eprintln!("warning: {} was not installed by git-ticket, leaving it in place, use --force to remove it", path.display());
+            continue;
+        }
+
+        fs::remove_file(&path).map_err(|e| Error::Write(path.clone(), e))?;
+        writeln!(out, "removed {}", path.display())?;
+    }
+
+    if unset_template && git::run(&["config", "--local", "--get", "commit.template"]).is_ok() {
+        git::run(&["config", "--local", "--unset", "commit.template"])?;
+        writeln!(out, "unset commit.template")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::indoc;
+use rstest::rstest;
+
+const CONFIG: &str = indoc! {r#"
+    [areas.ui]
+    paths = ["web/**", "*.css"]
+    scopes = ["ui"]
+
+    [areas.backend]
+    paths = ["server/**"]
+    scopes = ["api"]
+
+    [areas.docs]
+    paths = ["docs/**"]
+"#};
+
+#[rstest]
+#[case::single_area(&["web/src/app.ts"], None, "ui\n")]
+#[case::several_areas(&["web/index.html", "server/main.rs", "docs/guide.md"], None, "backend\ndocs\nui\n")]
+#[case::scope(&["src/lib.rs"], Some("api"), "backend\n")]
+#[case::scope_and_paths(&["theme.css"], Some("api"), "backend\nui\n")]
+#[case::unmapped(&["src/lib.rs"], None, "")]
+fn test_lists_areas_of_staged_changes(#[case] paths: &[&str], #[case] scope: Option<&str>, #[case] expect: &str) {
+    let repo = paths.iter().fold(TestRepo::new().config(CONFIG), |repo, path| repo.stage(path));
+
+    let mut args = vec!["areas"];
+    if let Some(scope) = scope {
+        args.extend(["--scope", scope]);
+    }
+
+    repo.command(&args).assert().success().stdout(expect.to_string());
+}
@@ -0,0 +1,215 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use std::{fs, path::Path, sync::LazyLock};
+
+use assert_cmd::{Command, cargo_bin};
+use common::TestRepo;
+use indoc::indoc;
+use rstest::rstest;
+use tempfile::TempDir;
+
+static BINARY: LazyLock<&Path> = LazyLock::new(|| cargo_bin!("git-ticket"));
+
+fn message_file(message: &str) -> TempDir {
+    let dir = tempfile::tempdir().expect("should have created a temp dir");
+    fs::write(dir.path().join("COMMIT_EDITMSG"), message).expect("should have written the message");
+    dir
+}
+
+#[rstest]
+#[case::header_only("feat: add lexer\n")]
+#[case::with_body_and_footers("fix(parser): handle tabs\n\nTabs were ignored.\n\nRefs: PROJ-1\n")]
+fn test_accepts_valid_message_from_stdin(#[case] message: &str) {
+    Command::new(BINARY.clone()).arg("check").write_stdin(message).assert().success().stdout("").stderr("");
+}
+
+#[test]
+fn test_rejects_invalid_message() {
+    Command::new(BINARY.clone())
+        .arg("check")
+        .write_stdin("add lexer\n")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(indoc! {"
+            error: the commit message is not a valid conventional commit
+            error(s):
+              header 'add lexer' must be formatted as '<type>[(<scope>)][!]: <description>'
+        "});
+}
+
+#[test]
+fn test_rejects_trailing_period() {
+    Command::new(BINARY.clone())
+        .arg("check")
+        .write_stdin("feat: add lexer.\n")
+        .assert()
+        .failure()
+        .code(1)
+        .stdout("")
+        .stderr(indoc! {"
+            error: the commit message is not a valid conventional commit
+            error(s):
+              description 'add lexer.' must not end with a full stop
+        "});
+}
+
+#[test]
+fn test_suggests_diff_removing_trailing_period() {
+    let dir = message_file("feat(parser): add lexer.\n\nSplits the header into tokens.\n");
+
+    Command::new(BINARY.clone())
+        .args(["check", "--suggest", "COMMIT_EDITMSG"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .code(1)
+        .stdout(indoc! {"
+            --- a/COMMIT_EDITMSG
+            +++ b/COMMIT_EDITMSG
+            @@ -1,3 +1,3 @@
+            -feat(parser): add lexer.
+            +feat(parser): add lexer
+             
+             Splits the header into tokens.
+        "});
+}
+
+#[test]
+fn test_suggests_diff_lowercasing_type() {
+    Command::new(BINARY.clone())
+        .args(["check", "--suggest"])
+        .write_stdin("Fix: handle tabs\n")
+        .assert()
+        .success()
+        .stdout(indoc! {"
+            --- a/message
+            +++ b/message
+            @@ -1 +1 @@
+            -Fix: handle tabs
+            +fix: handle tabs
+        "});
+}
+
+#[test]
+fn test_suggests_nothing_for_valid_message() {
+    Command::new(BINARY.clone())
+        .args(["check", "--suggest"])
+        .write_stdin("feat: add lexer\n")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn test_fails_when_file_is_missing() {
+    let dir = tempfile::tempdir().expect("should have created a temp dir");
+
+    Command::new(BINARY.clone())
+        .args(["check", "missing.txt"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: could not read missing.txt: No such file or directory (os error 2)\n");
+}
+
+#[test]
+fn test_checks_json_array_of_messages() {
+    Command::new(BINARY.clone())
+        .args(["check", "--json-input"])
+        .write_stdin(r#"[{"message": "feat: add lexer\n\nRefs: PROJ-1\n"}, {"message": "feat: add lexer."}]"#)
+        .assert()
+        .success()
+        .stdout(concat!(
+            r#"[{"valid":true,"errors":[]},"#,
+            r#"{"valid":false,"errors":["description 'add lexer.' must not end with a full stop"]}]"#,
+            "\n"
+        ));
+}
+
+#[test]
+fn test_checks_every_commit_in_range() {
+    let repo = TestRepo::new().commit("feat: add lexer").commit("add parser").commit("fix: handle tabs.");
+    let sha = |rev: &str| repo.git(&["rev-parse", "--short=7", rev]);
+
+    repo.command(&["check", "--range", "HEAD~2..HEAD"]).assert().failure().code(1).stderr(format!(
+        "error: some commits are not valid conventional commits\n\
+         {} add parser\n  header 'add parser' must be formatted as '<type>[(<scope>)][!]: <description>'\n\
+         {} fix: handle tabs.\n  description 'handle tabs.' must not end with a full stop\n",
+        sha("HEAD~1"),
+        sha("HEAD")
+    ));
+}
+
+#[test]
+fn test_rejects_message_breaking_configured_rules() {
+    let repo = TestRepo::new().config("[rules]\ntype-enum = [\"fix\"]\n");
+
+    repo.command(&["check"]).write_stdin("feat: add lexer.\n").assert().failure().code(1).stderr(indoc! {"
+        error: the commit message is not a valid conventional commit
+        error(s):
+          description 'add lexer.' must not end with a full stop
+          type 'feat' must be one of: fix
+    "});
+}
+
+#[test]
+fn test_accepts_revert_of_commit_in_history() {
+    let repo = TestRepo::new().commit("feat: add lexer");
+    let reverted = repo.git(&["rev-parse", "HEAD"]);
+    let repo = repo.commit(&format!("revert: feat: add lexer\n\nThis reverts commit {reverted}."));
+
+    repo.command(&["check", "--range", "HEAD", "--verify-reverts"]).assert().success().stderr("");
+}
+
+#[test]
+fn test_flags_revert_of_unknown_commit() {
+    let repo = TestRepo::new().commit("feat: add lexer").commit("revert: feat: add lexer\n\nRefs: 0badc0ffee");
+    let sha = repo.git(&["rev-parse", "--short=7", "HEAD"]);
+
+    repo.command(&["check", "--range", "HEAD", "--verify-reverts"]).assert().failure().code(1).stderr(format!(
+        "error: some commits are not valid conventional commits\n{sha} revert: feat: add lexer\n  reverts '0badc0ffee', which is not in its history\n"
+    ));
+    repo.command(&["check", "--range", "HEAD"]).assert().success();
+}
+
+#[rstest]
+#[case::subject_too_long(
+    &format!("feat: {}\n", "a".repeat(73)),
+    &format!("description-max-length: description '{}' is 73 characters long, it must be at most 72\n", "a".repeat(73))
+)]
+#[case::trailing_period("feat: add lexer.\n", "description-trailing-period: description 'add lexer.' must not end with a full stop\n")]
+#[case::missing_type(": add lexer\n", "type-required: field 'type' is required\n")]
+fn test_explains_rule_of_each_problem(#[case] message: &str, #[case] expect: &str) {
+    Command::new(BINARY.clone())
+        .args(["check", "--explain"])
+        .write_stdin(message)
+        .assert()
+        .failure()
+        .code(1)
+        .stdout(expect.to_string());
+}
+
+#[test]
+fn test_explains_nothing_for_valid_message() {
+    Command::new(BINARY.clone())
+        .args(["check", "--explain"])
+        .write_stdin("feat: add lexer\n")
+        .assert()
+        .success()
+        .stdout("");
+}
@@ -0,0 +1,135 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! A harness running `git-ticket` end-to-end against temporary repositories.
+//!
+//! Each test binary only uses part of the harness, hence the `dead_code` allowance.
+
+#![allow(dead_code)]
+
+use std::{ffi::OsStr, fs, path::Path, process, sync::LazyLock};
+
+use assert_cmd::{Command, cargo_bin};
+use tempfile::TempDir;
+
+static BINARY: LazyLock<&Path> = LazyLock::new(|| cargo_bin!("git-ticket"));
+
+/// A git repository with its own identity, home and configuration directories, isolated from the real ones.
+///
+/// Fixtures are set up builder style, ex: `TestRepo::new().commit("feat: add lexer").branch("feature/PROJ-1")`.
+pub struct TestRepo {
+    repo: TempDir,
+    home: TempDir,
+}
+
+impl TestRepo {
+    /// Creates an empty repository on the default branch, committing as `Test <test@test.io>`.
+    pub fn new() -> Self {
+        let test_repo = TestRepo {
+            repo: tempfile::tempdir().expect("should have created a temp dir"),
+            home: tempfile::tempdir().expect("should have created a temp dir"),
+        };
+        test_repo.git(&["init", "--quiet"]);
+        test_repo.git(&["config", "user.name", "Test"]);
+        test_repo.git(&["config", "user.email", "test@test.io"]);
+        test_repo
+    }
+
+    /// Creates and checks out a branch, ex: `feature/PROJ-1`.
+    pub fn branch(self, name: &str) -> Self {
+        self.git(&["checkout", "--quiet", "-b", name]);
+        self
+    }
+
+    /// Commits the staged changes, or an empty commit, with the message kept as written.
+    pub fn commit(self, message: &str) -> Self {
+        self.git(&["commit", "--quiet", "--allow-empty", "--cleanup=verbatim", "-m", message]);
+        self
+    }
+
+    /// Writes a file with its path as content, and stages it.
+    pub fn stage(self, path: &str) -> Self {
+        self.write(path, &format!("{path}\n"));
+        self.git(&["add", path]);
+        self
+    }
+
+    /// Writes a file relative to the repository root, creating its directories.
+    pub fn file(self, path: &str, content: &str) -> Self {
+        self.write(path, content);
+        self
+    }
+
+    /// Writes the repository configuration file, `git-ticket.toml`.
+    pub fn config(self, content: &str) -> Self {
+        self.file("git-ticket.toml", content)
+    }
+
+    /// Writes the user configuration file, `$XDG_CONFIG_HOME/git-ticket/config.toml`.
+    pub fn user_config(self, content: &str) -> Self {
+        let dir = self.home.path().join("git-ticket");
+        fs::create_dir_all(&dir).expect("should have created the configuration directory");
+        fs::write(dir.join("config.toml"), content).expect("should have written the configuration");
+        self
+    }
+
+    /// Returns the root of the repository.
+    pub fn path(&self) -> &Path {
+        self.repo.path()
+    }
+
+    /// Returns the home directory, which is also the user configuration directory.
+    pub fn home(&self) -> &Path {
+        self.home.path()
+    }
+
+    /// Reads a file relative to the repository root.
+    pub fn read(&self, path: &str) -> String {
+        fs::read_to_string(self.path().join(path)).expect("should have read the file")
+    }
+
+    /// Runs git in the repository and returns its trimmed standard output.
+    pub fn git(&self, args: &[&str]) -> String {
+        let output = process::Command::new("git")
+            .args(args)
+            .current_dir(self.path())
+            .envs(self.env())
+            .output()
+            .expect("should have run git");
+        assert!(output.status.success(), "git {} failed: {}", args.join(" "), String::from_utf8_lossy(&output.stderr));
+        String::from_utf8_lossy(&output.stdout).trim_end().to_string()
+    }
+
+    /// Returns a `git-ticket` command run in the repository with the given arguments.
+    pub fn command(&self, args: &[&str]) -> Command {
+        let mut command = Command::new(BINARY.clone());
+        command.args(args).current_dir(self.path()).envs(self.env());
+        command
+    }
+
+    /// Returns the environment pointing git at the home directory and away from the global and system configurations.
+    fn env(&self) -> [(&'static str, &OsStr); 4] {
+        [
+            ("HOME", self.home().as_os_str()),
+            ("XDG_CONFIG_HOME", self.home().as_os_str()),
+            ("GIT_CONFIG_GLOBAL", OsStr::new("/dev/null")),
+            ("GIT_CONFIG_NOSYSTEM", OsStr::new("1")),
+        ]
+    }
+
+    fn write(&self, path: &str, content: &str) {
+        let file = self.path().join(path);
+        fs::create_dir_all(file.parent().expect("should have a parent directory")).expect("should have created directories");
+        fs::write(&file, content).expect("should have written the file");
+    }
+}
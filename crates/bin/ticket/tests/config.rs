@@ -0,0 +1,162 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::indoc;
+use std::fs;
+
+#[test]
+fn test_prints_defaults_without_configuration() {
+    TestRepo::new().command(&["config"]).assert().success().stdout(indoc! {r#"
+        template_path = "~/.gitmessage.txt"
+        branch_pattern = '[A-Z][A-Z0-9]+-\d+'
+        footer_token = "Refs"
+        ticket_template = "{ticket}"
+        style = "footer"
+        prefill_type = false
+    "#});
+}
+
+#[test]
+fn test_reads_repository_configuration_before_user_configuration() {
+    let repo = TestRepo::new().config(r#"footer_token = "Closes""#).user_config(indoc! {r#"
+        footer_token = "Fixes"
+        ticket_template = "{ticket}"
+        style = "prefix"
+        prefill_type = false
+    "#});
+
+    repo.command(&["config"]).assert().success().stdout(indoc! {r#"
+        template_path = "~/.gitmessage.txt"
+        branch_pattern = '[A-Z][A-Z0-9]+-\d+'
+        footer_token = "Closes"
+        ticket_template = "{ticket}"
+        style = "footer"
+        prefill_type = false
+    "#});
+}
+
+#[test]
+fn test_reads_user_configuration_without_repository_configuration() {
+    let repo = TestRepo::new().user_config(indoc! {r#"
+        template_path = "~/templates/commit.txt"
+        style = "prefix"
+    "#});
+
+    repo.command(&["config"]).assert().success().stdout(indoc! {r#"
+        template_path = "~/templates/commit.txt"
+        branch_pattern = '[A-Z][A-Z0-9]+-\d+'
+        footer_token = "Refs"
+        ticket_template = "{ticket}"
+        style = "prefix"
+        prefill_type = false
+    "#});
+}
+
+#[test]
+fn test_reads_user_configuration_from_home_without_xdg_config_home() {
+    let repo = TestRepo::new();
+    let dir = repo.home().join(".config/git-ticket");
+    fs::create_dir_all(&dir).expect("should have created the configuration directory");
+    fs::write(dir.join("config.toml"), "style = \"prefix\"\n").expect("should have written the configuration");
+
+    repo.command(&["config"]).env_remove("XDG_CONFIG_HOME").assert().success().stdout(indoc! {r#"
+        template_path = "~/.gitmessage.txt"
+        branch_pattern = '[A-Z][A-Z0-9]+-\d+'
+        footer_token = "Refs"
+        ticket_template = "{ticket}"
+        style = "prefix"
+        prefill_type = false
+    "#});
+}
+
+#[test]
+fn test_flags_override_configuration() {
+    let repo = TestRepo::new().config(indoc! {r#"
+        footer_token = "Closes"
+        branch_pattern = "GH-\\d+"
+    "#});
+
+    repo.command(&["config", "--footer-token", "Fixes", "--style", "prefix"])
+        .assert()
+        .success()
+        .stdout(indoc! {r#"
+            template_path = "~/.gitmessage.txt"
+            branch_pattern = 'GH-\d+'
+            footer_token = "Fixes"
+            ticket_template = "{ticket}"
+            style = "prefix"
+            prefill_type = false
+        "#});
+}
+
+#[test]
+fn test_reads_configuration_file_from_flag() {
+    let repo = TestRepo::new().config(r#"footer_token = "Closes""#);
+    let path = repo.home().join("ci.toml");
+    fs::write(
+        &path,
+        indoc! {r#"
+        footer_token = "Fixes"
+        branch_pattern = "GH-\\d+"
+    "#},
+    )
+    .expect("should have written the configuration");
+
+    repo.command(&["--config", &path.to_string_lossy(), "config", "--style", "prefix"])
+        .assert()
+        .success()
+        .stdout(indoc! {r#"
+            template_path = "~/.gitmessage.txt"
+            branch_pattern = 'GH-\d+'
+            footer_token = "Fixes"
+            ticket_template = "{ticket}"
+            style = "prefix"
+            prefill_type = false
+        "#});
+}
+
+#[test]
+fn test_fails_with_missing_configuration_file_from_flag() {
+    let repo = TestRepo::new().config(r#"footer_token = "Closes""#);
+
+    repo.command(&["config", "--config", "missing.toml"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: could not read missing.toml: No such file or directory (os error 2)\n");
+}
+
+#[test]
+fn test_reports_offending_key() {
+    let repo = TestRepo::new().config("footer_token = 42");
+    let path = repo.path().join("git-ticket.toml");
+
+    repo.command(&["config"]).assert().failure().code(1).stderr(format!(
+        "error: invalid configuration, {}: `footer_token` invalid type: integer `42`, expected a string\n",
+        path.canonicalize().expect("should have resolved the path").display()
+    ));
+}
+
+#[test]
+fn test_reports_invalid_branch_pattern() {
+    let assert = TestRepo::new().command(&["config", "--branch-pattern", "("]).assert().failure().code(1);
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(
+        stderr.starts_with("error: invalid configuration, `branch_pattern` is not a valid regular expression"),
+        "unexpected error: {stderr}"
+    );
+}
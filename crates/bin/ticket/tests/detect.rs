@@ -0,0 +1,107 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::formatdoc;
+
+fn repo(branch: &str) -> TestRepo {
+    TestRepo::new().branch(branch)
+}
+
+#[test]
+fn test_prints_detected_tickets_as_text() {
+    let repo = repo("feature/PROJ-1+OPS-2");
+
+    repo.command(&["detect"]).assert().success().stdout(formatdoc! {"
+        tickets: PROJ-1, OPS-2
+        footer token: Refs
+        template path: ~/.gitmessage.txt
+        git dir: {}
+    ", repo.path().join(".git").display()});
+}
+
+#[test]
+fn test_prints_detected_tickets_as_json() {
+    let repo = repo("feature/PROJ-1");
+
+    repo.command(&["detect"])
+        .args(["--format", "json", "--footer-token", "Closes"])
+        .assert()
+        .success()
+        .stdout(format!(
+            "{{\"tickets\":[\"PROJ-1\"],\"footer_token\":\"Closes\",\"template_path\":\"~/.gitmessage.txt\",\"git_dir\":\"{}\"}}\n",
+            repo.path().join(".git").display()
+        ));
+}
+
+#[test]
+fn test_prints_no_tickets_as_json() {
+    let repo = repo("main");
+
+    repo.command(&["detect"]).args(["--format", "json"]).assert().success().stdout(format!(
+        "{{\"tickets\":[],\"footer_token\":\"Refs\",\"template_path\":\"~/.gitmessage.txt\",\"git_dir\":\"{}\"}}\n",
+        repo.path().join(".git").display()
+    ));
+}
+
+#[test]
+fn test_prints_errors_as_json() {
+    let repo = repo("feature/PROJ-1");
+
+    let output = repo
+        .command(&["detect"])
+        .args(["--format", "json", "--branch-pattern", "("])
+        .assert()
+        .failure()
+        .code(1)
+        .stdout("")
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        stderr.starts_with(r#"{"error":{"exit_code":1,"kind":"config","message":"invalid configuration, `branch_pattern` is not a valid regular expression"#),
+        "unexpected error output: {stderr}"
+    );
+}
+
+#[test]
+fn test_prints_settings_from_repository_configuration() {
+    let repo = repo("feature/GH-7").config("branch_pattern = \"GH-\\\\d+\"\nfooter_token = \"Closes\"\n");
+
+    repo.command(&["detect"]).assert().success().stdout(formatdoc! {"
+        tickets: GH-7
+        footer token: Closes
+        template path: ~/.gitmessage.txt
+        git dir: {}
+    ", repo.path().join(".git").display()});
+}
+
+#[test]
+fn test_prints_closing_keyword_as_footer_token() {
+    let repo = repo("feature/PROJ-1").config("footer_token = \"Closes\"\nclosing_keyword = \"fixes\"\n");
+
+    repo.command(&["detect"]).assert().success().stdout(formatdoc! {"
+        tickets: PROJ-1
+        footer token: Fixes
+        template path: ~/.gitmessage.txt
+        git dir: {}
+    ", repo.path().join(".git").display()});
+
+    repo.command(&["detect", "--format", "json"]).assert().success().stdout(format!(
+        "{{\"tickets\":[\"PROJ-1\"],\"footer_token\":\"Fixes\",\"template_path\":\"~/.gitmessage.txt\",\"git_dir\":\"{}\"}}\n",
+        repo.path().join(".git").display()
+    ));
+}
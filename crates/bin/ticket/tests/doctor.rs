@@ -0,0 +1,126 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::{formatdoc, indoc};
+
+const OUR_HOOK: &str = indoc! {r#"
+    #!/bin/sh
+    # git-ticket: managed hook
+    exec git-ticket hook commit-msg "$@"
+"#};
+
+/// A repository on `feature/PROJ-1` with both hooks installed and `commit.template` set to an existing file.
+fn healthy() -> TestRepo {
+    let repo = TestRepo::new()
+        .branch("feature/PROJ-1")
+        .file(".git/hooks/prepare-commit-msg", OUR_HOOK)
+        .file(".git/hooks/commit-msg", OUR_HOOK)
+        .file("template.txt", "\n\nRefs: PROJ-1\n");
+    repo.git(&["config", "commit.template", &repo.path().join("template.txt").to_string_lossy()]);
+    repo
+}
+
+#[test]
+fn test_passes_healthy_setup() {
+    let repo = healthy();
+
+    repo.command(&["doctor"]).assert().success().stdout(formatdoc! {"
+        [pass] the configuration is valid
+        [pass] in a git repository
+        [pass] commit.template is set to {}
+        [pass] the prepare-commit-msg hook is installed at .git/hooks/prepare-commit-msg
+        [pass] the commit-msg hook is installed at .git/hooks/commit-msg
+        [pass] the branch `feature/PROJ-1` matches the branch pattern `[A-Z][A-Z0-9]+-\\d+`
+        [pass] tickets found: PROJ-1
+    ", repo.path().join("template.txt").display()});
+}
+
+#[test]
+fn test_warns_about_missing_hooks_and_ticket() {
+    let repo = TestRepo::new().branch("main").file(".git/hooks/commit-msg", "#!/bin/sh\nnpx commitlint --edit \"$1\"\n");
+
+    repo.command(&["doctor"]).assert().success().stdout(indoc! {r#"
+        [pass] the configuration is valid
+        [pass] in a git repository
+        [warn] commit.template is not set
+               hint: run `git-ticket init` to write the template and set it
+        [warn] the prepare-commit-msg hook is not installed
+               hint: run `git-ticket install` to install the hooks
+        [warn] the commit-msg hook at .git/hooks/commit-msg was not installed by git-ticket
+               hint: make sure the tool managing it runs `git-ticket hook commit-msg`
+        [warn] the branch `main` does not match the branch pattern `[A-Z][A-Z0-9]+-\d+`
+               hint: name the branch after its ticket, ex: `feature/PROJ-123`, or set `branch_pattern` in the configuration
+    "#});
+}
+
+#[test]
+fn test_fails_with_missing_template() {
+    let repo = healthy();
+    repo.git(&["config", "commit.template", "/nonexistent/template.txt"]);
+
+    let assert = repo
+        .command(&["doctor"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: 1 critical check(s) failed, see the hints above\n");
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    assert!(
+        stdout.contains(indoc! {"
+            [fail] commit.template points to /nonexistent/template.txt, which does not exist
+                   hint: run `git-ticket init` to write it again, or unset it with `git config --unset commit.template`
+        "}),
+        "unexpected checklist:\n{stdout}"
+    );
+}
+
+#[test]
+fn test_fails_with_invalid_configuration() {
+    let repo = healthy().config("branch_pattern = \"(\"\n");
+
+    let assert = repo
+        .command(&["doctor"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: 1 critical check(s) failed, see the hints above\n");
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    assert!(
+        stdout.starts_with("[fail] invalid configuration, `branch_pattern` is not a valid regular expression"),
+        "unexpected checklist:\n{stdout}"
+    );
+}
+
+#[test]
+fn test_fails_outside_repository() {
+    let repo = TestRepo::new();
+    let outside = repo.home().join("outside");
+    std::fs::create_dir_all(&outside).expect("should have created the directory");
+
+    repo.command(&["doctor"])
+        .current_dir(&outside)
+        .assert()
+        .failure()
+        .code(1)
+        .stdout(indoc! {"
+            [pass] the configuration is valid
+            [fail] not in a git repository
+                   hint: run git-ticket from inside a repository, or create one with `git init`
+        "})
+        .stderr("error: 1 critical check(s) failed, see the hints above\n");
+}
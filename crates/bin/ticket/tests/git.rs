@@ -0,0 +1,32 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+use std::{path::Path, sync::LazyLock};
+
+use assert_cmd::{Command, cargo_bin};
+
+static BINARY: LazyLock<&Path> = LazyLock::new(|| cargo_bin!("git-ticket"));
+
+#[test]
+fn test_reports_missing_git_with_a_friendly_error() {
+    let empty = tempfile::tempdir().expect("should have created a temp dir");
+
+    Command::new(BINARY.clone())
+        .arg("suggest")
+        .env("PATH", empty.path())
+        .current_dir(empty.path())
+        .assert()
+        .failure()
+        .code(3)
+        .stderr("error: git was not found, please install git 2.20.0 or later and make sure it is on your PATH\n");
+}
@@ -32,11 +32,33 @@ fn test_prints_help_with_short_description_when_the_short_help_flag_is_set() {
         "
 			Attaches ticket(s) to your commit messages.
 
-			Usage: git-ticket
+			Usage: git-ticket [OPTIONS] [COMMAND]
+
+			Commands:
+			  areas      Lists the configured areas touched by the staged changes
+			  check      Checks that a commit message follows the conventional commits specification
+			  config     Prints the resolved configuration
+			  detect     Prints the tickets found in the branch name and how they are attached
+			  doctor     Checks the repository, template, hooks and branch are set up for git-ticket, with hints fixing what isn't
+			  hook       Runs as a git hook
+			  init       Writes the commit message template with the branch's tickets and sets `commit.template` to it
+			  install    Installs the git hooks running git-ticket, leaving hooks managed by other tools in place
+			  list       Lists the tickets that would be attached, and whether they match the branch pattern
+			  suggest    Suggests a conventional commit header from the staged changes
+			  uninstall  Removes the git hooks installed for git-ticket, leaving hooks managed by other tools in place
+			  help       Print this message or the help of the given subcommand(s)
 
 			Options:
-			  -h, --help     Print help (see more with '--help')
-			  -V, --version  Print version
+			      --config <PATH>               The configuration file to read, instead of searching the repository and user configuration directories
+			      --template-path <PATH>        The commit message template to attach tickets to
+			      --branch-pattern <PATTERN>    The regular expression matching tickets in branch names
+			      --footer-token <TOKEN>        The footer token tickets are attached with
+			      --closing-keyword <KEYWORD>   Attaches tickets under a closing keyword instead of the footer token, ex: `Closes`, `Fixes` or `Resolves`
+			      --ticket-template <TEMPLATE>  How tickets are written, with `{name}` replaced by the named captures of the branch pattern
+			      --style <STYLE>               How tickets are attached to commit messages [possible values: footer, prefix]
+			      --format <FORMAT>             How results and errors are printed [default: text] [possible values: text, json]
+			  -h, --help                        Print help (see more with '--help')
+			  -V, --version                     Print version
 		"
     ));
 }
@@ -47,11 +69,59 @@ fn test_prints_long_description_when_the_full_help_flag_is_set() {
         "
 			Attaches ticket(s) to your commit messages. This is done via the git commit message template.
 			Please ensure to set the path to this file in your git configuration using `git config --global commit.template ~/.gitmessage.txt`.
-			By default the file will be created in your home directory with the name ~/.gitmessage.txt, but this can be overridden.
+			By default the file will be created in your home directory, your user profile on Windows, with the name ~/.gitmessage.txt, but this can be overridden. A leading ~ in the path is expanded to your home directory on every platform.
+
+			Usage: git-ticket [OPTIONS] [COMMAND]
 
-			Usage: git-ticket
+			Commands:
+			  areas      Lists the configured areas touched by the staged changes
+			  check      Checks that a commit message follows the conventional commits specification
+			  config     Prints the resolved configuration
+			  detect     Prints the tickets found in the branch name and how they are attached
+			  doctor     Checks the repository, template, hooks and branch are set up for git-ticket, with hints fixing what isn't
+			  hook       Runs as a git hook
+			  init       Writes the commit message template with the branch's tickets and sets `commit.template` to it
+			  install    Installs the git hooks running git-ticket, leaving hooks managed by other tools in place
+			  list       Lists the tickets that would be attached, and whether they match the branch pattern
+			  suggest    Suggests a conventional commit header from the staged changes
+			  uninstall  Removes the git hooks installed for git-ticket, leaving hooks managed by other tools in place
+			  help       Print this message or the help of the given subcommand(s)
 
 			Options:
+			      --config <PATH>
+			          The configuration file to read, instead of searching the repository and user configuration directories
+
+			      --template-path <PATH>
+			          The commit message template to attach tickets to
+
+			      --branch-pattern <PATTERN>
+			          The regular expression matching tickets in branch names
+
+			      --footer-token <TOKEN>
+			          The footer token tickets are attached with
+
+			      --closing-keyword <KEYWORD>
+			          Attaches tickets under a closing keyword instead of the footer token, ex: `Closes`, `Fixes` or `Resolves`
+
+			      --ticket-template <TEMPLATE>
+			          How tickets are written, with `{name}` replaced by the named captures of the branch pattern
+
+			      --style <STYLE>
+			          How tickets are attached to commit messages
+
+			          Possible values:
+			          - footer: As a footer, ex: `Refs: PROJ-123`
+			          - prefix: As a prefix of the subject, ex: `[PROJ-123] feat: add lexer`
+
+			      --format <FORMAT>
+			          How results and errors are printed
+			          
+			          [default: text]
+
+			          Possible values:
+			          - text: Human readable text
+			          - json: JSON, for editors and other tools
+
 			  -h, --help
 			          Print help (see a summary with '-h')
 
@@ -0,0 +1,306 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::indoc;
+use rstest::rstest;
+
+const MESSAGE_FILE: &str = ".git/COMMIT_EDITMSG";
+
+/// A repository with a commit made on `feature/PROJ-1`, now on `feature/PROJ-2`.
+fn repo() -> TestRepo {
+    TestRepo::new().branch("feature/PROJ-1").commit("feat: add lexer\n\nRefs: PROJ-1").branch("feature/PROJ-2")
+}
+
+fn prepare_commit_msg(repo: TestRepo, message: &str, args: &[&str]) -> String {
+    let repo = repo.file(MESSAGE_FILE, message);
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE]).args(args).assert().success();
+    repo.read(MESSAGE_FILE)
+}
+
+#[rstest]
+#[case::footer_present("feat: add lexer\n\nRefs: PROJ-1\n", "feat: add lexer\n\nRefs: PROJ-1\n")]
+#[case::footer_missing("feat: add lexer\n", "feat: add lexer\n\nRefs: PROJ-2\n")]
+fn test_keeps_ticket_of_amended_commit(#[case] message: &str, #[case] expect: &str) {
+    assert_eq!(expect, prepare_commit_msg(repo(), message, &["commit", "HEAD"]));
+}
+
+#[rstest]
+#[case::no_source("\n# Please enter the commit message\n", &[], "\n\nRefs: PROJ-2\n\n# Please enter the commit message\n")]
+#[case::message_source("feat: add lexer\n", &["message"], "feat: add lexer\n\nRefs: PROJ-2\n")]
+#[case::already_attached("feat: add lexer\n\nRefs: PROJ-2\n", &["message"], "feat: add lexer\n\nRefs: PROJ-2\n")]
+fn test_attaches_ticket_from_branch(#[case] message: &str, #[case] args: &[&str], #[case] expect: &str) {
+    assert_eq!(expect, prepare_commit_msg(repo(), message, args));
+}
+
+#[test]
+fn test_attaches_every_ticket_from_branch() {
+    assert_eq!(
+        "feat: add lexer\n\nRefs: PROJ-2\nRefs: PROJ-3\n",
+        prepare_commit_msg(repo().branch("feature/PROJ-2+PROJ-3"), "feat: add lexer\n", &["message"])
+    );
+}
+
+#[test]
+fn test_attaches_ticket_above_custom_comments() {
+    let repo = repo();
+    repo.git(&["config", "core.commentChar", ";"]);
+
+    assert_eq!(
+        "feat: add lexer\n\nRefs: PROJ-2\n\n; Please enter the commit message\n",
+        prepare_commit_msg(repo, "feat: add lexer\n\n; Please enter the commit message\n", &[])
+    );
+}
+
+#[test]
+fn test_leaves_message_without_ticket_in_branch() {
+    assert_eq!("feat: add lexer\n", prepare_commit_msg(repo().branch("main"), "feat: add lexer\n", &["message"]));
+}
+
+#[rstest]
+#[case::jira_ticket("closing_keyword = \"fixes\"\n", "feature/PROJ-3", "Fixes: PROJ-3")]
+#[case::github_issue(
+    "closing_keyword = \"Closes\"\nbranch_pattern = 'issue-(?<ticket>\\d+)'\nticket_template = \"#{ticket}\"\n",
+    "issue-42",
+    "Closes #42"
+)]
+fn test_attaches_ticket_under_closing_keyword(#[case] config: &str, #[case] branch: &str, #[case] footer: &str) {
+    let repo = repo().config(config).branch(branch);
+    assert_eq!(format!("feat: add lexer\n\n{footer}\n"), prepare_commit_msg(repo, "feat: add lexer\n", &["message"]));
+}
+
+#[test]
+fn test_rejects_unsupported_closing_keyword() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n");
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message", "--closing-keyword", "Refs"])
+        .assert()
+        .failure()
+        .code(2);
+
+    assert_eq!("feat: add lexer\n", repo.read(MESSAGE_FILE));
+}
+
+/// The configuration of a team encoding the commit type and a description in branch names, ex: `feat/PROJ-3/add-lexer`.
+const BRANCH_VARIABLES: &str = r#"
+branch_pattern = '(?<type>[a-z]+)/(?<ticket>[A-Z]+-\d+)/(?<slug>[a-z-]+)'
+ticket_template = "{ticket} ({slug})"
+prefill_type = true
+"#;
+
+#[rstest]
+#[case::new_message("\n# Please enter the commit message\n", &[], "feat: \n\nRefs: PROJ-3 (add-lexer)\n\n# Please enter the commit message\n")]
+#[case::message_source("fix: handle tabs\n", &["message"], "fix: handle tabs\n\nRefs: PROJ-3 (add-lexer)\n")]
+fn test_fills_message_from_branch_variables(#[case] message: &str, #[case] args: &[&str], #[case] expect: &str) {
+    let repo = repo().config(BRANCH_VARIABLES).branch("feat/PROJ-3/add-lexer");
+    assert_eq!(expect, prepare_commit_msg(repo, message, args));
+}
+
+#[test]
+fn test_fails_with_unknown_ticket_template_variable() {
+    let repo = repo().config("ticket_template = \"{ticket} {slug}\"\n").file(MESSAGE_FILE, "feat: add lexer\n");
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: invalid configuration, `ticket_template` references unknown variable `{slug}`, available variables are `{ticket}`\n");
+
+    assert_eq!("feat: add lexer\n", repo.read(MESSAGE_FILE));
+}
+
+#[test]
+fn test_skips_prompt_without_terminal() {
+    assert_eq!(
+        "feat: add lexer\n",
+        prepare_commit_msg(repo().branch("main"), "feat: add lexer\n", &["message", "--interactive"])
+    );
+}
+
+#[rstest]
+#[case::unsigned("feat: add lexer\n", "feat: add lexer\n\nRefs: PROJ-2\nSigned-off-by: Test <test@test.io>\n")]
+#[case::signed_off(
+    "feat: add lexer\n\nRefs: PROJ-2\nSigned-off-by: Test <test@test.io>\n",
+    "feat: add lexer\n\nRefs: PROJ-2\nSigned-off-by: Test <test@test.io>\n"
+)]
+fn test_signs_off_message(#[case] message: &str, #[case] expect: &str) {
+    assert_eq!(expect, prepare_commit_msg(repo(), message, &["message", "--sign-off"]));
+}
+
+#[test]
+fn test_signs_off_message_without_ticket_in_branch() {
+    assert_eq!(
+        "feat: add lexer\n\nSigned-off-by: Test <test@test.io>\n",
+        prepare_commit_msg(repo().branch("main"), "feat: add lexer\n", &["message", "--sign-off"])
+    );
+}
+
+#[test]
+fn test_fails_to_sign_off_with_invalid_email() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n");
+    repo.git(&["config", "user.email", "test.io"]);
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message", "--sign-off"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(indoc! {"
+            error: invalid git identity, error(s):
+              field 'email' has invalid value: Missing separator character '@'.
+        "});
+
+    assert_eq!("feat: add lexer\n", repo.read(MESSAGE_FILE));
+}
+
+#[test]
+fn test_skips_sign_off_without_identity_when_opting_out_with_trailer() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n\nGit-Ticket: skip\n");
+    repo.git(&["config", "--unset", "user.name"]);
+    repo.git(&["config", "--unset", "user.email"]);
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message", "--sign-off"])
+        .assert()
+        .success()
+        .stderr("");
+
+    assert_eq!("feat: add lexer\n\nGit-Ticket: skip\n", repo.read(MESSAGE_FILE));
+}
+
+#[test]
+fn test_prints_diff_on_dry_run() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n");
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(indoc! {"
+            --- a/.git/COMMIT_EDITMSG
+            +++ b/.git/COMMIT_EDITMSG
+            @@ -1 +1,3 @@
+             feat: add lexer
+            +
+            +Refs: PROJ-2
+        "});
+
+    assert_eq!("feat: add lexer\n", repo.read(MESSAGE_FILE));
+}
+
+#[test]
+fn test_prints_nothing_on_dry_run_without_change() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n\nRefs: PROJ-2\n");
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message", "--dry-run"])
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[rstest]
+#[case::valid("feat: add lexer\n\n# Please enter the commit message\n")]
+#[case::merge("Merge branch 'feature/PROJ-1'\n")]
+#[case::fixup("fixup! feat: add lexer\n")]
+#[case::squash("squash! feat: add lexer\n\nAlso handle tabs.\n")]
+fn test_accepts_commit_message(#[case] message: &str) {
+    let repo = repo().file(MESSAGE_FILE, message);
+
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().success().stdout("");
+}
+
+#[test]
+fn test_accepts_commit_message_with_interleaved_comments() {
+    let repo = repo().file(
+        MESSAGE_FILE,
+        "feat: add lexer\n# Please enter the commit message\n\nSplits the header.\n\n\n# with '#' ignored\n",
+    );
+
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().success().stdout("");
+}
+
+#[test]
+fn test_strips_comments_with_configured_comment_char() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n; Please enter the commit message\n");
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().failure().code(1);
+
+    repo.git(&["config", "core.commentChar", ";"]);
+
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().success().stdout("");
+}
+
+#[test]
+fn test_rejects_non_conventional_commit_message() {
+    let repo = repo().file(MESSAGE_FILE, "Add lexer\n");
+
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().failure().code(1).stderr(indoc! {"
+        error: the commit message is not a valid conventional commit
+        error(s):
+          header 'Add lexer' must be formatted as '<type>[(<scope>)][!]: <description>'
+    "});
+}
+
+#[test]
+fn test_rejects_commit_message_breaking_configured_rules() {
+    let repo = repo().config("[rules]\ntype-enum = [\"fix\"]\n").file(MESSAGE_FILE, "feat: add lexer\n");
+
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().failure().code(1).stderr(indoc! {"
+        error: the commit message is not a valid conventional commit
+        error(s):
+          type 'feat' must be one of: fix
+    "});
+}
+
+#[test]
+fn test_rejects_commit_message_breaking_lint_rules() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer.\n");
+
+    repo.command(&["hook", "commit-msg", MESSAGE_FILE]).assert().failure().code(1).stderr(indoc! {"
+        error: the commit message is not a valid conventional commit
+        error(s):
+          description 'add lexer.' must not end with a full stop
+    "});
+}
+
+#[rstest]
+#[case::prepare_commit_msg(&["hook", "prepare-commit-msg", MESSAGE_FILE])]
+#[case::commit_msg(&["hook", "commit-msg", MESSAGE_FILE])]
+fn test_leaves_message_when_disabled(#[case] args: &[&str]) {
+    let repo = repo().config("footer_token = 42\n").file(MESSAGE_FILE, "Add lexer\n");
+
+    repo.command(args).env("GIT_TICKET_DISABLE", "1").assert().success().stdout("").stderr("");
+
+    assert_eq!("Add lexer\n", repo.read(MESSAGE_FILE));
+}
+
+#[test]
+fn test_runs_when_disable_variable_is_zero() {
+    let repo = repo().file(MESSAGE_FILE, "feat: add lexer\n");
+
+    repo.command(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message"])
+        .env("GIT_TICKET_DISABLE", "0")
+        .assert()
+        .success();
+
+    assert_eq!("feat: add lexer\n\nRefs: PROJ-2\n", repo.read(MESSAGE_FILE));
+}
+
+#[rstest]
+#[case::prepare_commit_msg(&["hook", "prepare-commit-msg", MESSAGE_FILE, "message"])]
+#[case::commit_msg(&["hook", "commit-msg", MESSAGE_FILE])]
+fn test_leaves_message_opting_out_with_trailer(#[case] args: &[&str]) {
+    let repo = repo().file(MESSAGE_FILE, "Add lexer\n\nGit-Ticket: skip\n");
+
+    repo.command(args).assert().success();
+
+    assert_eq!("Add lexer\n\nGit-Ticket: skip\n", repo.read(MESSAGE_FILE));
+}
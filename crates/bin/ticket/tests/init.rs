@@ -0,0 +1,144 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use assert_cmd::Command;
+use common::TestRepo;
+use std::fs;
+
+/// A repository on `feature/PROJ-1`.
+fn repo() -> TestRepo {
+    TestRepo::new().branch("feature/PROJ-1")
+}
+
+fn init(repo: &TestRepo, args: &[&str]) -> Command {
+    repo.command(&[&["init"], args].concat())
+}
+
+#[test]
+fn test_writes_template_with_sign_off() {
+    let repo = repo();
+
+    init(&repo, &["--dco", "--minimal"]).assert().success();
+
+    let path = repo.home().join(".gitmessage.txt");
+    assert_eq!(
+        "\n\nRefs: PROJ-1\nSigned-off-by: Test <test@test.io>\n",
+        fs::read_to_string(&path).expect("should have written the template")
+    );
+    assert_eq!(path.to_string_lossy(), repo.git(&["config", "commit.template"]));
+}
+
+#[test]
+fn test_accepts_sign_off_as_alias_of_dco() {
+    let repo = repo();
+
+    init(&repo, &["--sign-off", "--minimal"]).assert().success();
+
+    assert_eq!(
+        "\n\nRefs: PROJ-1\nSigned-off-by: Test <test@test.io>\n",
+        fs::read_to_string(repo.home().join(".gitmessage.txt")).expect("should have written the template")
+    );
+}
+
+#[test]
+fn test_writes_template_without_sign_off() {
+    let repo = repo();
+    let path = repo.path().join("templates/commit.txt");
+
+    init(&repo, &["--minimal", "--template-path", &path.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", path.display()));
+
+    assert_eq!("\n\nRefs: PROJ-1\n", fs::read_to_string(&path).expect("should have written the template"));
+}
+
+#[test]
+fn test_writes_commented_hints_by_default() {
+    let repo = repo();
+
+    init(&repo, &[]).assert().success();
+
+    let template = fs::read_to_string(repo.home().join(".gitmessage.txt")).expect("should have written the template");
+    let hints = template.strip_prefix("\n\nRefs: PROJ-1\n\n").expect("should have followed the tickets with hints");
+    assert!(hints.contains("<type>[(<scope>)][!]: <description>"), "unexpected hints:\n{hints}");
+    assert!(hints.lines().all(|line| line.starts_with('#')), "every hint should be a comment:\n{hints}");
+}
+
+#[test]
+fn test_writes_template_from_file() {
+    let repo = repo();
+    let scaffold = repo.path().join("scaffold.txt");
+    fs::write(&scaffold, "# Describe the change\n# Link the design doc\n").expect("should have written the scaffold");
+
+    init(&repo, &["--template-file", &scaffold.to_string_lossy()]).assert().success();
+
+    assert_eq!(
+        "\n\nRefs: PROJ-1\n\n# Describe the change\n# Link the design doc\n",
+        fs::read_to_string(repo.home().join(".gitmessage.txt")).expect("should have written the template")
+    );
+}
+
+#[test]
+fn test_fails_with_missing_template_file() {
+    let repo = repo();
+
+    init(&repo, &["--template-file", "missing.txt"]).assert().failure().code(1);
+
+    assert!(!repo.home().join(".gitmessage.txt").exists(), "should not have written the template");
+}
+
+#[test]
+fn test_expands_home_in_template_path() {
+    let repo = repo();
+    let path = repo.home().join("templates/commit.txt");
+
+    init(&repo, &["--minimal", "--template-path", "~/templates/commit.txt"])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", path.display()));
+
+    assert_eq!("\n\nRefs: PROJ-1\n", fs::read_to_string(&path).expect("should have written the template"));
+    assert_eq!(path.to_string_lossy(), repo.git(&["config", "commit.template"]));
+}
+
+#[test]
+fn test_fails_to_sign_off_without_identity() {
+    let repo = repo();
+    repo.git(&["config", "--unset", "user.email"]);
+
+    init(&repo, &["--dco"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: invalid git identity, set user.name and user.email with `git config` to sign off commits\n");
+}
+
+#[test]
+fn test_prints_template_on_dry_run() {
+    let repo = repo();
+    let path = repo.home().join(".gitmessage.txt");
+
+    init(&repo, &["--dco", "--minimal", "--dry-run"]).assert().success().stdout(format!(
+        "would write {0}:\n\n\nRefs: PROJ-1\nSigned-off-by: Test <test@test.io>\nwould set commit.template to {0}\n",
+        path.display()
+    ));
+
+    assert!(!path.exists(), "should not have written the template");
+    assert!(
+        !repo.git(&["config", "--local", "--list"]).contains("commit.template"),
+        "should not have set commit.template"
+    );
+}
@@ -0,0 +1,123 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::indoc;
+
+const FOREIGN_HOOK: &str = indoc! {r#"
+    #!/bin/sh
+    . "$(dirname "$0")/husky.sh"
+    npx --no -- commitlint --edit "$1"
+"#};
+
+#[test]
+fn test_installs_hooks() {
+    let repo = TestRepo::new();
+
+    repo.command(&["install"])
+        .assert()
+        .success()
+        .stdout("installed .git/hooks/prepare-commit-msg\ninstalled .git/hooks/commit-msg\n");
+
+    assert_eq!(
+        indoc! {r#"
+            #!/bin/sh
+            # git-ticket: managed hook
+            exec git-ticket hook prepare-commit-msg "$@"
+        "#},
+        repo.read(".git/hooks/prepare-commit-msg")
+    );
+    assert_eq!(
+        indoc! {r#"
+            #!/bin/sh
+            # git-ticket: managed hook
+            exec git-ticket hook commit-msg "$@"
+        "#},
+        repo.read(".git/hooks/commit-msg")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_makes_hooks_executable() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let repo = TestRepo::new();
+
+    repo.command(&["install"]).assert().success();
+
+    let mode = std::fs::metadata(repo.path().join(".git/hooks/commit-msg"))
+        .expect("should have read the hook")
+        .permissions()
+        .mode();
+    assert_eq!(0o111, mode & 0o111, "should have made the hook executable");
+}
+
+#[test]
+fn test_uninstalls_installed_hooks() {
+    let repo = TestRepo::new();
+
+    repo.command(&["install"]).assert().success();
+    repo.command(&["uninstall"])
+        .assert()
+        .success()
+        .stdout("removed .git/hooks/prepare-commit-msg\nremoved .git/hooks/commit-msg\n")
+        .stderr("");
+
+    assert!(!repo.path().join(".git/hooks/prepare-commit-msg").exists(), "should have removed prepare-commit-msg");
+    assert!(!repo.path().join(".git/hooks/commit-msg").exists(), "should have removed commit-msg");
+}
+
+#[test]
+fn test_leaves_foreign_hook_in_place() {
+    let repo = TestRepo::new().file(".git/hooks/commit-msg", FOREIGN_HOOK);
+
+    repo.command(&["install"])
+        .assert()
+        .success()
+        .stdout("installed .git/hooks/prepare-commit-msg\n")
+        .stderr("warning: .git/hooks/commit-msg was not installed by git-ticket, leaving it in place, use --force to overwrite it\n");
+
+    assert_eq!(FOREIGN_HOOK, repo.read(".git/hooks/commit-msg"));
+}
+
+#[test]
+fn test_overwrites_foreign_hook_when_forced() {
+    let repo = TestRepo::new().file(".git/hooks/commit-msg", FOREIGN_HOOK);
+
+    repo.command(&["install", "--force"])
+        .assert()
+        .success()
+        .stdout("installed .git/hooks/prepare-commit-msg\ninstalled .git/hooks/commit-msg\n")
+        .stderr("");
+
+    assert!(
+        repo.read(".git/hooks/commit-msg").contains("# git-ticket: managed hook"),
+        "should have overwritten commit-msg"
+    );
+}
+
+#[test]
+fn test_installs_hooks_to_hooks_path() {
+    let repo = TestRepo::new();
+    repo.git(&["config", "core.hooksPath", ".githooks"]);
+
+    repo.command(&["install"])
+        .assert()
+        .success()
+        .stdout("installed .githooks/prepare-commit-msg\ninstalled .githooks/commit-msg\n");
+
+    assert!(repo.read(".githooks/commit-msg").contains("# git-ticket: managed hook"), "should have written commit-msg");
+}
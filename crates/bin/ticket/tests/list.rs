@@ -0,0 +1,72 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::indoc;
+
+#[test]
+fn test_lists_tickets_from_branch_and_flags() {
+    let repo = TestRepo::new().branch("feature/PROJ-1");
+
+    repo.command(&["list", "--ticket", "OPS-2", "--ticket", "proj-3"]).assert().success().stdout(indoc! {r"
+        tickets:
+          PROJ-1 from branch
+          OPS-2 from flag
+          proj-3 from flag, warning: does not match the branch pattern `[A-Z][A-Z0-9]+-\d+`
+        footer token: Refs
+    "});
+}
+
+#[test]
+fn test_lists_no_tickets() {
+    TestRepo::new().branch("main").command(&["list"]).assert().success().stdout(indoc! {"
+        tickets: none
+        footer token: Refs
+    "});
+}
+
+#[test]
+fn test_lists_tickets_as_json() {
+    let repo = TestRepo::new().branch("feature/PROJ-1");
+
+    repo.command(&["list", "--ticket", "oops"])
+        .args(["--format", "json", "--footer-token", "Closes"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            r#"{"tickets":[{"ticket":"PROJ-1","source":"branch","valid":true},{"ticket":"oops","source":"flag","valid":false}],"#,
+            r#""footer_token":"Closes"}"#,
+            "\n"
+        ));
+}
+
+#[test]
+fn test_lists_closing_keyword_as_footer_token() {
+    let repo = TestRepo::new().branch("feature/PROJ-1");
+
+    repo.command(&["list", "--closing-keyword", "resolves"]).assert().success().stdout(indoc! {"
+        tickets:
+          PROJ-1 from branch
+        footer token: Resolves
+    "});
+
+    repo.command(&["list", "--closing-keyword", "resolves", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(concat!(
+            r#"{"tickets":[{"ticket":"PROJ-1","source":"branch","valid":true}],"footer_token":"Resolves"}"#,
+            "\n"
+        ));
+}
@@ -0,0 +1,59 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use rstest::rstest;
+
+#[rstest]
+#[case::new_source_file(&["crates/parser/src/lexer.rs"], "feat(parser): add lexer.rs\n")]
+#[case::new_docs(&["docs/user/guide.md", "docs/user/faq.md"], "docs(user): add 2 files\n")]
+#[case::new_workflow(&[".github/workflows/ci.yml"], "ci(workflows): add ci.yml\n")]
+#[case::new_tests(&["tests/parser.rs"], "test: add parser.rs\n")]
+fn test_suggests_header_for_staged_files(#[case] paths: &[&str], #[case] expect: &str) {
+    let repo = paths.iter().fold(TestRepo::new(), |repo, path| repo.stage(path));
+
+    repo.command(&["suggest"]).assert().success().stdout(expect.to_string());
+}
+
+#[test]
+fn test_suggests_fix_for_modified_source_file() {
+    let repo = TestRepo::new()
+        .stage("src/model/person.rs")
+        .commit("feat: add person")
+        .file("src/model/person.rs", "changed\n");
+    repo.git(&["add", "src/model/person.rs"]);
+
+    repo.command(&["suggest"]).assert().success().stdout("fix(model): update person.rs\n");
+}
+
+#[test]
+fn test_prefers_scope_listed_in_codeowners() {
+    let repo = TestRepo::new()
+        .file(".github/CODEOWNERS", "/crates/parser/ @compilers\n")
+        .stage("crates/parser/src/lexer/tokens.rs");
+
+    repo.command(&["suggest"]).assert().success().stdout("feat(parser): add tokens.rs\n");
+}
+
+#[test]
+fn test_fails_when_nothing_is_staged() {
+    let repo = TestRepo::new();
+
+    repo.command(&["suggest"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr("error: there are no staged changes, stage some files with `git add` first\n");
+}
@@ -0,0 +1,101 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+mod common;
+
+use common::TestRepo;
+use indoc::indoc;
+
+const OUR_HOOK: &str = indoc! {r#"
+    #!/bin/sh
+    # git-ticket: managed hook
+    exec git-ticket hook commit-msg "$@"
+"#};
+
+const FOREIGN_HOOK: &str = indoc! {r#"
+    #!/bin/sh
+    . "$(dirname "$0")/husky.sh"
+    npx --no -- commitlint --edit "$1"
+"#};
+
+fn exists(repo: &TestRepo, path: &str) -> bool {
+    repo.path().join(path).exists()
+}
+
+#[test]
+fn test_removes_our_hooks() {
+    let repo = TestRepo::new().file(".git/hooks/prepare-commit-msg", OUR_HOOK).file(".git/hooks/commit-msg", OUR_HOOK);
+
+    repo.command(&["uninstall"])
+        .assert()
+        .success()
+        .stdout("removed .git/hooks/prepare-commit-msg\nremoved .git/hooks/commit-msg\n");
+
+    assert!(!exists(&repo, ".git/hooks/prepare-commit-msg"), "should have removed prepare-commit-msg");
+    assert!(!exists(&repo, ".git/hooks/commit-msg"), "should have removed commit-msg");
+}
+
+#[test]
+fn test_leaves_foreign_hook_in_place() {
+    let repo = TestRepo::new().file(".git/hooks/prepare-commit-msg", OUR_HOOK).file(".git/hooks/commit-msg", FOREIGN_HOOK);
+
+    repo.command(&["uninstall"])
+        .assert()
+        .success()
+        .stdout("removed .git/hooks/prepare-commit-msg\n")
+        .stderr("warning: .git/hooks/commit-msg was not installed by git-ticket, leaving it in place, use --force to remove it\n");
+
+    assert_eq!(FOREIGN_HOOK, repo.read(".git/hooks/commit-msg"));
+}
+
+#[test]
+fn test_removes_foreign_hook_when_forced() {
+    let repo = TestRepo::new().file(".git/hooks/commit-msg", FOREIGN_HOOK);
+
+    repo.command(&["uninstall", "--force"])
+        .assert()
+        .success()
+        .stdout("removed .git/hooks/commit-msg\n")
+        .stderr("");
+
+    assert!(!exists(&repo, ".git/hooks/commit-msg"), "should have removed commit-msg");
+}
+
+#[test]
+fn test_removes_hooks_from_hooks_path() {
+    let repo = TestRepo::new().file(".githooks/commit-msg", OUR_HOOK);
+    repo.git(&["config", "core.hooksPath", ".githooks"]);
+
+    repo.command(&["uninstall"]).assert().success().stdout("removed .githooks/commit-msg\n");
+
+    assert!(!exists(&repo, ".githooks/commit-msg"), "should have removed commit-msg");
+}
+
+#[test]
+fn test_unsets_commit_template_when_asked() {
+    let repo = TestRepo::new();
+    repo.git(&["config", "commit.template", "~/.gitmessage.txt"]);
+
+    repo.command(&["uninstall"]).assert().success().stdout("");
+    assert_eq!("~/.gitmessage.txt", repo.git(&["config", "commit.template"]));
+
+    repo.command(&["uninstall", "--unset-template"]).assert().success().stdout("unset commit.template\n");
+    assert!(!repo.git(&["config", "--local", "--list"]).contains("commit.template"), "should have unset commit.template");
+}
+
+#[test]
+fn test_does_nothing_without_hooks() {
+    let repo = TestRepo::new();
+
+    repo.command(&["uninstall", "--unset-template"]).assert().success().stdout("").stderr("");
+}
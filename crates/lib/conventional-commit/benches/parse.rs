@@ -0,0 +1,76 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Benchmarks of `Commit::parse` and `Commit::is_conventional` on representative messages.
+//!
+//! Run with `cargo bench -p conventional-commit`, and compare against a run of the base branch on the same machine
+//! rather than against absolute timings. Parsing a message should grow with its length only, and the 10k commits history
+//! take about ten thousand times a single message: a regression by a multiple means something allocates or scans more
+//! than it should. `Commit::is_conventional` only reads the header, so its time should stay flat as the body and footers
+//! grow.
+
+use conventional_commit::model::Commit;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+
+/// Messages typical of a repository history, from the shortest to the most complete.
+const MESSAGES: [(&str, &str); 4] = [
+    ("header", "feat: add lexer"),
+    ("scope_and_marker", "feat(parser)!: drop support for the v1 header format"),
+    (
+        "body",
+        "fix(parser): handle tabs in headers\n\nTabs were ignored by the lexer when reading headers, so they ended up in the\ndescription. They are now read as white-space.",
+    ),
+    (
+        "body_and_footers",
+        "feat(parser)!: split the header into tokens\n\nThe lexer reads the type, scope and description separately, so errors point\nat the part of the header that is wrong.\n\nIt also keeps track of positions.\n\nBREAKING CHANGE: the header is no longer parsed by a single regex\nRefs: PROJ-123\nReviewed-by: Alice Bob <alice.bob@test.io>\nCo-Authored-By: Charlie Delta <charlie@delta.io>",
+    ),
+];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    for (name, message) in MESSAGES {
+        group.throughput(Throughput::Bytes(message.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), message, |b, message| b.iter(|| Commit::parse(black_box(message))));
+    }
+
+    group.finish();
+}
+
+fn is_conventional(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_conventional");
+
+    for (name, message) in MESSAGES {
+        group.throughput(Throughput::Bytes(message.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), message, |b, message| {
+            b.iter(|| Commit::is_conventional(black_box(message)));
+        });
+    }
+
+    group.finish();
+}
+
+fn parse_history(c: &mut Criterion) {
+    let history: Vec<&str> = MESSAGES.iter().map(|(_, message)| *message).cycle().take(10_000).collect();
+
+    let mut group = c.benchmark_group("parse_history");
+    group.throughput(Throughput::Elements(history.len() as u64));
+    group.bench_function("10k_commits", |b| {
+        b.iter(|| history.iter().filter(|message| Commit::parse(black_box(message)).is_ok()).count());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, is_conventional, parse_history);
+criterion_main!(benches);
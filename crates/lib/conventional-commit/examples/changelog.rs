@@ -0,0 +1,76 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Prints a Markdown changelog of a `git log` history.
+//!
+//! Run with `git log --format=%B%x00 v1.0.0..HEAD | cargo run -p conventional-commit --example changelog`. The commits
+//! are grouped in one section per type, after a `BREAKING CHANGES` section listing what each breaking commit breaks
+//! along with the issues it references. Commits not following the conventional commits specification are skipped, and
+//! counted on standard error.
+
+use conventional_commit::{
+    changelog::{self, SectionTitles},
+    errors::ConventionalError,
+    log::CommitLog,
+    model::Commit,
+};
+use std::{fmt::Write, io};
+
+fn main() -> Result<(), ConventionalError> {
+    let mut commits = Vec::new();
+    let mut skipped = 0;
+
+    for result in CommitLog::new(io::stdin().lock()) {
+        match result {
+            Ok(commit) => commits.push(commit),
+            Err(ConventionalError::Io(e)) => return Err(ConventionalError::Io(e)),
+            Err(_) => skipped += 1,
+        }
+    }
+
+    print!("{}", render(&commits));
+    if skipped > 0 {
+        eprintln!("skipped {skipped} commit(s) not following the conventional commits specification");
+    }
+
+    Ok(())
+}
+
+/// Renders the changelog: the breaking changes first, then the commits grouped by type.
+fn render(commits: &[Commit]) -> String {
+    let mut out = String::from("# Changelog\n");
+
+    let breaking: Vec<&Commit> = commits.iter().filter(|c| c.is_breaking()).collect();
+    if !breaking.is_empty() {
+        out.push_str("\n### BREAKING CHANGES\n\n");
+        for commit in breaking {
+            let _ = write!(out, "- ");
+            if let Some(scope) = commit.scope() {
+                let _ = write!(out, "**{scope}:** ");
+            }
+            let _ = write!(out, "{}", commit.breaking_change_description().unwrap_or(commit.description().as_str()));
+
+            let issues = commit.issue_references();
+            if !issues.is_empty() {
+                let _ = write!(out, " ({})", issues.join(", "));
+            }
+            out.push('\n');
+        }
+    }
+
+    if !commits.is_empty() {
+        let _ = write!(out, "\n{}", changelog::render(commits, &SectionTitles::default()));
+    }
+
+    out
+}
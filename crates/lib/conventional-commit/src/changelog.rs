@@ -0,0 +1,207 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Markdown changelog rendering for conventional commits.
+//!
+//! Commits are grouped in one section per type. Section headings come from a `SectionTitles` map, which is English by
+//! default and can be localised without affecting the type tokens themselves.
+
+use crate::model::{Commit, CommitType, KNOWN_TYPES};
+use std::{borrow::Cow, collections::HashMap, fmt::Write};
+
+/// The headings used for each commit type's changelog section.
+///
+/// The default titles are the English [`CommitType::section_title`]s, ex: `Features` for `feat`. Custom types without a
+/// title are title-cased, ex: `Wip` for `wip`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::{changelog::SectionTitles, model::CommitType};
+/// let titles = SectionTitles::default().with(CommitType::Feat, "Fonctionnalités");
+///
+/// assert_eq!(titles.title(&CommitType::Feat), "Fonctionnalités");
+/// assert_eq!(titles.title(&CommitType::Fix), "Bug Fixes");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SectionTitles(HashMap<CommitType, String>);
+
+impl SectionTitles {
+    /// Sets the heading for a commit type, replacing any previous one.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type the heading is for.
+    /// * `title` - The heading of the type's section.
+    ///
+    /// # Returns
+    /// The updated titles, for chaining.
+    #[must_use]
+    pub fn with(mut self, commit_type: CommitType, title: impl Into<String>) -> Self {
+        self.0.insert(commit_type, title.into());
+        self
+    }
+
+    /// Returns the heading for a commit type.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type to look up.
+    ///
+    /// # Returns
+    /// The configured heading, or the section title of custom types without one.
+    #[must_use]
+    pub fn title(&self, commit_type: &CommitType) -> Cow<'_, str> {
+        self.0.get(commit_type).map_or_else(|| commit_type.section_title(), |title| Cow::Borrowed(title.as_str()))
+    }
+}
+
+impl Default for SectionTitles {
+    fn default() -> Self {
+        SectionTitles(HashMap::from(KNOWN_TYPES.map(|commit_type| {
+            let title = commit_type.section_title().into_owned();
+            (commit_type, title)
+        })))
+    }
+}
+
+/// Renders commits as a Markdown changelog.
+///
+/// Sections appear in the order their type is first seen, and entries keep the order of the commits. Each entry is the
+/// commit description, prefixed with the scope in bold when there is one.
+///
+/// # Arguments
+/// * `commits` - The commits to include.
+/// * `titles` - The headings to use for each section.
+///
+/// # Returns
+/// The Markdown changelog, or an empty string if there are no commits.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::{changelog::{self, SectionTitles}, model::Commit};
+/// let commits = [Commit::parse("feat(parser): add lexer").unwrap(), Commit::parse("fix: handle tabs").unwrap()];
+///
+/// assert_eq!(
+///     changelog::render(&commits, &SectionTitles::default()),
+///     "### Features\n\n- **parser:** add lexer\n\n### Bug Fixes\n\n- handle tabs\n"
+/// );
+/// ```
+#[must_use]
+pub fn render(commits: &[Commit], titles: &SectionTitles) -> String {
+    let mut sections: Vec<(&CommitType, Vec<&Commit>)> = Vec::new();
+
+    for commit in commits {
+        match sections.iter_mut().find(|(t, _)| *t == commit.commit_type()) {
+            Some((_, entries)) => entries.push(commit),
+            None => sections.push((commit.commit_type(), vec![commit])),
+        }
+    }
+
+    let mut out = String::new();
+
+    for (i, (commit_type, entries)) in sections.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+
+        let _ = writeln!(out, "### {}\n", titles.title(commit_type));
+
+        for commit in entries {
+            let _ = match commit.scope() {
+                Some(scope) => writeln!(out, "- **{scope}:** {}", commit.description()),
+                None => writeln!(out, "- {}", commit.description()),
+            };
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+
+    fn commits(messages: &[&str]) -> Vec<Commit> {
+        messages.iter().map(|m| Commit::parse(m).expect("should have parsed the commit")).collect()
+    }
+
+    #[test]
+    fn test_renders_english_headings_by_default() {
+        let commits = commits(&["feat(parser): add lexer", "fix: handle tabs", "feat: add footers", "wip: try things"]);
+
+        assert_eq!(
+            indoc! {"
+                ### Features
+
+                - **parser:** add lexer
+                - add footers
+
+                ### Bug Fixes
+
+                - handle tabs
+
+                ### Wip
+
+                - try things
+            "},
+            render(&commits, &SectionTitles::default())
+        );
+    }
+
+    #[test]
+    fn test_renders_localised_headings() {
+        let commits = commits(&["feat(parser): ajouter l'analyseur", "fix: gérer les tabulations", "docs: expliquer les pieds de page"]);
+        let titles = SectionTitles::default()
+            .with(CommitType::Feat, "Fonctionnalités")
+            .with(CommitType::Fix, "Corrections de bugs")
+            .with(CommitType::Docs, "Documentation");
+
+        assert_eq!(
+            indoc! {"
+                ### Fonctionnalités
+
+                - **parser:** ajouter l'analyseur
+
+                ### Corrections de bugs
+
+                - gérer les tabulations
+
+                ### Documentation
+
+                - expliquer les pieds de page
+            "},
+            render(&commits, &titles)
+        );
+    }
+
+    #[test]
+    fn test_localised_headings_do_not_change_type_tokens() {
+        let commits = commits(&["feat: add lexer"]);
+        let _ = render(&commits, &SectionTitles::default().with(CommitType::Feat, "Fonctionnalités"));
+
+        assert_eq!("feat: add lexer", format!("{}", commits[0]));
+    }
+
+    #[test]
+    fn test_renders_nothing_without_commits() {
+        assert_eq!("", render(&[], &SectionTitles::default()));
+    }
+
+    #[test]
+    fn test_titles_custom_type_when_configured() {
+        let titles = SectionTitles::default().with(CommitType::Custom("wip".into()), "Work in progress");
+        assert_eq!("Work in progress", titles.title(&CommitType::Custom("wip".into())));
+    }
+}
@@ -15,15 +15,32 @@
 //!
 //! This module provides a standardized way to collect and display multiple errors.
 //! It includes the `Errors` struct for managing collections of errors and the
-//! `multi_error!` macro for convenient error collection creation.
+//! `multi_error!` macro for convenient error collection creation, the `ContextErrors`
+//! wrapper labelling a collection with where its errors come from, as well as the
+//! `ConventionalError` enum returned by every operation parsing a commit message.
+//! `ConventionalError` requires the `std` feature, the rest is `no_std`.
 
-use core::error::Error as CoreError;
-use std::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "std")]
+use crate::model::ValidationError;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    error::Error as CoreError,
+    fmt::{Debug, Display, Formatter},
+};
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use thiserror::Error;
 
 /// Creates a collection of errors.
 ///
 /// This macro simplifies the creation of an `Errors` struct by accepting
-/// a comma-separated list of error instances.
+/// a comma-separated list of error instances. Like `vec!`, a trailing comma
+/// is allowed, and calling it without arguments creates an empty collection.
 ///
 /// # Examples
 ///
@@ -49,14 +66,21 @@ use std::fmt::{Debug, Display, Formatter};
 /// // Create an Errors collection with multiple errors
 /// let multiple_errors = multi_error!(
 ///     MyError::new("first error"),
-///     MyError::new("second error")
+///     MyError::new("second error"),
 /// );
+///
+/// // Create an empty Errors collection
+/// let no_errors: Errors<MyError> = multi_error!();
+/// assert!(no_errors.is_empty());
 /// ```
 #[macro_export]
 macro_rules! multi_error {
-    ($($err:expr),+) => {
-        $crate::errors::Errors::from(vec![$($err),+])
-    }
+    () => {
+        $crate::errors::Errors::from($crate::__private::Vec::new())
+    };
+    ($($err:expr),+ $(,)?) => {
+        $crate::errors::Errors::from($crate::__private::vec![$($err),+])
+    };
 }
 
 /// A collection of errors that implements the `Error` trait.
@@ -105,9 +129,15 @@ macro_rules! multi_error {
 /// let wrapper = WrapperError(errors);
 /// ```
 #[derive(Debug, PartialEq)]
-pub struct Errors<E>(Vec<E>)
+pub struct Errors<E>
 where
-    E: CoreError + Debug + PartialEq;
+    E: CoreError + Debug + PartialEq,
+{
+    /// The errors, in the order they were added.
+    errors: Vec<E>,
+    /// How many errors were dropped by [`Errors::truncate`].
+    omitted: usize,
+}
 
 impl<E> Errors<E>
 where
@@ -118,7 +148,7 @@ where
     /// # Returns
     /// A new instance of `Errors` containing no errors.
     pub(crate) fn new() -> Self {
-        Self(Vec::new())
+        Self { errors: Vec::new(), omitted: 0 }
     }
 
     /// Adds a new error to the collection.
@@ -159,26 +189,526 @@ where
     /// // The collection now contains both errors
     /// ```
     pub fn append(&mut self, err: E) {
-        self.0.push(err);
+        self.errors.push(err);
+    }
+
+    /// Moves all the errors of another collection to the end of this one.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The collection whose errors are appended, in order
+    pub(crate) fn merge(&mut self, other: Self) {
+        self.errors.extend(other.errors);
+        self.omitted += other.omitted;
+    }
+
+    /// Removes consecutive repeated errors, keeping the first of each run.
+    ///
+    /// Only adjacent duplicates are removed, the relative order of the remaining errors is unchanged. Sort the collection
+    /// first, or use [`Errors::dedup_all`], to remove duplicates that are not next to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let mut errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::MissingRequiredField("email".into())
+    /// );
+    ///
+    /// errors.dedup();
+    ///
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    pub fn dedup(&mut self) {
+        self.errors.dedup();
+    }
+
+    /// Removes every repeated error, keeping the first occurrence of each.
+    ///
+    /// The relative order of the remaining errors is unchanged. Each error is compared with the ones kept before it, so
+    /// this only requires `PartialEq` and runs in quadratic time, which is fine for the handful of errors a validation
+    /// produces.
+    pub fn dedup_all(&mut self) {
+        let mut kept: Vec<E> = Vec::with_capacity(self.errors.len());
+
+        for err in self.errors.drain(..) {
+            if !kept.contains(&err) {
+                kept.push(err);
+            }
+        }
+
+        self.errors = kept;
+    }
+
+    /// Keeps only the errors the predicate returns `true` for, ex: to drop the non-fatal ones.
+    ///
+    /// The relative order of the kept errors is unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `keep` - Returns whether an error should be kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let mut errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::InvalidFieldValue("email".into(), anyhow::anyhow!("must contain an '@'"))
+    /// );
+    ///
+    /// errors.retain(|e| matches!(e, ValidationError::MissingRequiredField(_)));
+    ///
+    /// assert_eq!(format!("{errors}"), "error(s):\n  field 'name' is required");
+    /// ```
+    pub fn retain<F>(&mut self, keep: F)
+    where
+        F: FnMut(&E) -> bool,
+    {
+        self.errors.retain(keep);
+    }
+
+    /// Converts each error into another type, ex: to report library errors through an application's own error type.
+    ///
+    /// The order of the errors and the count of errors dropped by [`Errors::truncate`] are kept.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Converts an error
+    ///
+    /// # Returns
+    /// A collection of the converted errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    /// # use thiserror::Error;
+    ///
+    /// #[derive(Error, Debug, PartialEq)]
+    /// #[error("commit rejected: {0}")]
+    /// struct AppError(String);
+    ///
+    /// let errors = multi_error!(ValidationError::MissingRequiredField("scope".into()));
+    /// let errors = errors.map(|e| AppError(e.to_string()));
+    ///
+    /// assert_eq!(format!("{errors}"), "error(s):\n  commit rejected: field 'scope' is required");
+    /// ```
+    pub fn map<F, T>(self, f: F) -> Errors<T>
+    where
+        F: FnMut(E) -> T,
+        T: CoreError + Debug + PartialEq,
+    {
+        Errors {
+            errors: self.errors.into_iter().map(f).collect(),
+            omitted: self.omitted,
+        }
+    }
+
+    /// Sorts the errors with a comparator function.
+    ///
+    /// The sort is stable: errors that compare equal keep their relative order.
+    ///
+    /// # Parameters
+    ///
+    /// * `compare` - Returns the ordering of two errors
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&E, &E) -> Ordering,
+    {
+        self.errors.sort_by(compare);
+    }
+
+    /// Sorts the errors by a key extracted from each of them.
+    ///
+    /// The sort is stable: errors with equal keys keep their relative order.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - Extracts the key to sort by from an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let mut errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::MissingRequiredField("email".into())
+    /// );
+    ///
+    /// errors.sort_by_key(ToString::to_string);
+    ///
+    /// assert_eq!(format!("{errors}"), "error(s):\n  field 'email' is required\n  field 'name' is required");
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, key: F)
+    where
+        K: Ord,
+        F: FnMut(&E) -> K,
+    {
+        self.errors.sort_by_key(key);
+    }
+
+    /// Keeps the first `len` errors, dropping the others and remembering how many were dropped.
+    ///
+    /// The dropped errors are reported as a trailing `and M more` line when the collection is displayed, and the
+    /// collection is still considered to hold errors even when `len` is `0`. Nothing changes if there are no more than
+    /// `len` errors.
+    ///
+    /// # Parameters
+    ///
+    /// * `len` - How many errors to keep
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let mut errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("type".into()),
+    ///     ValidationError::MissingRequiredField("description".into()),
+    ///     ValidationError::MissingRequiredField("email".into())
+    /// );
+    ///
+    /// errors.truncate(1);
+    ///
+    /// assert_eq!(errors.omitted(), 2);
+    /// assert_eq!(format!("{errors}"), "error(s):\n  field 'type' is required\n  and 2 more");
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.omitted += self.errors.len().saturating_sub(len);
+        self.errors.truncate(len);
+    }
+
+    /// Returns how many errors were dropped by [`Errors::truncate`].
+    ///
+    /// # Returns
+    /// The amount of dropped errors, `0` if the collection was never truncated.
+    #[must_use]
+    pub fn omitted(&self) -> usize {
+        self.omitted
+    }
+
+    /// Returns an iterator over the errors, in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::MissingRequiredField("email".into())
+    /// );
+    ///
+    /// let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    ///
+    /// assert_eq!(messages, ["field 'name' is required", "field 'email' is required"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &E> {
+        self.errors.iter()
+    }
+
+    /// Returns the errors as a slice, for code that doesn't know about `Errors`.
+    ///
+    /// # Returns
+    /// The errors in the order they were added, without the ones dropped by [`Errors::truncate`].
+    #[must_use]
+    pub fn as_slice(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Takes ownership of the errors, for code that doesn't know about `Errors`.
+    ///
+    /// # Returns
+    /// The errors in the order they were added. The count of errors dropped by [`Errors::truncate`] is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::MissingRequiredField("email".into())
+    /// );
+    ///
+    /// assert_eq!(errors.as_slice().len(), 2);
+    /// assert_eq!(
+    ///     errors.into_vec(),
+    ///     vec![ValidationError::MissingRequiredField("name".into()), ValidationError::MissingRequiredField("email".into())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn into_vec(self) -> Vec<E> {
+        self.errors
+    }
+
+    /// Turns the collection into the result of an operation, the value when no error was collected.
+    ///
+    /// # Arguments
+    /// * `value` - What the operation produced.
+    ///
+    /// # Returns
+    /// * `Ok(T)` with the value if the collection is empty.
+    /// * `Err(Errors<E>)` with the collection otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns the collection itself when it holds at least one error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::{errors::Errors, model::ValidationError, multi_error};
+    ///
+    /// let errors: Errors<ValidationError> = multi_error!();
+    /// assert_eq!(errors.into_result("Alice").ok(), Some("Alice"));
+    ///
+    /// let errors = multi_error!(ValidationError::MissingRequiredField("name".into()));
+    /// assert_eq!(errors.into_result("").unwrap_err().len(), 1);
+    /// ```
+    pub fn into_result<T>(self, value: T) -> Result<T, Self> {
+        if self.is_empty() { Ok(value) } else { Err(self) }
+    }
+
+    /// Returns the first error added, typically the primary one.
+    ///
+    /// Unlike `source()`, which exposes the same error as a `dyn CoreError` for error chains, this returns the concrete
+    /// type so no downcasting is needed.
+    ///
+    /// # Returns
+    /// * `Some(&E)` with the first error.
+    /// * `None` if the collection is empty, including when every error was dropped by [`Errors::truncate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use conventional_commit::model::ValidationError;
+    /// use conventional_commit::multi_error;
+    ///
+    /// let errors = multi_error!(
+    ///     ValidationError::MissingRequiredField("name".into()),
+    ///     ValidationError::MissingRequiredField("email".into())
+    /// );
+    ///
+    /// assert_eq!(errors.first(), Some(&ValidationError::MissingRequiredField("name".into())));
+    /// assert_eq!(errors.last(), Some(&ValidationError::MissingRequiredField("email".into())));
+    /// ```
+    #[must_use]
+    pub fn first(&self) -> Option<&E> {
+        self.errors.first()
+    }
+
+    /// Returns the last error added.
+    ///
+    /// # Returns
+    /// * `Some(&E)` with the last error kept.
+    /// * `None` if the collection is empty, including when every error was dropped by [`Errors::truncate`].
+    #[must_use]
+    pub fn last(&self) -> Option<&E> {
+        self.errors.last()
     }
 
     /// Returns `true` if the collection contains no errors.
     ///
     /// # Returns
-    /// * `true` if no errors are in the collection.
+    /// * `true` if no errors are in the collection, and none were dropped by [`Errors::truncate`].
     /// * `false` if there is at least one error.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.errors.is_empty() && self.omitted == 0
     }
 
     /// Returns the amount of errors in the collection.
     ///
     /// # Returns
-    /// The amount of errors stored in this collection. Returns `0` if empty.
+    /// The amount of errors stored in this collection, not counting the ones dropped by [`Errors::truncate`]. Returns
+    /// `0` if empty.
     #[must_use]
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.errors.len()
+    }
+
+    /// Returns a report displaying the errors like `Display` does, optionally colored for terminals.
+    ///
+    /// # Arguments
+    /// * `use_color` - Whether to highlight the `error(s):` header and the `and N more` line with ANSI escape codes.
+    ///   Callers should only enable it when writing to a terminal and the `NO_COLOR` environment variable is unset.
+    ///
+    /// # Returns
+    /// An `ErrorsReport` borrowing the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::{errors::Errors, model::ValidationError};
+    /// let errs = Errors::from([ValidationError::MissingRequiredField("scope".into())]);
+    ///
+    /// assert_eq!(errs.report(false).to_string(), errs.to_string());
+    /// assert!(errs.report(true).to_string().starts_with("\x1b[1;31merror(s):\x1b[0m"));
+    /// ```
+    #[must_use]
+    pub fn report(&self, use_color: bool) -> ErrorsReport<'_, E> {
+        ErrorsReport {
+            errors: self,
+            context: None,
+            use_color,
+        }
+    }
+
+    /// Labels the errors with where they come from, ex: `author[0]`, to prefix each of them when displayed.
+    ///
+    /// The errors themselves are left as they are, so they can still be matched on and compared.
+    ///
+    /// # Arguments
+    /// * `context` - The label, ex: the path of the nested value the errors were found in.
+    ///
+    /// # Returns
+    /// A `ContextErrors` owning the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::{model::ValidationError, multi_error};
+    /// let errors = multi_error!(ValidationError::MissingRequiredField("email".into())).with_context("author[0]");
+    ///
+    /// assert_eq!(errors.to_string(), "error(s):\n  author[0]: field 'email' is required");
+    /// assert_eq!(errors.errors().first(), Some(&ValidationError::MissingRequiredField("email".into())));
+    /// ```
+    #[must_use]
+    pub fn with_context(self, context: impl Into<String>) -> ContextErrors<E> {
+        ContextErrors {
+            context: context.into(),
+            errors: self,
+        }
+    }
+}
+
+/// A collection of errors labelled with where they come from, created by [`Errors::with_context`].
+///
+/// It displays like `Errors`, with each error prefixed by the label. Its source is the first error, unchanged, and two
+/// `ContextErrors` are equal when both their labels and their errors are, compare [`ContextErrors::errors`] to ignore
+/// the labels. Collections of `ContextErrors` can be nested in an `Errors`, ex: one per co-author of a commit.
+#[derive(Debug, PartialEq)]
+pub struct ContextErrors<E>
+where
+    E: CoreError + Debug + PartialEq,
+{
+    context: String,
+    errors: Errors<E>,
+}
+
+impl<E> ContextErrors<E>
+where
+    E: CoreError + Debug + PartialEq,
+{
+    /// Returns the label of the errors.
+    #[must_use]
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    /// Returns the errors, without their label.
+    #[must_use]
+    pub fn errors(&self) -> &Errors<E> {
+        &self.errors
+    }
+
+    /// Returns the errors, dropping their label.
+    #[must_use]
+    pub fn into_errors(self) -> Errors<E> {
+        self.errors
+    }
+
+    /// Returns a report displaying the labelled errors, optionally colored for terminals like [`Errors::report`].
+    ///
+    /// # Arguments
+    /// * `use_color` - Whether to highlight the `error(s):` header and the `and N more` line with ANSI escape codes.
+    ///
+    /// # Returns
+    /// An `ErrorsReport` borrowing the collection.
+    #[must_use]
+    pub fn report(&self, use_color: bool) -> ErrorsReport<'_, E> {
+        ErrorsReport {
+            errors: &self.errors,
+            context: Some(&self.context),
+            use_color,
+        }
+    }
+}
+
+impl<E> Display for ContextErrors<E>
+where
+    E: CoreError + Debug + PartialEq,
+{
+    /// Formats the errors like the `Display` of `Errors`, with each error prefixed by the label, ex: `author[0]: `.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.report(false))
+    }
+}
+
+impl<E> CoreError for ContextErrors<E>
+where
+    E: CoreError + Debug + PartialEq + 'static,
+{
+    /// Returns the first error, unchanged by the label, as the source.
+    fn source(&self) -> Option<&(dyn CoreError + 'static)> {
+        self.errors.source()
+    }
+}
+
+/// A display of an `Errors` collection, colored with ANSI escape codes when enabled, created by [`Errors::report`].
+///
+/// Color is kept out of the `Display` of `Errors` itself, so error chains and logs always get plain text.
+#[derive(Debug)]
+pub struct ErrorsReport<'a, E>
+where
+    E: CoreError + Debug + PartialEq,
+{
+    errors: &'a Errors<E>,
+    context: Option<&'a str>,
+    use_color: bool,
+}
+
+impl<E> Display for ErrorsReport<'_, E>
+where
+    E: CoreError + Debug + PartialEq,
+{
+    /// Formats the errors like the `Display` of `Errors`, with the header in bold red and the count of omitted errors
+    /// dimmed when color is enabled.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (header, dim, reset) = if self.use_color { ("\x1b[1;31m", "\x1b[2m", "\x1b[0m") } else { ("", "", "") };
+
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "{header}error(s):{reset}")?;
+        for err in &self.errors.errors {
+            write!(f, "\n  ")?;
+            if let Some(context) = self.context {
+                write!(f, "{context}: ")?;
+            }
+            write!(f, "{}", err.to_string().replace('\n', "\n  "))?;
+        }
+
+        if self.errors.omitted > 0 {
+            write!(f, "\n  {dim}and {} more{reset}", self.errors.omitted)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -196,7 +726,10 @@ where
     ///
     /// * `value` - An iterable collection of errors to convert
     fn from(value: I) -> Self {
-        Errors(value.into_iter().collect())
+        Errors {
+            errors: value.into_iter().collect(),
+            omitted: 0,
+        }
     }
 }
 
@@ -212,20 +745,14 @@ where
     ///   first error message
     ///   second error message
     ///   ...
+    ///   and 2 more
     /// ```
     ///
-    /// If the collection is empty, nothing is displayed.
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.0.is_empty() {
-            return Ok(());
-        }
-
-        write!(f, "error(s):")?;
-        for err in &self.0 {
-            write!(f, "\n  {err}")?;
-        }
-
-        Ok(())
+    /// The last line only appears when errors were dropped by [`Errors::truncate`]. If the collection is empty, nothing
+    /// is displayed. Every line of a multi-line error is indented, so a nested `Errors` is indented two more spaces per
+    /// level of nesting.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.report(false))
     }
 }
 
@@ -243,18 +770,108 @@ where
     /// * `Some(&dyn CoreError)` - A reference to the first error if the collection is not empty
     /// * `None` - If the collection is empty
     fn source(&self) -> Option<&(dyn CoreError + 'static)> {
-        self.0.first().map::<&(dyn CoreError + 'static), _>(|e| e)
+        self.errors.first().map::<&(dyn CoreError + 'static), _>(|e| e)
     }
 }
 
+/// The errors returned by the crate's operations parsing commit messages, ex: `Commit::parse`, `Commit::from_bytes` or
+/// `log::CommitLog`.
+///
+/// The field-level problems of an invalid message are kept as `ValidationError`s in the `Validation` variant, the other
+/// variants are only returned when reading raw input.
+#[cfg(feature = "std")]
+#[derive(Error, Debug)]
+pub enum ConventionalError {
+    /// The message is not a valid conventional commit.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - The problems found in the message
+    #[error(transparent)]
+    Validation(#[from] Errors<ValidationError>),
+
+    /// The input could not be read as a commit message, ex: it is not valid UTF-8.
+    ///
+    /// # Parameters
+    ///
+    /// * `0` - Why the input is malformed
+    #[error("malformed commit message, {0}")]
+    Parse(String),
+
+    /// Reading the input failed.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use anyhow::anyhow;
     use prop::collection::vec;
     use proptest::prelude::*;
     use rstest::rstest;
-    use thiserror::Error;
+
+    #[test]
+    fn test_retains_matching_errors() {
+        let mut errs = Errors::from([
+            ValidationError::MissingRequiredField("type".into()),
+            ValidationError::InvalidFieldValue("scope".into(), anyhow!("must not be blank")),
+            ValidationError::MissingRequiredField("description".into()),
+        ]);
+
+        errs.retain(|e| matches!(e, ValidationError::MissingRequiredField(_)));
+
+        assert_eq!(
+            Errors::from([
+                ValidationError::MissingRequiredField("type".into()),
+                ValidationError::MissingRequiredField("description".into())
+            ]),
+            errs
+        );
+    }
+
+    #[test]
+    fn test_maps_errors_to_another_type() {
+        let mut errs = Errors::from([
+            ValidationError::MissingRequiredField("type".into()),
+            ValidationError::InvalidFieldValue("scope".into(), anyhow!("must not be blank")),
+            ValidationError::MissingRequiredField("description".into()),
+        ]);
+        errs.truncate(2);
+
+        let errs = errs.map(|e| TestError::String(e.to_string()));
+
+        assert_eq!(
+            "error(s):\n  string error: field 'type' is required\n  string error: field 'scope' has invalid value: must not be blank\n  and 1 more",
+            errs.to_string()
+        );
+    }
+
+    #[test]
+    fn test_recovers_kept_errors() {
+        let mut errs = Errors::from([TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(3)]);
+        errs.truncate(2);
+
+        assert_eq!([TestError::Numeric(1), TestError::Numeric(2)], errs.as_slice());
+        assert_eq!(vec![TestError::Numeric(1), TestError::Numeric(2)], errs.into_vec());
+    }
+
+    #[rstest]
+    #[case::empty(Errors::new(), Ok(42))]
+    #[case::errors(Errors::from([TestError::Numeric(1)]), Err(Errors::from([TestError::Numeric(1)])))]
+    fn test_turns_errors_into_result(#[case] errs: Errors<TestError>, #[case] expect: Result<i32, Errors<TestError>>) {
+        assert_eq!(expect, errs.into_result(42));
+    }
+
+    #[rstest]
+    #[case::single(Errors::from([TestError::Numeric(1)]), Some(TestError::Numeric(1)), Some(TestError::Numeric(1)))]
+    #[case::several(Errors::from([TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(3)]), Some(TestError::Numeric(1)), Some(TestError::Numeric(3)))]
+    #[case::empty(Errors::new(), None, None)]
+    fn test_returns_first_and_last_errors(#[case] errors: Errors<TestError>, #[case] first: Option<TestError>, #[case] last: Option<TestError>) {
+        assert_eq!(first.as_ref(), errors.first());
+        assert_eq!(last.as_ref(), errors.last());
+    }
 
     #[rstest]
     #[case::single_error(
@@ -297,6 +914,23 @@ mod tests {
             "  string error: inner error",
         ],
 	)]
+    #[case::nested_errors(
+        multi_error!(
+            TestError::Numeric(1),
+            TestError::Nested(Box::new(multi_error!(
+                TestError::Numeric(2),
+                TestError::Nested(Box::new(multi_error!(TestError::Numeric(3))))
+            )))
+        ),
+        vec![
+            "error(s):",
+            "  numeric error: 1",
+            "  error(s):",
+            "    numeric error: 2",
+            "    error(s):",
+            "      numeric error: 3",
+        ],
+	)]
     #[case::mixed_errors(
         multi_error!(
             TestError::Numeric(42),
@@ -364,26 +998,207 @@ mod tests {
     }
 
     #[rstest]
-    #[case::empty(Errors(vec![]), true)]
+    #[case::past_the_cap(&[1, 2, 3, 4], 2, &[1, 2], 2)]
+    #[case::at_the_cap(&[1, 2], 2, &[1, 2], 0)]
+    #[case::under_the_cap(&[1], 2, &[1], 0)]
+    fn test_truncates_errors(#[case] errors: &[i32], #[case] len: usize, #[case] kept: &[i32], #[case] omitted: usize) {
+        let mut errs = Errors::from(errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>());
+
+        errs.truncate(len);
+
+        assert_eq!(kept, errs.iter().map(|e| if let TestError::Numeric(i) = e { *i } else { 0 }).collect::<Vec<_>>());
+        assert_eq!(omitted, errs.omitted());
+    }
+
+    #[test]
+    fn test_displays_omitted_errors_count() {
+        let mut errs = multi_error!(TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(3));
+
+        errs.truncate(1);
+
+        assert_eq!("error(s):\n  numeric error: 1\n  and 2 more", format!("{errs}"));
+    }
+
+    #[rstest]
+    #[case::plain(false, "error(s):\n  numeric error: 1\n  and 2 more")]
+    #[case::colored(true, "\x1b[1;31merror(s):\x1b[0m\n  numeric error: 1\n  \x1b[2mand 2 more\x1b[0m")]
+    fn test_reports_errors(#[case] use_color: bool, #[case] expect: &str) {
+        let mut errs = multi_error!(TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(3));
+
+        errs.truncate(1);
+
+        assert_eq!(expect, errs.report(use_color).to_string());
+    }
+
+    #[rstest]
+    #[case::plain(false)]
+    #[case::colored(true)]
+    fn test_reports_nothing_when_empty(#[case] use_color: bool) {
+        let errs: Errors<TestError> = multi_error!();
+        assert_eq!("", errs.report(use_color).to_string());
+    }
+
+    #[test]
+    fn test_prefixes_errors_with_context() {
+        let mut errs = multi_error!(TestError::Numeric(1), TestError::String("two\nlines".into()), TestError::Numeric(3));
+        errs.truncate(2);
+
+        let errs = errs.with_context("author[0]");
+
+        assert_eq!(
+            "error(s):\n  author[0]: numeric error: 1\n  author[0]: string error: two\n  lines\n  and 1 more",
+            errs.to_string()
+        );
+        assert_eq!("author[0]", errs.context());
+        assert_eq!(
+            "\x1b[1;31merror(s):\x1b[0m\n  author[0]: numeric error: 1\n  author[0]: string error: two\n  lines\n  \x1b[2mand 1 more\x1b[0m",
+            errs.report(true).to_string()
+        );
+    }
+
+    #[test]
+    fn test_keeps_errors_unchanged_under_context() {
+        let errs = multi_error!(TestError::Numeric(1), TestError::Numeric(2));
+
+        let labelled = multi_error!(TestError::Numeric(1), TestError::Numeric(2)).with_context("author[0]");
+
+        assert_eq!(&errs, labelled.errors());
+        assert_eq!(Some("numeric error: 1".to_string()), labelled.source().map(ToString::to_string));
+        assert_ne!(multi_error!(TestError::Numeric(1), TestError::Numeric(2)).with_context("author[1]"), labelled);
+        assert_eq!(errs, labelled.into_errors());
+    }
+
+    #[test]
+    fn test_nests_errors_with_context() {
+        let errs = multi_error!(
+            multi_error!(TestError::Numeric(1)).with_context("author[0]"),
+            multi_error!(TestError::Numeric(2), TestError::Numeric(3)).with_context("author[1]")
+        );
+
+        assert_eq!(
+            "error(s):\n  error(s):\n    author[0]: numeric error: 1\n  error(s):\n    author[1]: numeric error: 2\n    author[1]: numeric error: 3",
+            errs.to_string()
+        );
+    }
+
+    #[test]
+    fn test_is_not_empty_when_every_error_is_omitted() {
+        let mut errs = multi_error!(TestError::Numeric(1));
+
+        errs.truncate(0);
+
+        assert!(!errs.is_empty());
+        assert_eq!("error(s):\n  and 1 more", format!("{errs}"));
+    }
+
+    #[test]
+    fn test_creates_empty_errors_without_arguments() {
+        let errs: Errors<TestError> = multi_error!();
+        assert_eq!(Errors::from(vec![]), errs);
+    }
+
+    #[test]
+    fn test_accepts_trailing_comma() {
+        assert_eq!(
+            Errors::from(vec![TestError::Numeric(1), TestError::Numeric(2)]),
+            multi_error!(TestError::Numeric(1), TestError::Numeric(2),)
+        );
+    }
+
+    #[rstest]
+    #[case::empty(Errors::from(vec![]), true)]
     #[case::one(multi_error!(TestError::Numeric(1)), false)]
     fn test_is_empty(#[case] errs: Errors<TestError>, #[case] expect: bool) {
         assert_eq!(expect, errs.is_empty());
     }
 
     #[rstest]
-    #[case::empty(Errors(vec![]), 0)]
+    #[case::empty(Errors::from(vec![]), 0)]
     #[case::two(multi_error!(TestError::Numeric(1), TestError::Numeric(2)), 2)]
     fn test_len(#[case] errs: Errors<TestError>, #[case] expect: usize) {
         assert_eq!(expect, errs.len());
     }
 
+    #[rstest]
+    #[case::no_duplicates(multi_error!(TestError::Numeric(1), TestError::Numeric(2)), multi_error!(TestError::Numeric(1), TestError::Numeric(2)))]
+    #[case::adjacent_duplicates(
+        multi_error!(TestError::Numeric(1), TestError::Numeric(1), TestError::Numeric(2)),
+        multi_error!(TestError::Numeric(1), TestError::Numeric(2))
+    )]
+    #[case::non_adjacent_duplicates_kept(
+        multi_error!(TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(1)),
+        multi_error!(TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(1))
+    )]
+    fn test_dedup(#[case] mut errs: Errors<TestError>, #[case] expect: Errors<TestError>) {
+        errs.dedup();
+        assert_eq!(expect, errs);
+    }
+
+    #[rstest]
+    #[case::adjacent_duplicates(
+        multi_error!(TestError::Numeric(1), TestError::Numeric(1), TestError::Numeric(2)),
+        multi_error!(TestError::Numeric(1), TestError::Numeric(2))
+    )]
+    #[case::non_adjacent_duplicates(
+        multi_error!(TestError::Numeric(2), TestError::String("a".into()), TestError::Numeric(2), TestError::String("a".into())),
+        multi_error!(TestError::Numeric(2), TestError::String("a".into()))
+    )]
+    fn test_dedup_all_keeps_first_occurrences(#[case] mut errs: Errors<TestError>, #[case] expect: Errors<TestError>) {
+        errs.dedup_all();
+        assert_eq!(expect, errs);
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        let mut errs = multi_error!(TestError::String("b".into()), TestError::Numeric(2), TestError::String("a".into()), TestError::Numeric(1));
+
+        errs.sort_by(|a, b| matches!(a, TestError::String(_)).cmp(&matches!(b, TestError::String(_))));
+
+        assert_eq!(
+            multi_error!(TestError::Numeric(2), TestError::Numeric(1), TestError::String("b".into()), TestError::String("a".into())),
+            errs
+        );
+    }
+
+    #[test]
+    fn test_sort_by_key() {
+        let mut errs = multi_error!(TestError::Numeric(3), TestError::Numeric(1), TestError::Numeric(2));
+
+        errs.sort_by_key(|e| match e {
+            TestError::Numeric(n) => *n,
+            _ => 0,
+        });
+
+        assert_eq!(multi_error!(TestError::Numeric(1), TestError::Numeric(2), TestError::Numeric(3)), errs);
+    }
+
     proptest! {
+        #[test]
+        fn prop_dedup_leaves_no_adjacent_duplicates(errors in vec(1..5i32, 0..50)) {
+            let mut errs = Errors::from(errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>());
+
+            errs.dedup();
+
+            prop_assert!(errs.errors.windows(2).all(|w| w[0] != w[1]));
+        }
+
+        #[test]
+        fn prop_dedup_all_leaves_no_duplicates(errors in vec(1..5i32, 0..50)) {
+            let mut errs = Errors::from(errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>());
+
+            errs.dedup_all();
+
+            for (i, err) in errs.errors.iter().enumerate() {
+                prop_assert!(!errs.errors[i + 1..].contains(err));
+            }
+        }
+
         #[test]
         fn prop_errors_display_has_correct_line_count(errors in vec(1..100i32, 1..50)) {
             let test_errors = errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>();
             let expected_line_count = test_errors.len() + 1;
 
-            let errs = Errors(test_errors);
+            let errs = Errors::from(test_errors);
 
             let display_output = format!("{errs}");
 
@@ -399,7 +1214,7 @@ mod tests {
             }
 
             let test_errors = errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>();
-            let errors_struct = Errors(test_errors);
+            let errors_struct = Errors::from(test_errors);
 
             let source = errors_struct.source()
                 .expect("should have extracted source error")
@@ -412,7 +1227,7 @@ mod tests {
         #[test]
         fn prop_errors_display_starts_with_header(errors in vec(1..100i32, 1..50)) {
             let test_errors = errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>();
-            let errs = Errors(test_errors);
+            let errs = Errors::from(test_errors);
 
             let display_output = format!("{errs}");
 
@@ -422,7 +1237,7 @@ mod tests {
         #[test]
         fn prop_errors_display_has_correct_indentation(errors in vec(1..100i32, 1..50)) {
             let test_errors = errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>();
-            let errs = Errors(test_errors);
+            let errs = Errors::from(test_errors);
 
             let display_output = format!("{errs}");
             let error_lines = display_output.lines().skip(1);
@@ -436,9 +1251,9 @@ mod tests {
         #[allow(clippy::len_zero)] // Allowed here for the assertion to be meaningful
         fn prop_is_empty_len_relationship(errors in vec(1..100i32, 0..50)) {
             let test_errors = errors.iter().map(|&i| TestError::Numeric(i)).collect::<Vec<_>>();
-            let errs = Errors(test_errors);
+            let errs = Errors::from(test_errors);
 
-            prop_assert_eq!(errs.0.is_empty(), errs.0.len() == 0);
+            prop_assert_eq!(errs.errors.is_empty(), errs.errors.len() == 0);
         }
     }
 
@@ -15,7 +15,32 @@
 //!
 //! This crate provides core types and utilities for representing and validating conventional commit data
 //! in the header, footer, and body of the commit.
+//!
+//! # Features
+//!
+//! The `std` feature, enabled by default, provides everything. Without it the crate is `no_std`, needing only `alloc`,
+//! and provides the error types: [`errors::Errors`], [`errors::ContextErrors`], [`errors::ErrorsReport`] and the
+//! [`multi_error!`] macro, and in `model` the [`model::ValidationError`] error with the
+//! [`model::Build`] and [`model::Validate`] traits and [`model::validate_all`]. The commit components, such as `Commit`
+//! and the email-checking `Person`, and the `changelog`, `lint`, `log` and `scissors` modules require `std`.
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod changelog;
 pub mod errors;
+#[cfg(feature = "std")]
+pub mod lint;
+#[cfg(feature = "std")]
+pub mod log;
 pub mod model;
+#[cfg(feature = "std")]
+pub mod scissors;
+
+/// Re-exports used by the crate's macros, so they expand without `std`.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::{vec, vec::Vec};
+}
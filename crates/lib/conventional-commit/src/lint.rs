@@ -0,0 +1,183 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Opt-in lint rules applied on top of the conventional commit specification.
+//!
+//! Parsing only enforces the specification itself. Teams wanting stricter messages enable extra rules through
+//! `LintOptions` and check parsed commits with `Commit::validate_with`. A commit parsed once with
+//! `Commit::parse_structure` can be checked against as many sets of rules as needed. Likewise, `NormalizeOptions`
+//! enables extra fixes applied by `Commit::normalized_with`. Teams already using commitlint can instead describe their
+//! rules with a `RuleConfig` and check commits with `Commit::check`.
+
+use crate::model::CommitTypeSet;
+use serde::{Deserialize, Serialize};
+
+/// The optional rules to check a commit against.
+///
+/// Every rule is disabled by default, so `LintOptions::default()` accepts any commit that parses.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::{lint::LintOptions, model::Commit};
+/// let options = LintOptions { require_breaking_description: true, ..LintOptions::default() };
+///
+/// assert!(Commit::parse("feat!: drop v1").unwrap().validate_with(&options).is_err());
+/// assert!(Commit::parse("feat!: drop v1").unwrap().validate_with(&LintOptions::default()).is_ok());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintOptions {
+    /// Restricts the types to the set, ex: to reject custom types. Any well-formed type is accepted when `None`.
+    pub types: Option<CommitTypeSet>,
+    /// Limits the description to this many characters. Unlimited when `None`.
+    pub max_description_length: Option<usize>,
+    /// Requires a `BREAKING CHANGE` or `BREAKING-CHANGE` footer describing the change when the header has the `!` marker.
+    pub require_breaking_description: bool,
+    /// Rejects descriptions ending with a full stop, ex: `feat: add lexer.`. `Commit::normalized` removes it.
+    pub forbid_trailing_period: bool,
+    /// Reports at most this many errors, followed by an `and M more` line counting the others. Unlimited when `None`.
+    pub max_errors: Option<usize>,
+    /// Whether a scope must, may or must not be given. Any scope is accepted by default.
+    pub scope_policy: ScopePolicy,
+}
+
+/// Whether commits are allowed to have a scope, ex: `parser` in `feat(parser): add lexer`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScopePolicy {
+    /// Every commit must have a scope.
+    Required,
+    /// Commits may have a scope. This is the specification's behaviour.
+    #[default]
+    Optional,
+    /// Commits must not have a scope, to keep headers simple.
+    Forbidden,
+}
+
+/// The optional fixes to apply when normalising a commit, on top of the ones `Commit::normalized` always applies.
+///
+/// Every fix is disabled by default, so `NormalizeOptions::default()` normalises like `Commit::normalized`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::{lint::NormalizeOptions, model::Commit};
+/// let options = NormalizeOptions { ascii_punctuation: true };
+///
+/// assert_eq!(Commit::parse("fix: don’t crash — again").unwrap().normalized_with(&options).to_string(), "fix: don't crash - again");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Replaces the curly quotes and dashes editors insert in the description with their ASCII equivalents, ex: `’` with
+    /// `'` and `—` with `-`.
+    pub ascii_punctuation: bool,
+}
+
+/// A subset of the commitlint rules, deserialized from a TOML or JSON configuration with the commitlint rule names.
+///
+/// Every rule is disabled by default, so `RuleConfig::default()` accepts any commit that parses. Disabled rules are left
+/// out when serializing.
+///
+/// The allowed types and scopes are compared case-sensitively like commitlint does, ex: `Parser` is not an allowed scope
+/// when `scope-enum` lists `parser`. The known types are parsed in lowercase whatever their case in the message, so only
+/// custom types can break `type-enum` by their case alone. Footer tokens are compared case-insensitively, like git does
+/// for the trailers `trailer-exists` looks for.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::{lint::RuleConfig, model::Commit};
+/// let config: RuleConfig = toml::from_str(r#"
+///     type-enum = ["feat", "fix"]
+///     header-max-length = 50
+///     trailer-exists = ["Refs"]
+/// "#).unwrap();
+///
+/// assert!(Commit::parse("feat: add lexer\n\nRefs: PROJ-1").unwrap().check(&config).is_ok());
+/// assert!(Commit::parse("docs: add lexer").unwrap().check(&config).is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RuleConfig {
+    /// The allowed types, like commitlint's `type-enum`. Any type when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_enum: Option<Vec<String>>,
+    /// The allowed scopes, like commitlint's `scope-enum`. Every scope of a list must be allowed. Any scope when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope_enum: Option<Vec<String>>,
+    /// The maximum length of the whole header, in characters, like commitlint's `header-max-length`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_max_length: Option<usize>,
+    /// The maximum length of each line of the body, in characters, like commitlint's `body-max-line-length`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_max_line_length: Option<usize>,
+    /// The tokens of the footers every commit must have, like commitlint's `trailer-exists`, ex: `Signed-off-by`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trailer_exists: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+
+    fn expected_config() -> RuleConfig {
+        RuleConfig {
+            type_enum: Some(vec!["feat".into(), "fix".into()]),
+            scope_enum: None,
+            header_max_length: Some(72),
+            body_max_line_length: Some(100),
+            trailer_exists: vec!["Signed-off-by".into()],
+        }
+    }
+
+    #[test]
+    fn test_deserializes_rule_config_from_toml() {
+        let toml = indoc! {r#"
+            type-enum = ["feat", "fix"]
+            header-max-length = 72
+            body-max-line-length = 100
+            trailer-exists = ["Signed-off-by"]
+        "#};
+
+        assert_eq!(expected_config(), toml::from_str(toml).expect("should have deserialized the config"));
+    }
+
+    #[test]
+    fn test_deserializes_rule_config_from_json() {
+        let json = r#"{"type-enum": ["feat", "fix"], "header-max-length": 72, "body-max-line-length": 100, "trailer-exists": ["Signed-off-by"]}"#;
+
+        assert_eq!(expected_config(), serde_json::from_str(json).expect("should have deserialized the config"));
+    }
+
+    #[test]
+    fn test_deserializes_empty_rule_config_to_default() {
+        assert_eq!(RuleConfig::default(), toml::from_str("").expect("should have deserialized the config"));
+    }
+
+    #[test]
+    fn test_serializes_enabled_rules_only() {
+        let config = RuleConfig {
+            header_max_length: Some(72),
+            ..RuleConfig::default()
+        };
+
+        assert_eq!("header-max-length = 72\n", toml::to_string(&config).expect("should have serialized the config"));
+    }
+
+    #[test]
+    fn test_rejects_unknown_rule() {
+        let err = toml::from_str::<RuleConfig>("subject-case = \"lower-case\"").expect_err("should have failed");
+        assert!(err.to_string().contains("unknown field `subject-case`"), "unexpected error: {err}");
+    }
+}
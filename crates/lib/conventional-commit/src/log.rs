@@ -0,0 +1,165 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Streaming parser for the output of `git log`.
+//!
+//! Commit messages are read one at a time from the NUL separated output of `git log --format=%B%x00`, so the history of
+//! huge repositories never has to be held in memory at once.
+
+use crate::{errors::ConventionalError, model::Commit};
+use std::io::{BufRead, Split};
+
+/// An iterator parsing each commit message of a `git log --format=%B%x00` output.
+///
+/// Non-conventional commits are returned as `ConventionalError::Validation` rather than skipped, so the caller decides
+/// what to do with them, and messages that are not valid UTF-8 as `ConventionalError::Parse`. Reading stops after the
+/// first I/O error, which is returned as a `ConventionalError::Io`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::log::CommitLog;
+/// let output = "feat: add lexer\n\0\nUpdate README\n\0\n";
+/// let results: Vec<_> = CommitLog::new(output.as_bytes()).collect();
+///
+/// assert_eq!(results.len(), 2);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub struct CommitLog<R: BufRead> {
+    records: Split<R>,
+    failed: bool,
+}
+
+impl<R: BufRead> CommitLog<R> {
+    /// Creates a parser reading the log from the reader.
+    ///
+    /// # Arguments
+    /// * `reader` - The output of `git log --format=%B%x00`.
+    ///
+    /// # Returns
+    /// A `CommitLog` yielding one result per commit, in the order they were logged.
+    pub fn new(reader: R) -> Self {
+        CommitLog {
+            records: reader.split(b'\0'),
+            failed: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for CommitLog<R> {
+    type Item = Result<Commit, ConventionalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        for record in self.records.by_ref() {
+            let record = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(ConventionalError::Io(e)));
+                }
+            };
+
+            let start = record.iter().position(|b| !matches!(b, b'\r' | b'\n')).unwrap_or(record.len());
+            if !record[start..].trim_ascii().is_empty() {
+                return Some(Commit::from_bytes(&record[start..]));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::model::CommitType;
+    use std::io::{self, BufReader, Read};
+
+    /// A reader repeating the same commit forever, to check that the log is read lazily.
+    struct EndlessLog;
+
+    impl Read for EndlessLog {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let record = b"feat: add lexer\n\0\n";
+            let len = record.len().min(buf.len());
+            buf[..len].copy_from_slice(&record[..len]);
+            Ok(len)
+        }
+    }
+
+    /// A reader failing on every read.
+    struct BrokenLog;
+
+    impl Read for BrokenLog {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    #[test]
+    fn test_parses_every_commit_in_order() {
+        let output = "feat(parser): add lexer\n\nSplits the header.\n\0\nfix: handle tabs\n\nRefs: PROJ-1\n\0\n";
+        let commits: Vec<Commit> = CommitLog::new(output.as_bytes()).map(|c| c.expect("should have parsed the commit")).collect();
+
+        assert_eq!(2, commits.len());
+        assert_eq!(&CommitType::Feat, commits[0].commit_type());
+        assert_eq!(Some("Splits the header."), commits[0].body());
+        assert_eq!(&CommitType::Fix, commits[1].commit_type());
+        assert_eq!("Refs: PROJ-1", commits[1].footers()[0].to_string());
+    }
+
+    #[test]
+    fn test_returns_non_conventional_commits_as_errors() {
+        let output = "Merge branch 'main'\n\0\nfeat: add lexer\n\0\n";
+        let results: Vec<_> = CommitLog::new(output.as_bytes()).collect();
+
+        assert_eq!(2, results.len());
+        assert!(matches!(&results[0], Err(ConventionalError::Validation(errs)) if errs.first().is_some_and(|e| e.rule() == "header-format")));
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn test_skips_empty_records() {
+        assert_eq!(0, CommitLog::new("\0\n\0\n\n".as_bytes()).count());
+    }
+
+    #[test]
+    fn test_reads_lazily() {
+        let commits: Vec<_> = CommitLog::new(BufReader::new(EndlessLog)).take(3).collect();
+        assert_eq!(3, commits.len());
+    }
+
+    #[test]
+    fn test_stops_after_read_error() {
+        let mut log = CommitLog::new(BufReader::new(BrokenLog));
+
+        let err = log.next().expect("should have returned the error").expect_err("should have failed");
+        assert!(matches!(err, ConventionalError::Io(e) if e.to_string() == "disk on fire"));
+        assert!(log.next().is_none());
+    }
+
+    #[test]
+    fn test_returns_malformed_messages_as_errors() {
+        let results: Vec<_> = CommitLog::new(&b"feat: add \xFF lexer\n\0\nfix: handle tabs\n\0\n"[..]).collect();
+
+        assert_eq!(2, results.len());
+        assert!(matches!(results[0], Err(ConventionalError::Parse(_))));
+        assert!(results[1].is_ok());
+    }
+}
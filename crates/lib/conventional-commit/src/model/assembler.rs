@@ -0,0 +1,269 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Commit message assembly for programmatic commit creation.
+//!
+//! A `Commit` renders whatever it holds, so some combinations of valid components produce a message that reads back
+//! differently, ex: a body whose last paragraph looks like footers. The `CommitAssembler` normalises or rejects those
+//! combinations so the message it produces always parses back to the same commit.
+
+use crate::{
+    errors::Errors,
+    model::{Build, Commit, CommitType, Description, Footer, Scope, ValidationError, ValidationErrors, footer},
+};
+use anyhow::anyhow;
+
+/// Assembles commit messages that are guaranteed to parse back to the commit they were assembled from.
+///
+/// In debug builds, every assembled message is parsed again and compared to the commit, to catch rendering bugs early.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Build, Commit, CommitAssembler, CommitType, Footer};
+/// let message = CommitAssembler::new(CommitType::Feat, "add lexer".parse().unwrap())
+///     .scope("parser".parse().unwrap())
+///     .body("Splits the header into tokens.\r\n")
+///     .footer(Footer::builder("Refs").value("PROJ-123").build().unwrap())
+///     .assemble()
+///     .unwrap();
+///
+/// assert_eq!(message, "feat(parser): add lexer\n\nSplits the header into tokens.\n\nRefs: PROJ-123");
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommitAssembler {
+    commit_type: CommitType,
+    scope: Option<Scope>,
+    breaking: bool,
+    description: Description,
+    body: Option<String>,
+    footers: Vec<Footer>,
+}
+
+impl CommitAssembler {
+    /// Creates an assembler for a commit with the given type and description.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type of the commit.
+    /// * `description` - The description of the commit.
+    ///
+    /// # Returns
+    /// A `CommitAssembler` instance for further configuration.
+    #[must_use]
+    pub fn new(commit_type: CommitType, description: Description) -> Self {
+        CommitAssembler {
+            commit_type,
+            scope: None,
+            breaking: false,
+            description,
+            body: None,
+            footers: Vec::new(),
+        }
+    }
+
+    /// Sets the scope of the commit.
+    ///
+    /// # Returns
+    /// The assembler, for further configuration.
+    pub fn scope(&mut self, scope: Scope) -> &mut Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sets whether the header carries the `!` breaking change marker.
+    ///
+    /// # Returns
+    /// The assembler, for further configuration.
+    pub fn breaking(&mut self, breaking: bool) -> &mut Self {
+        self.breaking = breaking;
+        self
+    }
+
+    /// Sets the body of the commit. Line endings are normalised, and surrounding blank lines are dropped.
+    ///
+    /// # Returns
+    /// The assembler, for further configuration.
+    pub fn body(&mut self, body: impl Into<String>) -> &mut Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Appends a footer to the commit. Line endings in its value are normalised, and trailing white-space is dropped.
+    ///
+    /// # Returns
+    /// The assembler, for further configuration.
+    pub fn footer(&mut self, footer: Footer) -> &mut Self {
+        self.footers.push(footer);
+        self
+    }
+
+    /// Assembles the commit message.
+    ///
+    /// # Returns
+    /// * `Ok(String)` with a message that parses back to the assembled commit.
+    /// * `Err(ValidationErrors)` if the components cannot be rendered faithfully.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationErrors` if a custom type is not a valid type token, the body ends with a paragraph that would
+    /// be read as footers while there are no footers, or a footer value has a blank line or a line that would be read
+    /// as another footer.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the assembled message does not parse back to the same commit, which is a bug.
+    pub fn assemble(&self) -> Result<String, ValidationErrors> {
+        let mut errs = Errors::new();
+
+        if let Err(e) = self.commit_type.as_str().parse::<CommitType>() {
+            errs.append(e);
+        }
+
+        let body = self.body.as_deref().map(normalise_body).filter(|b| !b.is_empty());
+
+        if self.footers.is_empty()
+            && let Some(body) = &body
+            && body.rsplit("\n\n").next().is_some_and(footer::is_footer_paragraph)
+        {
+            errs.append(ValidationError::InvalidFieldValue(
+                "body".into(),
+                anyhow!("must not end with a paragraph formatted as footers, add them as footers instead"),
+            ));
+        }
+
+        let mut builder = Commit::builder(self.commit_type.clone(), self.description.clone());
+        builder.breaking(self.breaking);
+
+        if let Some(scope) = &self.scope {
+            builder.scope(scope.clone());
+        }
+
+        if let Some(body) = body {
+            builder.body(body);
+        }
+
+        for footer in &self.footers {
+            let value = footer.value().trim_end().lines().collect::<Vec<_>>().join("\n");
+            if value.lines().enumerate().any(|(i, l)| l.trim().is_empty() || (i > 0 && footer::is_footer_line(l))) {
+                errs.append(ValidationError::InvalidFieldValue(
+                    "footer".into(),
+                    anyhow!("'{}' must continue its value on lines that are neither blank nor footers", footer.token()),
+                ));
+                continue;
+            }
+
+            match Footer::builder(footer.token()).separator(footer.separator()).value(value).build() {
+                Ok(footer) => {
+                    builder.footer(footer);
+                }
+                Err(e) => errs.merge(e),
+            }
+        }
+
+        if !errs.is_empty() {
+            return Err(errs);
+        }
+
+        let commit = builder.build()?;
+        let message = commit.to_string();
+
+        debug_assert_eq!(
+            Commit::parse(&message).ok().as_ref(),
+            Some(&commit),
+            "the assembled message should parse back to the same commit:\n{message}"
+        );
+
+        Ok(message)
+    }
+}
+
+/// Normalises line endings and drops the blank lines around the body.
+fn normalise_body(body: &str) -> String {
+    let lines: Vec<&str> = body.lines().skip_while(|l| l.trim().is_empty()).collect();
+    lines.join("\n").trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{model::BreakingChangeToken, multi_error};
+    use rstest::rstest;
+
+    fn assembler() -> CommitAssembler {
+        CommitAssembler::new(CommitType::Feat, "add lexer".parse().expect("should have parsed the description"))
+    }
+
+    fn footer(token: &str, value: &str) -> Footer {
+        Footer::builder(token).value(value).build().expect("should have built the footer")
+    }
+
+    #[rstest]
+    #[case::header_only(assembler())]
+    #[case::scope(assembler().scope("parser".parse().expect("should have parsed the scope")).clone())]
+    #[case::breaking(assembler().breaking(true).clone())]
+    #[case::custom_type(CommitAssembler::new(CommitType::Custom("wip".into()), "try things".parse().expect("should have parsed the description")))]
+    #[case::body(assembler().body("Splits the header into tokens.").clone())]
+    #[case::body_with_surrounding_blank_lines(assembler().body("\n\n  indented first line\n\nsecond paragraph \n\n").clone())]
+    #[case::crlf_body(assembler().body("first line\r\nsecond line\r\n").clone())]
+    #[case::footers(assembler().footer(footer("Refs", "PROJ-1")).footer(footer("Reviewed-by", "Alice")).clone())]
+    #[case::footer_with_trailing_space(assembler().footer(footer("Refs", "PROJ-1  ")).clone())]
+    #[case::issue_reference_footers(assembler().footer(footer("Closes", "#42")).footer(footer("Refs", "#43")).clone())]
+    #[case::breaking_footer(assembler().footer(Footer::breaking_change("drops v1").token(BreakingChangeToken::Hyphen).build().expect("should have built the footer")).clone())]
+    #[case::multi_line_footer(assembler().footer(footer("BREAKING CHANGE", "drops v1\r\n  and v2")).footer(footer("Refs", "PROJ-1")).clone())]
+    #[case::footer_like_body_with_footers(assembler().body("Refs: PROJ-1").footer(footer("Refs", "PROJ-2")).clone())]
+    #[case::everything(
+        assembler()
+            .scope("parser".parse().expect("should have parsed the scope"))
+            .breaking(true)
+            .body("Splits the header.\n\n    let tokens = lex(header);")
+            .footer(Footer::breaking_change("the lexer replaces the splitter").build().expect("should have built the footer"))
+            .clone()
+    )]
+    fn test_assembled_message_parses_back_to_equal_commit(#[case] assembler: CommitAssembler) {
+        let message = assembler.assemble().expect("should have assembled the message");
+        let commit = Commit::parse(&message).expect("should have parsed the assembled message");
+
+        assert_eq!(commit.commit_type(), &assembler.commit_type);
+        assert_eq!(commit.scope(), assembler.scope.as_ref());
+        assert_eq!(commit.has_breaking_marker(), assembler.breaking);
+        assert_eq!(commit.description(), &assembler.description);
+        assert_eq!(commit.footers().len(), assembler.footers.len());
+        assert_eq!(message, commit.to_string());
+    }
+
+    #[rstest]
+    #[case::invalid_custom_type(
+        CommitAssembler::new(CommitType::Custom("fe at".into()), "add".parse().expect("should have parsed the description")),
+        multi_error!(ValidationError::InvalidFieldValue("type".into(), anyhow!("'fe at' must only contain letters, digits, '-' or '_'")))
+    )]
+    #[case::footer_like_body(
+        assembler().body("Splits the header.\n\nRefs: PROJ-1").clone(),
+        multi_error!(ValidationError::InvalidFieldValue("body".into(), anyhow!("must not end with a paragraph formatted as footers, add them as footers instead")))
+    )]
+    #[case::footer_value_with_footer_line(
+        assembler().footer(footer("Refs", "PROJ-1\nCloses #2")).clone(),
+        multi_error!(ValidationError::InvalidFieldValue("footer".into(), anyhow!("'Refs' must continue its value on lines that are neither blank nor footers")))
+    )]
+    #[case::footer_value_with_blank_line(
+        assembler().footer(footer("BREAKING CHANGE", "drops v1\n\nand v2")).clone(),
+        multi_error!(ValidationError::InvalidFieldValue(
+            "footer".into(),
+            anyhow!("'BREAKING CHANGE' must continue its value on lines that are neither blank nor footers")
+        ))
+    )]
+    fn test_rejects_components_that_would_not_parse_back(#[case] assembler: CommitAssembler, #[case] expect: ValidationErrors) {
+        let errs = assembler.assemble().expect_err("should have failed");
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Body representation for conventional commits.
+//!
+//! The body is the free-form text between the header and the footers, made of paragraphs separated by blank lines.
+
+use crate::model::{ValidationError, footer};
+use anyhow::anyhow;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The body of a conventional commit, as paragraphs in the order they appear.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Body(Vec<String>);
+
+impl Body {
+    /// Parses and validates a body.
+    ///
+    /// Paragraphs are separated by one or more blank lines. Blank lines around the body are ignored, and trailing
+    /// white-space is removed from each paragraph.
+    ///
+    /// # Arguments
+    /// * `text` - The body, without the header or the footers.
+    ///
+    /// # Returns
+    /// * `Ok(Body)` if the body is valid.
+    /// * `Err(ValidationError)` if the body is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the body is blank, or if its first paragraph is only made of footers, which
+    /// usually means the footers were misplaced.
+    pub fn parse(text: &str) -> Result<Self, ValidationError> {
+        let lines: Vec<&str> = text.lines().collect();
+        let paragraphs: Vec<String> = lines
+            .split(|l| l.trim().is_empty())
+            .filter(|p| !p.is_empty())
+            .map(|p| p.join("\n").trim_end().to_string())
+            .collect();
+
+        let Some(first) = paragraphs.first() else {
+            return Err(ValidationError::MissingRequiredField("body".into()));
+        };
+
+        if footer::is_footer_paragraph(first) {
+            return Err(ValidationError::InvalidFieldValue(
+                "body".into(),
+                anyhow!("'{first}' looks like footers, they must be in the last paragraph of the message"),
+            ));
+        }
+
+        Ok(Body(paragraphs))
+    }
+
+    /// Returns the paragraphs of the body.
+    ///
+    /// # Returns
+    /// The paragraphs in order, each without surrounding blank lines.
+    #[must_use]
+    pub fn paragraphs(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl FromStr for Body {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Body::parse(s)
+    }
+}
+
+/// Writes the paragraphs separated by a single blank line, without a trailing line break.
+impl Display for Body {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join("\n\n"))
+    }
+}
+
+/// Splits the lines following the header's blank line into the body and the footers.
+///
+/// The last paragraph holds the footers when it starts with a footer, the lines after it being either other footers or
+/// the continuation of their values. Otherwise everything is body.
+pub(crate) fn split_footers<'a, 'b>(lines: &'b [&'a str]) -> (&'b [&'a str], &'b [&'a str]) {
+    let last_paragraph = lines.iter().rposition(|l| l.trim().is_empty()).map_or(0, |i| i + 1);
+
+    if lines.get(last_paragraph).is_some_and(|l| footer::is_footer_line(l)) {
+        lines.split_at(last_paragraph)
+    } else {
+        (lines, &[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::single_paragraph("Splits the header.", vec!["Splits the header."])]
+    #[case::multi_line_paragraph("Splits the header\ninto tokens.", vec!["Splits the header\ninto tokens."])]
+    #[case::paragraphs("First.\n\nSecond.", vec!["First.", "Second."])]
+    #[case::several_blank_lines("First.\n\n\n  \nSecond.", vec!["First.", "Second."])]
+    #[case::surrounding_blank_lines("\n\nFirst.\n\n", vec!["First."])]
+    #[case::trailing_white_space("First.  ", vec!["First."])]
+    #[case::indented("  let x = 1;\n\nSecond.", vec!["  let x = 1;", "Second."])]
+    #[case::footers_later("First.\n\nRefs: PROJ-1", vec!["First.", "Refs: PROJ-1"])]
+    #[case::footer_like_sentence("Note that the parser is stricter: it rejects tabs.\n\nSecond.", vec!["Note that the parser is stricter: it rejects tabs.", "Second."])]
+    fn test_parses_body(#[case] input: &str, #[case] expect: Vec<&str>) {
+        let body = Body::parse(input).expect("should have parsed the body");
+        assert_eq!(expect, body.paragraphs());
+    }
+
+    #[rstest]
+    #[case::single_paragraph("Splits the header.", "Splits the header.")]
+    #[case::collapses_blank_lines("First.\n\n\nSecond.\n", "First.\n\nSecond.")]
+    fn test_displays_body(#[case] input: &str, #[case] expect: &str) {
+        assert_eq!(expect, Body::parse(input).expect("should have parsed the body").to_string());
+    }
+
+    #[rstest]
+    #[case::empty("", ValidationError::MissingRequiredField("body".into()))]
+    #[case::blank("\n  \n", ValidationError::MissingRequiredField("body".into()))]
+    #[case::starts_with_footers(
+        "Refs: PROJ-1\n\nSplits the header.",
+        ValidationError::InvalidFieldValue("body".into(), anyhow!("'Refs: PROJ-1' looks like footers, they must be in the last paragraph of the message"))
+    )]
+    fn test_rejects_invalid_body(#[case] input: &str, #[case] expect: ValidationError) {
+        assert_eq!(expect, Body::parse(input).expect_err("should have failed"));
+    }
+
+    #[rstest]
+    #[case::empty(vec![], vec![], vec![])]
+    #[case::body_only(vec!["First.", "", "Second."], vec!["First.", "", "Second."], vec![])]
+    #[case::footers_only(vec!["Refs: PROJ-1"], vec![], vec!["Refs: PROJ-1"])]
+    #[case::body_and_footers(vec!["First.", "", "Refs: PROJ-1", "Closes #42"], vec!["First.", ""], vec!["Refs: PROJ-1", "Closes #42"])]
+    #[case::continued_value(vec!["First.", "", "BREAKING CHANGE: drops v1", "and v2"], vec!["First.", ""], vec!["BREAKING CHANGE: drops v1", "and v2"])]
+    #[case::last_paragraph_not_starting_with_footer(vec!["First.", "", "not a footer", "Refs: PROJ-1"], vec!["First.", "", "not a footer", "Refs: PROJ-1"], vec![])]
+    fn test_splits_body_and_footers(#[case] lines: Vec<&str>, #[case] body: Vec<&str>, #[case] footers: Vec<&str>) {
+        assert_eq!((body.as_slice(), footers.as_slice()), split_footers(&lines));
+    }
+}
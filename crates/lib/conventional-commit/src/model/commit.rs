@@ -0,0 +1,2610 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Commit representation for conventional commits.
+//!
+//! A conventional commit message is made of a header, an optional body and optional footers, each separated by a blank line:
+//!
+//! ```text
+//! <type>[(<scope>)][!]: <description>
+//!
+//! [body]
+//!
+//! [footer(s)]
+//! ```
+
+use crate::{
+    errors::{ConventionalError, Errors},
+    lint::{LintOptions, NormalizeOptions, RuleConfig, ScopePolicy},
+    model::{
+        Build, CANONICAL_RELATIONSHIPS, ClosingKeyword, CommitDiff, CommitKind, CommitType, CommitTypeSet, Description, Footer, Scope, Validate, ValidationError, ValidationErrors,
+        VersionBump, body, footer,
+    },
+    scissors,
+};
+use anyhow::anyhow;
+use derive_builder::Builder;
+use regex::Regex;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The character starting the comment lines of a message, unless git's `core.commentChar` says otherwise.
+const DEFAULT_COMMENT_CHAR: char = '#';
+
+/// The expected shape of the header, used in error messages.
+const HEADER_FORMAT: &str = "<type>[(<scope>)][!]: <description>";
+
+/// The footer token referencing the issues a commit relates to, alongside the tenses of the closing keywords.
+const REFS_TOKEN: &str = "Refs";
+
+/// The footer tokens linking a commit to the pull request it was merged from, ex: in squash merges.
+const PULL_REQUEST_TOKENS: [&str; 2] = ["PR-URL", "Pull-request"];
+
+/// The scope, or leading description word, of release commits, ex: `chore(release): 1.2.0` or `chore: release 1.2.0`.
+const RELEASE_KEYWORD: &str = "release";
+
+/// The footer token referencing the commit undone by a revert commit.
+const REVERT_TOKEN: &str = REFS_TOKEN;
+
+/// The accepted lengths of a commit SHA, from git's shortest abbreviation to a full SHA-1.
+const SHA_LENGTHS: std::ops::RangeInclusive<usize> = 7..=40;
+
+/// Represents a conventional commit message.
+///
+/// A `Commit` consists of a type, an optional scope, an optional breaking change marker, a description, an optional
+/// body and any number of footers. It may also keep a prefix written before the type, ex: `[PROJ-1]`, when parsed with
+/// an allowed prefix pattern.
+#[derive(Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(build_fn(skip))]
+pub struct Commit {
+    /// The prefix written before the type, ex: a ticket or an emoji, if any.
+    #[builder(setter(into, strip_option), default)]
+    prefix: Option<String>,
+    /// The type of the commit, ex: `feat`.
+    #[builder(setter(custom))]
+    kind: CommitType,
+    /// The scope of the commit, if any.
+    #[builder(setter(into, strip_option), default)]
+    scope: Option<Scope>,
+    /// Whether the header carries the `!` breaking change marker.
+    #[builder(default)]
+    breaking: bool,
+    /// The short description following the type and scope.
+    #[builder(setter(custom))]
+    description: Description,
+    /// The free-form body of the commit, if any.
+    #[builder(setter(into, strip_option), default)]
+    body: Option<String>,
+    /// The footers of the commit, in the order they appear.
+    #[builder(setter(custom), default)]
+    footers: Vec<Footer>,
+}
+
+/// Options controlling which messages `Commit::parse_with_options` accepts.
+///
+/// The default options accept the same messages as `Commit::parse`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Commit, ParseOptions};
+/// # use regex::Regex;
+/// let options = ParseOptions { prefix: Some(Regex::new(r"\[[A-Z]+-\d+\]").unwrap()), ..ParseOptions::default() };
+/// let commit = Commit::parse_with_options(&options, "[PROJ-1] feat: add lexer").unwrap();
+///
+/// assert_eq!(commit.prefix(), Some("[PROJ-1]"));
+/// assert_eq!(commit.to_string(), "[PROJ-1] feat: add lexer");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// The allowed commit types, or `None` to accept any well-formed type.
+    pub types: Option<CommitTypeSet>,
+    /// The pattern of a prefix allowed before the type, ex: a ticket like `[PROJ-1]` or a gitmoji. It must match at the
+    /// very start of the header, and the white-space following it is ignored.
+    pub prefix: Option<Regex>,
+    /// The maximum length of the description, in characters, or `None` for `Description::DEFAULT_MAX_LENGTH`.
+    pub max_description_length: Option<usize>,
+}
+
+/// The components of a header, as parsed from its first line.
+struct Header {
+    commit_type: CommitType,
+    scope: Option<Scope>,
+    breaking: bool,
+    description: Description,
+}
+
+impl Commit {
+    /// Creates a new `CommitBuilder` for constructing a `Commit`.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type of the commit.
+    /// * `description` - The description of the commit.
+    ///
+    /// # Returns
+    /// A `CommitBuilder` instance for further configuration.
+    #[must_use]
+    pub fn builder(commit_type: CommitType, description: Description) -> CommitBuilder {
+        CommitBuilder {
+            kind: Some(commit_type),
+            description: Some(description),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a commit reverting another one.
+    ///
+    /// The revert commit has the `revert` type and the original header as its description, ex: `revert: feat(parser): add
+    /// lexer`. Its body is `This reverts commit <sha>.`, as written by `git revert`, and a `Refs: <sha>` footer
+    /// references the original commit.
+    ///
+    /// The description is not held to the `Description::DEFAULT_MAX_LENGTH` limit: it is the original header, which may
+    /// already be as long as the limit allows, and `git revert` doesn't shorten it either. Parse the message back with
+    /// `Commit::parse_structure`, or `Commit::parse_with_options` and a `max_description_length` to taste, rather than
+    /// `Commit::parse`, which may reject it.
+    ///
+    /// # Arguments
+    /// * `original` - The commit being reverted. Its prefix, body and footers are not carried over.
+    /// * `sha` - The SHA of the commit being reverted, full or abbreviated.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` with the revert commit.
+    /// * `Err(ValidationErrors)` if the SHA is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidFieldValue` for the `sha` if it is not 7 to 40 hexadecimal characters.
+    pub fn revert(original: &Commit, sha: &str) -> Result<Self, ValidationErrors> {
+        if !is_sha(sha) {
+            return Err(Errors::from([ValidationError::InvalidFieldValue(
+                "sha".into(),
+                anyhow!("'{sha}' must be {} to {} hexadecimal characters", SHA_LENGTHS.start(), SHA_LENGTHS.end()),
+            )]));
+        }
+
+        let header = Commit {
+            prefix: None,
+            body: None,
+            footers: Vec::new(),
+            ..original.clone()
+        };
+        let description = Description::parse_with_limit(&header.to_string(), usize::MAX).map_err(|e| Errors::from([e]))?;
+
+        Commit::builder(CommitType::Revert, description)
+            .body(format!("This reverts commit {sha}."))
+            .footer(Footer::builder(REVERT_TOKEN).value(sha).build()?)
+            .build()
+    }
+
+    /// Parses a conventional commit message.
+    ///
+    /// Trailing white-space is ignored. The last paragraph of the message is read as footers when it starts with a
+    /// `token: value` footer, otherwise it is part of the body. A footer value continues on the following lines until the
+    /// next `token: ` or `token #`.
+    ///
+    /// # Arguments
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if the message is a valid conventional commit.
+    /// * `Err(ConventionalError::Validation)` listing every problem found in the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConventionalError::Validation` if the header is malformed, any of its components is invalid, or the body is not
+    /// separated from the header by a blank line. Any well-formed type is accepted, use `Commit::parse_with` to restrict
+    /// them.
+    pub fn parse(message: &str) -> Result<Self, ConventionalError> {
+        Ok(parse_message(message, None, None, Description::DEFAULT_MAX_LENGTH)?)
+    }
+
+    /// Checks whether a message starts with a conventional commit header, without parsing it into a `Commit`.
+    ///
+    /// Only the header is read, checked against the same grammar as [`Commit::parse`] without allocating, which makes it
+    /// a cheap gate for large histories, ex: in a pre-receive hook. A `true` only guarantees the shape of the header:
+    /// the body and footers are not looked at, and no lint rule or type restriction is checked.
+    ///
+    /// # Arguments
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// `true` if the first line is a valid `<type>[(<scope>)][!]: <description>` header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// assert!(Commit::is_conventional("feat(parser)!: drop v1\n\nBREAKING CHANGE: v1 headers are rejected"));
+    /// assert!(!Commit::is_conventional("Add lexer"));
+    /// ```
+    #[must_use]
+    pub fn is_conventional(message: &str) -> bool {
+        let message = message.trim_end();
+        let header = message.split_once('\n').map_or(message, |(header, _)| header);
+        is_header(header.trim_end_matches('\r'))
+    }
+
+    /// Parses a commit message, telling the `fixup!`, `squash!` and `amend!` commits of `git rebase --autosquash` apart
+    /// from conventional commits.
+    ///
+    /// An autosquash commit is recognised by its prefix, written by `git commit --fixup` or `--squash`, and keeps the
+    /// subject it targets as written after the prefix, which may itself be an autosquash or a non-conventional subject.
+    /// Any other message is parsed like with [`Commit::parse`].
+    ///
+    /// # Arguments
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// * `Ok(CommitKind::Fixup)`, `Ok(CommitKind::Squash)` or `Ok(CommitKind::Amend)` with the targeted subject.
+    /// * `Ok(CommitKind::Conventional)` if the message is a valid conventional commit.
+    /// * `Err(ConventionalError::Validation)` listing every problem found in the message otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Commit::parse` for messages that are not autosquash commits.
+    pub fn parse_kind(message: &str) -> Result<CommitKind, ConventionalError> {
+        let subject = message.lines().next().unwrap_or_default().trim_end();
+
+        match CommitKind::autosquash(subject) {
+            Some(kind) => Ok(kind),
+            None => Commit::parse(message).map(CommitKind::Conventional),
+        }
+    }
+
+    /// Parses a conventional commit message from raw bytes, ex: read from a file or the output of git.
+    ///
+    /// # Arguments
+    /// * `message` - The full commit message, encoded in UTF-8.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if the message is a valid conventional commit.
+    /// * `Err(ConventionalError)` if the message is not valid UTF-8 or not a valid conventional commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConventionalError::Parse` if the message is not valid UTF-8, or a `ConventionalError::Validation`
+    /// with the same errors as `Commit::parse` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::{errors::ConventionalError, model::Commit};
+    /// assert!(Commit::from_bytes(b"feat: add lexer").is_ok());
+    /// assert!(matches!(Commit::from_bytes(b"feat: add \xFF"), Err(ConventionalError::Parse(_))));
+    /// ```
+    pub fn from_bytes(message: &[u8]) -> Result<Self, ConventionalError> {
+        let message = std::str::from_utf8(message).map_err(|e| ConventionalError::Parse(format!("it is not valid UTF-8, {e}")))?;
+        Commit::parse(message)
+    }
+
+    /// Parses a commit message as edited in the editor, once cleaned up the way git does before storing it.
+    ///
+    /// See [`Commit::clean_git_message`] for the clean up, so the commit is the one git will actually store.
+    ///
+    /// # Arguments
+    /// * `message` - The content of the commit message file, ex: `.git/COMMIT_EDITMSG`.
+    /// * `comment_char` - The `core.commentChar` git setting, `#` when `None`.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if the cleaned up message is a valid conventional commit.
+    /// * `Err(ConventionalError::Validation)` listing every problem found in the cleaned up message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Commit::parse` does for the cleaned up message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let message = "feat: add lexer\n; Please enter the commit message\n\nSplits the header.\n\n\n";
+    ///
+    /// assert_eq!(Some("Splits the header."), Commit::parse_git_message(message, Some(';')).unwrap().body());
+    /// ```
+    pub fn parse_git_message(message: &str, comment_char: Option<char>) -> Result<Self, ConventionalError> {
+        Commit::parse(&Commit::clean_git_message(message, comment_char))
+    }
+
+    /// Cleans up a commit message the way git does with its default `strip` mode.
+    ///
+    /// Everything from the scissors line of `git commit --verbose` on is dropped, as are the lines starting with the
+    /// comment character. Trailing white-space is removed from every line, consecutive blank lines are collapsed into one,
+    /// and leading and trailing blank lines are removed.
+    ///
+    /// # Arguments
+    /// * `message` - The content of the commit message file.
+    /// * `comment_char` - The `core.commentChar` git setting, `#` when `None`.
+    ///
+    /// # Returns
+    /// The message git would store, ending with a line break, or empty if nothing is left.
+    #[must_use]
+    pub fn clean_git_message(message: &str, comment_char: Option<char>) -> String {
+        let comment_char = comment_char.unwrap_or(DEFAULT_COMMENT_CHAR);
+        let cut_line = &scissors::SCISSORS[DEFAULT_COMMENT_CHAR.len_utf8()..];
+
+        let mut cleaned = String::with_capacity(message.len());
+        let mut blank = false;
+        for line in message.lines() {
+            if line.strip_prefix(comment_char).is_some_and(|rest| rest.trim_end() == cut_line) {
+                break;
+            }
+            if line.starts_with(comment_char) {
+                continue;
+            }
+
+            let line = line.trim_end();
+            if line.is_empty() {
+                blank = !cleaned.is_empty();
+                continue;
+            }
+            if blank {
+                cleaned.push('\n');
+                blank = false;
+            }
+            cleaned.push_str(line);
+            cleaned.push('\n');
+        }
+
+        cleaned
+    }
+
+    /// Parses the structure of a conventional commit message, without applying any rule on top of the specification.
+    ///
+    /// Unlike `Commit::parse`, the description length is not limited and any well-formed type is accepted, so the commit
+    /// can be parsed once then checked against several sets of rules with `Commit::validate_with`, ex: one per team.
+    ///
+    /// # Arguments
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if the message is structurally a conventional commit.
+    /// * `Err(ConventionalError::Validation)` listing every structural problem found in the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConventionalError::Validation` if the header is malformed, any of its components is invalid, or the body is not
+    /// separated from the header by a blank line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::{lint::{LintOptions, ScopePolicy}, model::Commit};
+    /// let commit = Commit::parse_structure("feat: add lexer").unwrap();
+    ///
+    /// assert!(commit.validate_with(&LintOptions::default()).is_ok());
+    /// assert!(commit.validate_with(&LintOptions { scope_policy: ScopePolicy::Required, ..LintOptions::default() }).is_err());
+    /// ```
+    pub fn parse_structure(message: &str) -> Result<Self, ConventionalError> {
+        Ok(parse_message(message, None, None, usize::MAX)?)
+    }
+
+    /// Parses a conventional commit message, only accepting the given types.
+    ///
+    /// Unlike `Commit::parse`, which accepts any well-formed type, this rejects types missing from the set.
+    ///
+    /// # Arguments
+    /// * `types` - The allowed commit types.
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if the message is a valid conventional commit with an allowed type.
+    /// * `Err(ConventionalError::Validation)` listing every problem found in the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Commit::parse`, plus an `InvalidFieldValue` for the `type` listing the allowed values
+    /// if the type is not in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Commit, CommitType, CommitTypeSet};
+    /// let types = CommitTypeSet::empty().with(CommitType::Feat).with(CommitType::Fix);
+    ///
+    /// assert!(Commit::parse_with(&types, "fix: handle tabs").is_ok());
+    /// assert!(Commit::parse_with(&types, "wip: try things").is_err());
+    /// ```
+    pub fn parse_with(types: &CommitTypeSet, message: &str) -> Result<Self, ConventionalError> {
+        Ok(parse_message(message, Some(types), None, Description::DEFAULT_MAX_LENGTH)?)
+    }
+
+    /// Parses a conventional commit message according to the options.
+    ///
+    /// # Arguments
+    /// * `options` - The allowed types, subject prefix and description length.
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if the message is a valid conventional commit under the options.
+    /// * `Err(ConventionalError::Validation)` listing every problem found in the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Commit::parse_with` when types are restricted, or `Commit::parse` otherwise. A prefix
+    /// that doesn't match the allowed pattern is read as part of the type, which is then invalid.
+    pub fn parse_with_options(options: &ParseOptions, message: &str) -> Result<Self, ConventionalError> {
+        let limit = options.max_description_length.unwrap_or(Description::DEFAULT_MAX_LENGTH);
+        Ok(parse_message(message, options.types.as_ref(), options.prefix.as_ref(), limit)?)
+    }
+
+    /// Parses a commit message leniently, extracting whatever structure it has even when it is not a valid conventional
+    /// commit, ex: when migrating the history of a repository.
+    ///
+    /// Problems are reported as warnings alongside a best-effort `Commit` instead of failing:
+    ///
+    /// - A type outside the `CommitTypeSet::default()` set is kept as a `CommitType::Custom`.
+    /// - A description over `Description::DEFAULT_MAX_LENGTH` characters is kept whole.
+    /// - A malformed header, ex: without a colon, becomes the description of a `chore` commit.
+    /// - A body not separated from the header by a blank line is read as if it were.
+    /// - Footers that can't be parsed are moved to the end of the body, the others are kept as footers.
+    ///
+    /// # Arguments
+    /// * `message` - The full commit message.
+    ///
+    /// # Returns
+    /// * `Ok((Commit, ValidationErrors))` with the best-effort commit and the warnings, empty for a valid message.
+    /// * `Err(ConventionalError::Validation)` if the header is blank, so there is nothing to describe the commit with.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConventionalError::Validation` with a `MissingRequiredField` for the `header` if the first line of the message is blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Commit, CommitType};
+    /// let (commit, warnings) = Commit::parse_lenient("Update the README").unwrap();
+    ///
+    /// assert_eq!(commit.to_string(), "chore: Update the README");
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn parse_lenient(message: &str) -> Result<(Self, ValidationErrors), ConventionalError> {
+        let message = message.trim_end();
+        let (header, rest) = message.split_once('\n').unwrap_or((message, ""));
+        let header = header.trim_end_matches('\r').trim();
+
+        if header.is_empty() {
+            return Err(Errors::from([ValidationError::MissingRequiredField("header".into())]).into());
+        }
+
+        let mut warnings = Errors::new();
+
+        let header = match parse_header(header, None, usize::MAX) {
+            Ok(parsed) => {
+                if let Err(e) = CommitTypeSet::default().validate(&parsed.commit_type) {
+                    warnings.append(e);
+                }
+                if let Err(e) = Description::parse(parsed.description.as_str()) {
+                    warnings.append(e);
+                }
+                parsed
+            }
+            Err(errs) => {
+                warnings.merge(errs);
+                Header {
+                    commit_type: CommitType::Chore,
+                    scope: None,
+                    breaking: false,
+                    description: Description::parse_with_limit(header, usize::MAX).map_err(|e| Errors::from([e]))?,
+                }
+            }
+        };
+
+        let lines: Vec<&str> = rest.lines().collect();
+        let (separated, mut body, footer_lines) = split_body(rest, &lines);
+        if !separated {
+            warnings.append(unseparated_body());
+        }
+
+        let mut footers = Vec::new();
+        let mut unparsable = Vec::new();
+        for (lines, footer) in footer::parse_paragraph(footer_lines) {
+            match footer {
+                Ok(footer) => footers.push(footer),
+                Err(errs) => {
+                    warnings.merge(errs);
+                    unparsable.extend_from_slice(lines);
+                }
+            }
+        }
+        if !unparsable.is_empty() {
+            let unparsable = unparsable.join("\n");
+            body = if body.is_empty() { unparsable } else { format!("{body}\n\n{unparsable}") };
+        }
+
+        let commit = Commit {
+            prefix: None,
+            kind: header.commit_type,
+            scope: header.scope,
+            breaking: header.breaking,
+            description: header.description,
+            body: Some(body).filter(|b| !b.is_empty()),
+            footers,
+        };
+        Ok((commit, warnings))
+    }
+
+    /// Returns the prefix written before the type, if any.
+    ///
+    /// # Returns
+    /// * `Some(&str)` with the prefix, ex: `[PROJ-1]`.
+    /// * `None` if the header starts with the type.
+    #[must_use]
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Returns the type of the commit.
+    ///
+    /// # Returns
+    /// A reference to the `CommitType`.
+    #[must_use]
+    pub fn commit_type(&self) -> &CommitType {
+        &self.kind
+    }
+
+    /// Returns the scope of the commit, if any.
+    ///
+    /// # Returns
+    /// An optional reference to the `Scope`.
+    #[must_use]
+    pub fn scope(&self) -> Option<&Scope> {
+        self.scope.as_ref()
+    }
+
+    /// Returns the scopes of the commit, ex: `parser` and `lexer` for `feat(parser,lexer): add tokens`.
+    ///
+    /// # Returns
+    /// The scopes in the order they were written, empty if the commit has no scope.
+    #[must_use]
+    pub fn scopes(&self) -> &[String] {
+        self.scope.as_ref().map_or(&[], Scope::scopes)
+    }
+
+    /// Returns the description of the commit.
+    ///
+    /// # Returns
+    /// A reference to the `Description`.
+    #[must_use]
+    pub fn description(&self) -> &Description {
+        &self.description
+    }
+
+    /// Returns the subject of the commit, the single line header without the body and footers.
+    ///
+    /// # Returns
+    /// The header as it appears on the first line of the message, ex: `feat(parser)!: add lexer`, including the prefix
+    /// if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let commit = Commit::parse("feat(parser)!: add lexer\n\nSplits the header.\n\nRefs: PROJ-1").unwrap();
+    ///
+    /// assert_eq!(commit.subject(), "feat(parser)!: add lexer");
+    /// ```
+    #[must_use]
+    pub fn subject(&self) -> String {
+        let prefix = self.prefix.as_ref().map(|prefix| format!("{prefix} ")).unwrap_or_default();
+        let scope = self.scope.as_ref().map(|scope| format!("({scope})")).unwrap_or_default();
+        let marker = if self.breaking { "!" } else { "" };
+
+        format!("{prefix}{}{scope}{marker}: {}", self.kind, self.description)
+    }
+
+    /// Returns the header of the commit, an alias of `Commit::subject`.
+    ///
+    /// # Returns
+    /// The header as it appears on the first line of the message.
+    #[must_use]
+    pub fn header(&self) -> String {
+        self.subject()
+    }
+
+    /// Returns the body of the commit, if any.
+    ///
+    /// # Returns
+    /// An optional reference to the body text.
+    #[must_use]
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Returns the footers of the commit.
+    ///
+    /// # Returns
+    /// The footers in the order they appear in the message.
+    #[must_use]
+    pub fn footers(&self) -> &[Footer] {
+        &self.footers
+    }
+
+    /// Returns the length of the description in characters, the same count the `description-max-length` rule checks.
+    ///
+    /// # Returns
+    /// The number of characters of the description, without the type, scope and marker.
+    #[must_use]
+    pub fn char_count(&self) -> usize {
+        self.description.char_count()
+    }
+
+    /// Returns the number of words in the body, words being separated by white-space.
+    ///
+    /// # Returns
+    /// The number of words of the body, `0` if the commit has none.
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        self.body.as_deref().map_or(0, |body| body.split_whitespace().count())
+    }
+
+    /// Returns the number of footers.
+    ///
+    /// # Returns
+    /// The number of footers, counting repeated tokens once per occurrence.
+    #[must_use]
+    pub fn footer_count(&self) -> usize {
+        self.footers.len()
+    }
+
+    /// Returns whether the header carries the `!` breaking change marker.
+    ///
+    /// # Returns
+    /// `true` if the header is written as `<type>[(<scope>)]!: <description>`.
+    #[must_use]
+    pub fn has_breaking_marker(&self) -> bool {
+        self.breaking
+    }
+
+    /// Returns whether the commit introduces a breaking change.
+    ///
+    /// A commit is breaking if its header carries the `!` marker, or if it has a `BREAKING CHANGE` or `BREAKING-CHANGE`
+    /// footer.
+    ///
+    /// # Returns
+    /// `true` if the commit is breaking.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        self.breaking || self.footers.iter().any(Footer::is_breaking)
+    }
+
+    /// Returns the description of the breaking change, from the first `BREAKING CHANGE` or `BREAKING-CHANGE` footer.
+    ///
+    /// A commit only flagged by the `!` marker is breaking, according to `Commit::is_breaking`, but has no description.
+    ///
+    /// # Returns
+    /// * `Some(&str)` with the value of the breaking change footer.
+    /// * `None` if the commit has no breaking change footer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let commit = Commit::parse("feat!: drop v1\n\nBREAKING CHANGE: the v1 endpoints are gone").unwrap();
+    ///
+    /// assert_eq!(commit.breaking_change_description(), Some("the v1 endpoints are gone"));
+    /// assert_eq!(Commit::parse("feat!: drop v1").unwrap().breaking_change_description(), None);
+    /// ```
+    #[must_use]
+    pub fn breaking_change_description(&self) -> Option<&str> {
+        self.footers.iter().find(|f| f.is_breaking()).map(Footer::value)
+    }
+
+    /// Returns the semantic version component the commit requires to be incremented.
+    ///
+    /// Breaking changes, flagged either by the `!` marker or a breaking change footer, require a major bump. Otherwise
+    /// `feat` requires a minor bump, `fix` a patch bump, and any other type none.
+    ///
+    /// # Returns
+    /// The `VersionBump` for the commit.
+    #[must_use]
+    pub fn version_bump(&self) -> VersionBump {
+        match self.kind {
+            _ if self.is_breaking() => VersionBump::Major,
+            CommitType::Feat => VersionBump::Minor,
+            CommitType::Fix => VersionBump::Patch,
+            _ => VersionBump::None,
+        }
+    }
+
+    /// Returns the footers using a token, in the order they appear.
+    ///
+    /// Tokens are matched case-insensitively, as git does for trailers, and the footers keep the casing they were written
+    /// with. Breaking changes are the exception, their token must be uppercase, so use [`Commit::is_breaking`] for them.
+    ///
+    /// # Arguments
+    /// * `token` - The token of the footers to return, ex: `Closes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Commit, Footer};
+    /// let commit = Commit::parse("fix: handle tabs\n\ncloses #12\nReviewed-by: Alice\nCloses #13").unwrap();
+    ///
+    /// assert_eq!(vec!["#12", "#13"], commit.footers_by_token("Closes").map(Footer::value).collect::<Vec<_>>());
+    /// ```
+    pub fn footers_by_token<'a>(&'a self, token: &'a str) -> impl Iterator<Item = &'a Footer> {
+        self.footers.iter().filter(move |f| f.token().eq_ignore_ascii_case(token))
+    }
+
+    /// Returns the footers using exactly a token, in the order they appear.
+    ///
+    /// Unlike [`Commit::footers_by_token`], tokens only differing in case don't match.
+    ///
+    /// # Arguments
+    /// * `token` - The token of the footers to return, with the casing it must be written with, ex: `Closes`.
+    pub fn footers_by_token_exact<'a>(&'a self, token: &'a str) -> impl Iterator<Item = &'a Footer> {
+        self.footers.iter().filter(move |f| f.token() == token)
+    }
+
+    /// Returns the issues the commit references, from its `Closes`, `Fixes`, `Resolves` and `Refs` footers.
+    ///
+    /// Every form of GitHub's closing keywords is recognised, ex: `Close`, `Fixed` or `Resolves`, and tokens are matched
+    /// case-insensitively. A footer can reference several issues, separated by commas or white-space, and the leading
+    /// `#` of a reference is stripped, ex: `Closes: #12, #13`. Each issue is returned once, in the order it first
+    /// appears.
+    ///
+    /// # Returns
+    /// The referenced issues, ex: `12` or `PROJ-1`, empty if the commit references none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let commit = Commit::parse("fix: handle tabs\n\nFixes #12\nRefs: PROJ-1, #12").unwrap();
+    ///
+    /// assert_eq!(vec!["12", "PROJ-1"], commit.issue_references());
+    /// ```
+    #[must_use]
+    pub fn issue_references(&self) -> Vec<String> {
+        let references = self
+            .footers
+            .iter()
+            .filter(|f| is_issue_token(f.token()))
+            .flat_map(|f| f.value().split(|c: char| c == ',' || c.is_whitespace()))
+            .map(|reference| reference.strip_prefix('#').unwrap_or(reference))
+            .filter(|reference| !reference.is_empty());
+
+        let mut issues: Vec<String> = Vec::new();
+        for reference in references {
+            if !issues.iter().any(|issue| issue == reference) {
+                issues.push(reference.to_string());
+            }
+        }
+        issues
+    }
+
+    /// Returns the pull request the commit was merged from, if any.
+    ///
+    /// The pull request is read from the first `PR-URL` or `Pull-request` footer. Tokens are matched case-insensitively,
+    /// as git does for trailers.
+    ///
+    /// # Returns
+    /// * `Some(&str)` with the footer value, ex: `https://github.com/org/repo/pull/42`.
+    /// * `None` if the commit has no pull request footer.
+    #[must_use]
+    pub fn pull_request(&self) -> Option<&str> {
+        self.footers
+            .iter()
+            .find(|f| PULL_REQUEST_TOKENS.iter().any(|t| t.eq_ignore_ascii_case(f.token())))
+            .map(Footer::value)
+    }
+
+    /// Returns the version released by the commit, if it is a release commit.
+    ///
+    /// Release commits are `chore` commits whose description is a version, either under a `release` scope, ex:
+    /// `chore(release): 1.2.0`, or after the word `release`, ex: `chore: release v1.2.0`. The version must be
+    /// `major.minor.patch`, optionally prefixed by `v` and followed by a pre-release or build suffix, ex: `1.2.0-rc.1`.
+    ///
+    /// # Returns
+    /// * `Some(&str)` with the version as written, without the `v` prefix, ex: `1.2.0`.
+    /// * `None` if the commit is not a release commit.
+    #[must_use]
+    pub fn release_version(&self) -> Option<&str> {
+        if self.kind != CommitType::Chore {
+            return None;
+        }
+
+        let description = self.description.as_str().trim();
+        let version = match self.scope.as_ref() {
+            Some(scope) if scope.as_str().eq_ignore_ascii_case(RELEASE_KEYWORD) => description.strip_prefix(RELEASE_KEYWORD).map_or(description, str::trim_start),
+            _ => description
+                .split_once(' ')
+                .filter(|(keyword, _)| keyword.eq_ignore_ascii_case(RELEASE_KEYWORD))
+                .map(|(_, rest)| rest.trim_start())?,
+        };
+        let version = version.strip_prefix('v').unwrap_or(version);
+
+        is_version(version).then_some(version)
+    }
+
+    /// Returns the SHAs of the commits reverted by the commit, if it is a revert commit.
+    ///
+    /// SHAs are read from the `This reverts commit <sha>.` lines of the body, as written by `git revert` and
+    /// `Commit::revert`, and from the comma separated values of `Refs` footers that look like SHAs, ex: `Refs: 676104e,
+    /// a215868`. Each SHA is returned once, in the order it first appears.
+    ///
+    /// # Returns
+    /// The referenced SHAs, empty if the commit is not a `revert` or references none.
+    #[must_use]
+    pub fn reverted_shas(&self) -> Vec<&str> {
+        if self.kind != CommitType::Revert {
+            return Vec::new();
+        }
+
+        let from_body = self
+            .body
+            .iter()
+            .flat_map(|body| body.lines())
+            .filter_map(|line| line.trim().strip_prefix("This reverts commit ")?.strip_suffix('.'));
+        let from_footers = self
+            .footers
+            .iter()
+            .filter(|f| f.token().eq_ignore_ascii_case(REVERT_TOKEN))
+            .flat_map(|f| f.value().split(','))
+            .map(str::trim);
+
+        let mut shas = Vec::new();
+        for sha in from_body.chain(from_footers).filter(|sha| is_sha(sha)) {
+            if !shas.contains(&sha) {
+                shas.push(sha);
+            }
+        }
+        shas
+    }
+
+    /// Renders the commit message with the body hard-wrapped at the given column width.
+    ///
+    /// Lines of the body longer than `width` are broken at word boundaries. Words longer than `width`, such as URLs, are
+    /// kept whole on their own line rather than broken. Indented lines (ex: code samples), the header, the footers and any
+    /// paragraph of the body made only of trailers are never wrapped. Unlike `Display`, which preserves the exact content,
+    /// this should be used when generating messages.
+    ///
+    /// # Arguments
+    /// * `width` - The maximum number of characters per body line, commonly 72.
+    ///
+    /// # Returns
+    /// The rendered commit message.
+    #[must_use]
+    pub fn render_wrapped(&self, width: usize) -> String {
+        Commit {
+            body: self.body.as_deref().map(|body| wrap(body, width)),
+            ..self.clone()
+        }
+        .to_string()
+    }
+
+    /// Checks the commit against the opt-in rules enabled in the options.
+    ///
+    /// # Arguments
+    /// * `options` - The rules to check.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the commit satisfies every enabled rule.
+    /// * `Err(ValidationErrors)` with one error per violated rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidFieldValue` for the `type` when `types` is set and the type is not in it, one for the
+    /// `description` when it is longer than `max_description_length`, a `MissingRequiredField` for the
+    /// `breaking change` when `require_breaking_description` is enabled and the
+    /// header has the `!` marker but no footer describes the breaking change, a `RuleViolation` when
+    /// `forbid_trailing_period` is enabled and the description ends with a full stop, and a `MissingRequiredField` for
+    /// the `scope` when it is missing, or a `RuleViolation` when it is present, against the `scope_policy`.
+    pub fn validate_with(&self, options: &LintOptions) -> Result<(), ValidationErrors> {
+        let mut errs = Errors::new();
+
+        if let Some(types) = &options.types
+            && let Err(e) = types.validate(&self.kind)
+        {
+            errs.append(e);
+        }
+
+        if let Some(limit) = options.max_description_length
+            && let Err(e) = Description::parse_with_limit(self.description.as_str(), limit)
+        {
+            errs.append(e);
+        }
+
+        if options.require_breaking_description && self.breaking && !self.footers.iter().any(Footer::is_breaking) {
+            errs.append(ValidationError::MissingRequiredField("breaking change".into()));
+        }
+
+        if options.forbid_trailing_period && self.description.as_str().ends_with('.') {
+            errs.append(ValidationError::RuleViolation {
+                rule: "description-trailing-period".into(),
+                message: format!("description '{}' must not end with a full stop", self.description),
+            });
+        }
+
+        match (options.scope_policy, &self.scope) {
+            (ScopePolicy::Required, None) => errs.append(ValidationError::MissingRequiredField("scope".into())),
+            (ScopePolicy::Forbidden, Some(scope)) => errs.append(ValidationError::RuleViolation {
+                rule: "scope-forbidden".into(),
+                message: format!("scope '{scope}' is not allowed, scopes are forbidden"),
+            }),
+            _ => {}
+        }
+
+        if let Some(max) = options.max_errors {
+            errs.truncate(max);
+        }
+
+        errs.into_result(())
+    }
+
+    /// Checks the commit against the commitlint rules of the configuration.
+    ///
+    /// # Arguments
+    /// * `config` - The rules to check, ex: deserialized from a team's existing configuration.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the commit satisfies every configured rule.
+    /// * `Err(ValidationErrors)` with one error per violated rule, tagged with the commitlint rule name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RuleViolation` when the `type` or a `scope` is not allowed, compared case-sensitively, when the header is longer than
+    /// `header_max_length`, per body line longer than `body_max_line_length`, and per required footer that is missing.
+    pub fn check(&self, config: &RuleConfig) -> Result<(), ValidationErrors> {
+        let mut errs = Errors::new();
+
+        if let Some(types) = &config.type_enum
+            && !types.iter().any(|t| t == self.kind.as_str())
+        {
+            errs.append(ValidationError::RuleViolation {
+                rule: "type-enum".into(),
+                message: format!("type '{}' must be one of: {}", self.kind, types.join(", ")),
+            });
+        }
+
+        if let Some(scopes) = &config.scope_enum {
+            for scope in self.scopes().iter().filter(|s| !scopes.contains(s)) {
+                errs.append(ValidationError::RuleViolation {
+                    rule: "scope-enum".into(),
+                    message: format!("scope '{scope}' must be one of: {}", scopes.join(", ")),
+                });
+            }
+        }
+
+        let header = self.subject();
+        if let Some(max) = config.header_max_length
+            && header.chars().count() > max
+        {
+            errs.append(ValidationError::RuleViolation {
+                rule: "header-max-length".into(),
+                message: format!("header '{header}' is {} characters long, it must be at most {max}", header.chars().count()),
+            });
+        }
+
+        if let Some(max) = config.body_max_line_length {
+            for line in self.body.iter().flat_map(|body| body.lines()).filter(|line| line.chars().count() > max) {
+                errs.append(ValidationError::RuleViolation {
+                    rule: "body-max-line-length".into(),
+                    message: format!("body line '{line}' is {} characters long, lines must be at most {max}", line.chars().count()),
+                });
+            }
+        }
+
+        for token in config
+            .trailer_exists
+            .iter()
+            .filter(|token| !self.footers.iter().any(|f| f.token().eq_ignore_ascii_case(token)))
+        {
+            errs.append(ValidationError::RuleViolation {
+                rule: "trailer-exists".into(),
+                message: format!("footers must include a '{token}' footer"),
+            });
+        }
+
+        errs.into_result(())
+    }
+
+    /// Returns a copy of the commit with the problems that can be fixed automatically fixed.
+    ///
+    /// The type is lowercased, including custom types, and trailing full stops are removed from the description unless
+    /// nothing else would be left. The body and footers are kept as they are.
+    ///
+    /// # Returns
+    /// The normalised `Commit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let commit = Commit::parse("WIP(parser): add lexer.").unwrap();
+    ///
+    /// assert_eq!(commit.normalized().to_string(), "wip(parser): add lexer");
+    /// ```
+    #[must_use]
+    pub fn normalized(&self) -> Commit {
+        self.normalized_with(&NormalizeOptions::default())
+    }
+
+    /// Returns a copy of the commit normalised like [`Commit::normalized`], with the extra fixes enabled in the options.
+    ///
+    /// # Arguments
+    /// * `options` - The extra fixes to apply.
+    ///
+    /// # Returns
+    /// The normalised `Commit`.
+    #[must_use]
+    pub fn normalized_with(&self, options: &NormalizeOptions) -> Commit {
+        let kind = match &self.kind {
+            CommitType::Custom(custom) => CommitType::Custom(custom.to_ascii_lowercase()),
+            kind => kind.clone(),
+        };
+
+        let description = if options.ascii_punctuation {
+            ascii_punctuation(self.description.as_str())
+        } else {
+            self.description.as_str().to_string()
+        };
+        let description = Description::parse_with_limit(description.trim_end_matches('.').trim_end(), usize::MAX).unwrap_or_else(|_| self.description.clone());
+
+        Commit {
+            kind,
+            description,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the commit with a footer appended after the existing ones.
+    ///
+    /// # Arguments
+    /// * `footer` - The footer to append.
+    ///
+    /// # Returns
+    /// The `Commit` with the same header and body, and the footer last.
+    #[must_use]
+    pub fn with_footer(mut self, footer: Footer) -> Self {
+        self.footers.push(footer);
+        self
+    }
+
+    /// Returns the commit with its footers grouped in a conventional order, for messages to look the same across a team.
+    ///
+    /// `BREAKING CHANGE` footers come first, then the issue references, ex: `Refs` or `Closes`, then the other footers,
+    /// and the trailers naming people, ex: `Co-authored-by` or `Signed-off-by`, last. Footers keep their relative order
+    /// within each group. Tokens are matched case-insensitively, as git does for trailers.
+    ///
+    /// # Returns
+    /// The `Commit` with the same header and body, and the footers reordered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let commit = Commit::parse("feat!: add lexer\n\nSigned-off-by: Alice\nRefs: PROJ-1\nBREAKING CHANGE: drops tabs").unwrap();
+    ///
+    /// assert_eq!(
+    ///     commit.normalize_footers().to_string(),
+    ///     "feat!: add lexer\n\nBREAKING CHANGE: drops tabs\nRefs: PROJ-1\nSigned-off-by: Alice"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn normalize_footers(mut self) -> Self {
+        self.footers.sort_by_key(|f| match f.token() {
+            _ if f.is_breaking() => 0,
+            token if is_issue_token(token) => 1,
+            token if CANONICAL_RELATIONSHIPS.iter().any(|t| t.eq_ignore_ascii_case(token)) => 3,
+            _ => 2,
+        });
+        self
+    }
+
+    /// Returns the commit without the footers using a token.
+    ///
+    /// Tokens are matched case-insensitively, as git does for trailers. The other footers keep their order.
+    ///
+    /// # Arguments
+    /// * `token` - The token of the footers to remove, ex: `Refs`.
+    ///
+    /// # Returns
+    /// The `Commit` with the same header and body, and no footer using the token.
+    #[must_use]
+    pub fn without_footer(mut self, token: &str) -> Self {
+        self.footers.retain(|f| !f.token().eq_ignore_ascii_case(token));
+        self
+    }
+
+    /// Returns the commit with the value of the footers using a token replaced.
+    ///
+    /// Tokens are matched case-insensitively, as git does for trailers. The footers keep their position, token and
+    /// separator. The commit is returned unchanged if no footer uses the token.
+    ///
+    /// # Arguments
+    /// * `token` - The token of the footers to update, ex: `Refs`.
+    /// * `value` - The new value of the footers.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` with the same header and body, and the footers updated.
+    /// * `Err(ValidationErrors)` if the value is invalid for a footer, ex: blank.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `ValidationErrors` of the first footer the value is invalid for.
+    pub fn replace_footer(mut self, token: &str, value: &str) -> Result<Self, ValidationErrors> {
+        for footer in self.footers.iter_mut().filter(|f| f.token().eq_ignore_ascii_case(token)) {
+            *footer = Footer::builder(footer.token()).separator(footer.separator()).value(value).build()?;
+        }
+        Ok(self)
+    }
+
+    /// Compares the fields of the commit with the ones of the commit it became, ex: after a hook added a ticket.
+    ///
+    /// Unlike a text diff, only what the specification gives meaning to is compared: the header, the body, and the
+    /// footers regardless of their order.
+    ///
+    /// # Arguments
+    /// * `other` - The commit to compare with, the "after" side of the diff.
+    ///
+    /// # Returns
+    /// The `CommitDiff` listing the fields that differ, empty if none do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Commit;
+    /// let before = Commit::parse("feat: add lexer").unwrap();
+    /// let after = Commit::parse("feat: add lexer\n\nRefs: PROJ-1").unwrap();
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.added_footers(), after.footers());
+    /// assert!(diff.removed_footers().is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Commit) -> CommitDiff {
+        CommitDiff::between(self, other)
+    }
+}
+
+impl CommitBuilder {
+    /// Appends a footer to the commit.
+    ///
+    /// # Arguments
+    /// * `footer` - The footer to append after any previously added footers.
+    ///
+    /// # Returns
+    /// The builder, for further configuration.
+    pub fn footer(&mut self, footer: Footer) -> &mut Self {
+        self.footers.get_or_insert_with(Vec::new).push(footer);
+        self
+    }
+}
+
+/// Implementation of the `Build` trait for `CommitBuilder`.
+///
+/// This implementation validates that:
+/// - The type and description are set
+/// - A blank body is dropped rather than rendered as an empty paragraph
+impl Build<Commit> for CommitBuilder {
+    /// Validates and builds a `Commit` instance.
+    ///
+    /// # Returns
+    /// * `Ok(Commit)` if validation passes.
+    /// * `Err(ValidationErrors)` if validation fails.
+    fn build(&mut self) -> Result<Commit, ValidationErrors> {
+        let mut errs = Errors::new();
+
+        let kind = self.kind.clone().ok_or_else(|| errs.append(ValidationError::MissingRequiredField("type".into())));
+        let description = self
+            .description
+            .clone()
+            .ok_or_else(|| errs.append(ValidationError::MissingRequiredField("description".into())));
+
+        match (kind, description) {
+            (Ok(kind), Ok(description)) => Ok(Commit {
+                prefix: self.prefix.clone().flatten().filter(|p| !p.trim().is_empty()),
+                kind,
+                scope: self.scope.clone().flatten(),
+                breaking: self.breaking.unwrap_or_default(),
+                description,
+                body: self.body.clone().flatten().filter(|b| !b.trim().is_empty()),
+                footers: self.footers.clone().unwrap_or_default(),
+            }),
+            _ => Err(errs),
+        }
+    }
+}
+
+/// Implementation of the `Validate` trait for `Commit`.
+///
+/// Checks every component against the same rules as parsing: the type must be a valid type token, the scope must not
+/// be blank, the description must fit on one line of at most `Description::DEFAULT_MAX_LENGTH` characters, the body
+/// must not start with a blank line, and each footer must be valid. Errors name the fields `commit.type`,
+/// `commit.scope`, `commit.description` and `commit.body`, and `commit.footers[<index>].<field>` for the footers, ex:
+/// `commit.footers[0].token`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Build, Commit, CommitType, Description, Validate};
+/// let commit = Commit::builder(CommitType::Custom("fe at".into()), Description::parse("add lexer").unwrap()).build().unwrap();
+/// let errs = commit.validate().unwrap_err();
+///
+/// assert_eq!(errs.first().unwrap().to_string(), "field 'commit.type' has invalid value: 'fe at' must only contain letters, digits, '-' or '_'");
+/// ```
+impl Validate for Commit {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errs = Errors::new();
+
+        if let Err(e) = self.kind.as_str().parse::<CommitType>() {
+            errs.append(prefix_field("commit", e));
+        }
+
+        if let Some(Err(e)) = self.scope.as_ref().map(|s| s.as_str().parse::<Scope>()) {
+            errs.append(prefix_field("commit", e));
+        }
+
+        if let Err(e) = Description::parse(self.description.as_str()) {
+            errs.append(prefix_field("commit", e));
+        }
+
+        if let Some(body) = &self.body
+            && body.lines().next().is_some_and(|l| l.trim().is_empty())
+        {
+            errs.append(ValidationError::InvalidFieldValue("commit.body".into(), anyhow!("must not start with a blank line")));
+        }
+
+        for (i, footer) in self.footers.iter().enumerate() {
+            if let Err(e) = footer.validate() {
+                errs.merge(e.map(|e| prefix_field(&format!("commit.footers[{i}]"), e)));
+            }
+        }
+
+        errs.into_result(())
+    }
+}
+
+impl FromStr for Commit {
+    type Err = ConventionalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Commit::parse(s)
+    }
+}
+
+/// Implementation of the `Display` trait for `Commit`.
+///
+/// The header, body and footers are separated by blank lines, and no trailing line break is written.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Build, Commit, CommitType, Description, Footer, Scope};
+/// #
+/// let commit = Commit::builder(CommitType::Feat, Description::parse("add lexer").unwrap())
+///     .scope("parser".parse::<Scope>().unwrap())
+///     .body("Splits the header into tokens.")
+///     .footer(Footer::builder("Refs").value("PROJ-123").build().unwrap())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(format!("{commit}"), "feat(parser): add lexer\n\nSplits the header into tokens.\n\nRefs: PROJ-123");
+/// ```
+impl Display for Commit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subject())?;
+
+        if let Some(body) = &self.body {
+            write!(f, "\n\n{body}")?;
+        }
+
+        for (i, footer) in self.footers.iter().enumerate() {
+            write!(f, "{}{footer}", if i == 0 { "\n\n" } else { "\n" })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Hard-wraps each line of the text at word boundaries, leaving indented lines and trailer paragraphs untouched.
+///
+/// A paragraph of footers, ex: `Refs: PROJ-1`, is kept as is, since breaking a trailer would turn its value into a line
+/// of body text, or start a new trailer from a word of its value.
+fn wrap(text: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+
+    for paragraph in text.split("\n\n") {
+        if footer::is_footer_paragraph(paragraph) {
+            lines.push(paragraph.to_string());
+        } else {
+            lines.extend(paragraph.lines().map(|line| wrap_line(line, width)));
+        }
+        lines.push(String::new());
+    }
+
+    lines.pop();
+    lines.join("\n")
+}
+
+/// Hard-wraps a line at word boundaries, leaving it untouched when it is indented or fits within the width.
+fn wrap_line(line: &str, width: usize) -> String {
+    if line.chars().count() <= width || line.starts_with(char::is_whitespace) {
+        return line.to_string();
+    }
+
+    let mut wrapped = Vec::new();
+    let mut current = String::new();
+
+    for word in line.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            wrapped.push(std::mem::take(&mut current));
+        }
+
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    wrapped.push(current);
+    wrapped.join("\n")
+}
+
+/// Replaces curly quotes and typographic dashes with their ASCII equivalents.
+fn ascii_punctuation(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' => '\'',
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' => '"',
+            '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2015}' => '-',
+            c => c,
+        })
+        .collect()
+}
+
+/// Parses a commit message, restricting the type to the set and stripping the allowed prefix when there are ones.
+fn parse_message(message: &str, types: Option<&CommitTypeSet>, prefix: Option<&Regex>, limit: usize) -> Result<Commit, ValidationErrors> {
+    let message = message.trim_end();
+    let (header, rest) = message.split_once('\n').unwrap_or((message, ""));
+    let header = header.trim_end_matches('\r');
+
+    let (prefix, header) = match prefix.and_then(|p| p.find(header)).filter(|m| m.start() == 0 && !m.is_empty()) {
+        Some(m) => (Some(m.as_str().to_string()), header[m.end()..].trim_start()),
+        None => (None, header),
+    };
+
+    let mut errs = Errors::new();
+
+    let header = parse_header(header, types, limit).map_err(|e| errs.merge(e)).ok();
+    let (body, footers) = parse_body_and_footers(rest).map_err(|e| errs.merge(e)).unwrap_or_default();
+
+    match header {
+        Some(header) if errs.is_empty() => Ok(Commit {
+            prefix,
+            kind: header.commit_type,
+            scope: header.scope,
+            breaking: header.breaking,
+            description: header.description,
+            body,
+            footers,
+        }),
+        _ => Err(errs),
+    }
+}
+
+/// Parses the header line, ex: `feat(parser)!: add lexer`.
+fn parse_header(header: &str, types: Option<&CommitTypeSet>, limit: usize) -> Result<Header, ValidationErrors> {
+    if header.trim().is_empty() {
+        return Err(Errors::from([ValidationError::MissingRequiredField("header".into())]));
+    }
+
+    let malformed = || {
+        Errors::from([ValidationError::RuleViolation {
+            rule: "header-format".into(),
+            message: format!("header '{header}' must be formatted as '{HEADER_FORMAT}'"),
+        }])
+    };
+
+    let (prefix, description) = header.split_once(':').ok_or_else(malformed)?;
+    let description = description.strip_prefix(' ').ok_or_else(malformed)?;
+    let (prefix, breaking) = prefix.strip_suffix('!').map_or((prefix, false), |p| (p, true));
+
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((commit_type, scope)) => (commit_type, Some(scope.strip_suffix(')').ok_or_else(malformed)?)),
+        None => (prefix, None),
+    };
+
+    if commit_type.ends_with('!') || scope.is_some_and(|s| s.ends_with('!')) {
+        return Err(Errors::from([ValidationError::RuleViolation {
+            rule: "header-breaking-marker".into(),
+            message: format!("header '{header}' must place the breaking change marker '!' right before the colon, as in '{HEADER_FORMAT}'"),
+        }]));
+    }
+
+    let mut errs = Errors::new();
+
+    let commit_type = commit_type
+        .parse::<CommitType>()
+        .and_then(|t| types.map_or(Ok(()), |types| types.validate(&t)).map(|()| t))
+        .map_err(|e| errs.append(e));
+    let scope = scope.map(str::parse::<Scope>).transpose().map_err(|e| errs.append(e));
+    let description = Description::parse_with_limit(description, limit).map_err(|e| errs.append(e));
+
+    match (commit_type, scope, description) {
+        (Ok(commit_type), Ok(scope), Ok(description)) => Ok(Header {
+            commit_type,
+            scope,
+            breaking,
+            description,
+        }),
+        _ => Err(errs),
+    }
+}
+
+/// Checks the header line against the grammar `parse_header` enforces with the default description limit, without
+/// building any of its components.
+fn is_header(header: &str) -> bool {
+    let Some((prefix, description)) = header.split_once(':') else {
+        return false;
+    };
+    let Some(description) = description.strip_prefix(' ') else {
+        return false;
+    };
+    let prefix = prefix.strip_suffix('!').unwrap_or(prefix);
+
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((commit_type, scope)) => match scope.strip_suffix(')') {
+            Some(scope) => (commit_type, Some(scope)),
+            None => return false,
+        },
+        None => (prefix, None),
+    };
+
+    let is_type = !commit_type.is_empty() && commit_type.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    let is_scope = scope.is_none_or(|s| !s.ends_with('!') && !s.contains(['(', ')', '\n', '\r']) && s.split(',').all(|p| !p.trim().is_empty()));
+    let is_description = !description.trim().is_empty()
+        && description.trim() == description
+        && !description.contains(['\n', '\r'])
+        && description.chars().count() <= Description::DEFAULT_MAX_LENGTH;
+
+    is_type && is_scope && is_description
+}
+
+/// Checks whether a footer token references issues, as `Refs` or any tense of a `ClosingKeyword`, ignoring case.
+fn is_issue_token(token: &str) -> bool {
+    token.eq_ignore_ascii_case(REFS_TOKEN) || ClosingKeyword::ALL.iter().flat_map(|k| k.tenses()).any(|t| t.eq_ignore_ascii_case(token))
+}
+
+/// Checks that the text is a plausible commit SHA, full or abbreviated.
+fn is_sha(text: &str) -> bool {
+    SHA_LENGTHS.contains(&text.len()) && text.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Checks that the text is a `major.minor.patch` version, optionally followed by a `-pre-release` or `+build` suffix.
+fn is_version(text: &str) -> bool {
+    let (core, suffix) = text.split_at(text.find(['-', '+']).unwrap_or(text.len()));
+    let numbers: Vec<&str> = core.split('.').collect();
+
+    numbers.len() == 3 && numbers.iter().all(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit())) && suffix.len() != 1
+}
+
+/// Splits the text following the header into the body and the footers.
+fn parse_body_and_footers(rest: &str) -> Result<(Option<String>, Vec<Footer>), ValidationErrors> {
+    let lines: Vec<&str> = rest.lines().collect();
+    let (separated, body, footers) = split_body(rest, &lines);
+    if !separated {
+        return Err(Errors::from([unseparated_body()]));
+    }
+
+    let mut errs = Errors::new();
+    let footers = footer::parse_paragraph(footers).filter_map(|(_, f)| f.map_err(|e| errs.merge(e)).ok()).collect();
+
+    errs.into_result((Some(body).filter(|b| !b.is_empty()), footers))
+}
+
+/// Splits what follows the header into the body and the lines of its footer paragraph, without parsing the footers.
+///
+/// # Arguments
+/// * `rest` - The message after the header line.
+/// * `lines` - The lines of `rest`.
+///
+/// # Returns
+/// Whether the body is separated from the header by a blank line, the body without trailing white-space, and the footer
+/// lines.
+fn split_body<'a, 'b>(rest: &str, lines: &'b [&'a str]) -> (bool, String, &'b [&'a str]) {
+    let separated = lines.first().is_none_or(|line| line.trim().is_empty());
+    let start = lines.iter().take_while(|l| l.trim().is_empty()).count();
+    let (body, footers) = body::split_footers(&lines[start..]);
+
+    let body = if !body.is_empty() && !rest.contains('\r') {
+        line_range(rest, start, body.len()).trim_end().to_string()
+    } else {
+        body.join("\n").trim_end().to_string()
+    };
+    (separated, body, footers)
+}
+
+/// The error of a body written right after the header, without the blank line separating them.
+fn unseparated_body() -> ValidationError {
+    ValidationError::InvalidFieldValue("body".into(), anyhow!("must be separated from the header by a blank line"))
+}
+
+/// Renames the field of an error after the component it comes from, ex: `footer.token` to `commit.footers[0].token`.
+///
+/// Rule violations have no field, so they are kept as they are.
+fn prefix_field(prefix: &str, err: ValidationError) -> ValidationError {
+    let field = |field: &str| format!("{prefix}.{}", field.split_once('.').map_or(field, |(_, rest)| rest));
+
+    match err {
+        ValidationError::MissingRequiredField(name) => ValidationError::MissingRequiredField(field(&name)),
+        ValidationError::InvalidFieldValue(name, reason) => ValidationError::InvalidFieldValue(field(&name), reason),
+        err @ ValidationError::RuleViolation { .. } => err,
+    }
+}
+
+/// Returns `count` lines of the text from the `start`-th one, borrowed from the text whose lines end with `\n` only.
+///
+/// This spares joining the lines of the body again, as they are contiguous in the message without `\r`.
+fn line_range(text: &str, start: usize, count: usize) -> &str {
+    let from = text.splitn(start + 1, '\n').nth(start).unwrap_or_default();
+    from.match_indices('\n').nth(count.saturating_sub(1)).map_or(from, |(end, _)| &from[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        model::{BreakingChangeToken, FooterSeparator, KNOWN_TYPES},
+        multi_error,
+    };
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    fn commit(message: &str) -> Commit {
+        Commit::parse(message).expect("should have parsed the commit")
+    }
+
+    fn validation_errors<T: core::fmt::Debug>(result: Result<T, ConventionalError>) -> ValidationErrors {
+        match result.expect_err("should have failed") {
+            ConventionalError::Validation(errs) => errs,
+            err => panic!("expected validation errors, got: {err}"),
+        }
+    }
+
+    #[rstest]
+    #[case::type_only("feat: add lexer", CommitType::Feat, None, false, "add lexer")]
+    #[case::with_scope("fix(parser): handle tabs", CommitType::Fix, Some("parser"), false, "handle tabs")]
+    #[case::breaking_marker("refactor!: drop v1", CommitType::Refactor, None, true, "drop v1")]
+    #[case::scope_and_marker("feat(api)!: drop v1", CommitType::Feat, Some("api"), true, "drop v1")]
+    #[case::hyphenated_scope_and_marker("feat(a-b)!: drop v1", CommitType::Feat, Some("a-b"), true, "drop v1")]
+    #[case::marker_without_scope("feat!: drop v1", CommitType::Feat, None, true, "drop v1")]
+    #[case::scope_list("feat(parser, lexer)!: add tokens", CommitType::Feat, Some("parser,lexer"), true, "add tokens")]
+    #[case::custom_type("wip: try things", CommitType::Custom("wip".into()), None, false, "try things")]
+    fn test_parses_header(#[case] message: &str, #[case] commit_type: CommitType, #[case] scope: Option<&str>, #[case] breaking: bool, #[case] description: &str) {
+        let commit = commit(message);
+
+        assert_eq!(&commit_type, commit.commit_type());
+        assert_eq!(scope, commit.scope().map(Scope::as_str));
+        assert_eq!(breaking, commit.has_breaking_marker());
+        assert_eq!(description, commit.description().as_str());
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer", true)]
+    #[case::scope_and_marker("feat(parser, lexer)!: add tokens", true)]
+    #[case::body_and_footers("fix: handle tabs\n\nTabs are white-space.\n\nRefs: PROJ-1\n", true)]
+    #[case::crlf("fix: handle tabs\r\n\r\nTabs are white-space.", true)]
+    #[case::body_not_separated("fix: handle tabs\nTabs are white-space.", true)]
+    #[case::empty("", false)]
+    #[case::no_colon("Add lexer", false)]
+    #[case::no_space("feat:add lexer", false)]
+    #[case::invalid_type("fe at: add lexer", false)]
+    #[case::unclosed_scope("feat(api: add", false)]
+    #[case::empty_scope_in_list("feat(a,,b): add", false)]
+    #[case::misplaced_marker("feat(api!): drop v1", false)]
+    #[case::blank_description("feat:  ", false)]
+    #[case::longest_description(&format!("feat: {}", "a".repeat(72)), true)]
+    #[case::long_description(&format!("feat: {}", "a".repeat(73)), false)]
+    #[case::longest_multi_byte_description(&format!("feat(api): {}", "é".repeat(72)), true)]
+    fn test_checks_header_is_conventional(#[case] message: &str, #[case] expect: bool) {
+        assert_eq!(expect, Commit::is_conventional(message));
+    }
+
+    #[rstest]
+    #[case::no_scope("feat: add lexer", vec![])]
+    #[case::single_scope("feat(parser): add lexer", vec!["parser"])]
+    #[case::scope_list("feat(parser, lexer): add tokens", vec!["parser", "lexer"])]
+    fn test_returns_scopes(#[case] message: &str, #[case] expect: Vec<&str>) {
+        assert_eq!(expect, commit(message).scopes());
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer", None, vec![])]
+    #[case::trailing_line_break("feat: add lexer\n", None, vec![])]
+    #[case::body("feat: add lexer\n\nSplits the header.", Some("Splits the header."), vec![])]
+    #[case::multi_paragraph_body("feat: add lexer\n\nFirst.\n\nSecond.", Some("First.\n\nSecond."), vec![])]
+    #[case::footers_only("feat: add lexer\n\nRefs: PROJ-1\nReviewed-by: Alice", None, vec!["Refs: PROJ-1", "Reviewed-by: Alice"])]
+    #[case::body_and_footers("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1", Some("Splits the header."), vec!["Refs: PROJ-1"])]
+    #[case::continued_footer_value("feat: add lexer\n\nRefs: PROJ-1\n  and PROJ-2\nCloses #42", None, vec!["Refs: PROJ-1\n  and PROJ-2", "Closes #42"])]
+    #[case::last_paragraph_not_starting_with_footer("feat: add lexer\n\nnot a footer\nRefs: PROJ-1", Some("not a footer\nRefs: PROJ-1"), vec![])]
+    fn test_parses_body_and_footers(#[case] message: &str, #[case] body: Option<&str>, #[case] footers: Vec<&str>) {
+        let commit = commit(message);
+
+        assert_eq!(body, commit.body());
+        assert_eq!(footers, commit.footers().iter().map(ToString::to_string).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer")]
+    #[case::everything("feat(parser, lexer)!: add tokens\n\nSplits the header.\n\nBREAKING CHANGE: drops v1\n  and v2\nCloses #42")]
+    #[case::custom_type("wip: try things")]
+    fn test_validates_parsed_commit(#[case] message: &str) {
+        assert_eq!(Ok(()), commit(message).validate());
+    }
+
+    #[test]
+    fn test_reports_every_invalid_component() {
+        let commit = Commit {
+            kind: CommitType::Custom("fe at".into()),
+            description: Description::parse_with_limit(&"a".repeat(80), 100).expect("should have parsed the description"),
+            footers: vec![
+                footer("Refs: PROJ-1"),
+                Footer {
+                    token: "Reviewed by".into(),
+                    separator: FooterSeparator::Colon,
+                    value: "Alice".into(),
+                },
+            ],
+            ..commit("feat: add lexer")
+        };
+
+        let errs = commit.validate().expect_err("should have failed");
+
+        assert_eq!(
+            multi_error!(
+                ValidationError::InvalidFieldValue("commit.type".into(), anyhow!("'fe at' must only contain letters, digits, '-' or '_'")),
+                ValidationError::RuleViolation {
+                    rule: "description-max-length".into(),
+                    message: format!("description '{}' is 80 characters long, it must be at most 72", "a".repeat(80))
+                },
+                ValidationError::InvalidFieldValue("commit.footers[1].token".into(), anyhow!("'Reviewed by' must only contain letters, digits or '-'"))
+            ),
+            errs,
+            "got: {errs}"
+        );
+    }
+
+    #[test]
+    fn test_displays_multi_line_footer_value() {
+        let message = "feat: drop v1\n\nSplits the header.\n\nBREAKING CHANGE: the v1 endpoints are gone,\n  use the v2 ones instead\nRefs: PROJ-1";
+        let commit = commit(message);
+
+        assert_eq!(2, commit.footers().len());
+        assert_eq!(Some("Splits the header."), commit.body());
+        assert_eq!(message, commit.to_string());
+    }
+
+    #[rstest]
+    #[case::empty("", multi_error!(ValidationError::MissingRequiredField("header".into())))]
+    #[case::no_colon("add lexer", multi_error!(ValidationError::RuleViolation { rule: "header-format".into(), message: format!("header 'add lexer' must be formatted as '{HEADER_FORMAT}'") }))]
+    #[case::no_space("feat:add lexer", multi_error!(ValidationError::RuleViolation { rule: "header-format".into(), message: format!("header 'feat:add lexer' must be formatted as '{HEADER_FORMAT}'") }))]
+    #[case::empty_scope_in_list(
+        "feat(a,,b): add",
+        multi_error!(ValidationError::InvalidFieldValue("scope".into(), anyhow!("'a,,b' must not contain empty scopes between commas")))
+    )]
+    #[case::unclosed_scope("feat(api: add", multi_error!(ValidationError::RuleViolation { rule: "header-format".into(), message: format!("header 'feat(api: add' must be formatted as '{HEADER_FORMAT}'") }))]
+    #[case::marker_in_scope(
+        "feat(!): drop v1",
+        multi_error!(ValidationError::RuleViolation { rule: "header-breaking-marker".into(), message: format!("header 'feat(!): drop v1' must place the breaking change marker '!' right before the colon, as in '{HEADER_FORMAT}'") })
+    )]
+    #[case::marker_at_end_of_scope(
+        "feat(api!): drop v1",
+        multi_error!(ValidationError::RuleViolation { rule: "header-breaking-marker".into(), message: format!("header 'feat(api!): drop v1' must place the breaking change marker '!' right before the colon, as in '{HEADER_FORMAT}'") })
+    )]
+    #[case::marker_before_scope(
+        "feat!(api): drop v1",
+        multi_error!(ValidationError::RuleViolation { rule: "header-breaking-marker".into(), message: format!("header 'feat!(api): drop v1' must place the breaking change marker '!' right before the colon, as in '{HEADER_FORMAT}'") })
+    )]
+    #[case::invalid_type_and_description(
+        "fe at(api):  add",
+        multi_error!(
+            ValidationError::InvalidFieldValue("type".into(), anyhow!("'fe at' must only contain letters, digits, '-' or '_'")),
+            ValidationError::InvalidFieldValue("description".into(), anyhow!("' add' must not start or end with white-space"))
+        )
+    )]
+    #[case::missing_blank_line(
+        "feat: add lexer\nSplits the header.",
+        multi_error!(ValidationError::InvalidFieldValue("body".into(), anyhow!("must be separated from the header by a blank line")))
+    )]
+    #[case::footer_token_colliding_with_breaking_change(
+        "feat: drop v1\n\nbreaking-change: v1 is gone",
+        multi_error!(ValidationError::InvalidFieldValue(
+            "token".into(),
+            anyhow!("'breaking-change' collides with the reserved breaking change token, write it 'BREAKING CHANGE' or 'BREAKING-CHANGE'")
+        ))
+    )]
+    fn test_returns_error_parsing_commit(#[case] message: &str, #[case] expect: ValidationErrors) {
+        let errs = validation_errors(Commit::parse(message));
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+
+    #[rstest]
+    #[case::spec_type(CommitTypeSet::default(), "feat(parser): add lexer")]
+    #[case::added_type(CommitTypeSet::default().with(CommitType::Custom("wip".into())), "WIP: try things")]
+    fn test_parses_commit_with_allowed_type(#[case] types: CommitTypeSet, #[case] message: &str) {
+        let commit = Commit::parse_with(&types, message).expect("should have parsed the commit");
+        assert!(types.contains(commit.commit_type()));
+    }
+
+    #[rstest]
+    #[case::custom_type(
+        CommitTypeSet::empty().with(CommitType::Feat).with(CommitType::Fix),
+        "wip: try things",
+        multi_error!(ValidationError::InvalidFieldValue("type".into(), anyhow!("'wip' is not allowed, expected one of: feat, fix")))
+    )]
+    #[case::removed_type(
+        CommitTypeSet::empty().with(CommitType::Feat).with(CommitType::Chore).without(&CommitType::Chore),
+        "chore:  bump",
+        multi_error!(
+            ValidationError::InvalidFieldValue("type".into(), anyhow!("'chore' is not allowed, expected one of: feat")),
+            ValidationError::InvalidFieldValue("description".into(), anyhow!("' bump' must not start or end with white-space"))
+        )
+    )]
+    fn test_rejects_commit_with_disallowed_type(#[case] types: CommitTypeSet, #[case] message: &str, #[case] expect: ValidationErrors) {
+        let errs = validation_errors(Commit::parse_with(&types, message));
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+
+    fn prefix_options(pattern: &str) -> ParseOptions {
+        ParseOptions {
+            prefix: Some(Regex::new(pattern).expect("should have compiled the pattern")),
+            ..ParseOptions::default()
+        }
+    }
+
+    #[rstest]
+    #[case::ticket(r"\[[A-Z]+-\d+\]", "[PROJ-1] feat: add lexer", "[PROJ-1]", "[PROJ-1] feat: add lexer")]
+    #[case::gitmoji("✨|🐛", "✨ feat(parser): add lexer", "✨", "✨ feat(parser): add lexer")]
+    #[case::no_space(r"\[[A-Z]+-\d+\]", "[PROJ-1]feat: add lexer", "[PROJ-1]", "[PROJ-1] feat: add lexer")]
+    fn test_parses_commit_with_allowed_prefix(#[case] pattern: &str, #[case] message: &str, #[case] prefix: &str, #[case] expect: &str) {
+        let commit = Commit::parse_with_options(&prefix_options(pattern), message).expect("should have parsed the commit");
+
+        assert_eq!(Some(prefix), commit.prefix());
+        assert_eq!(&CommitType::Feat, commit.commit_type());
+        assert_eq!(expect, commit.to_string());
+    }
+
+    #[test]
+    fn test_returns_subject_with_prefix() {
+        let commit = Commit::parse_with_options(&prefix_options(r"\[[A-Z]+-\d+\]"), "[PROJ-1] feat: add lexer\n\nSplits the header.").expect("should have parsed the commit");
+        assert_eq!("[PROJ-1] feat: add lexer", commit.subject());
+    }
+
+    #[test]
+    fn test_parses_commit_without_optional_prefix() {
+        let commit = Commit::parse_with_options(&prefix_options(r"\[[A-Z]+-\d+\]"), "feat: add lexer").expect("should have parsed the commit");
+        assert_eq!(None, commit.prefix());
+    }
+
+    #[rstest]
+    #[case::disallowed_prefix(
+        r"\[[A-Z]+-\d+\]",
+        "(PROJ-1) feat: add lexer",
+        multi_error!(ValidationError::RuleViolation { rule: "header-format".into(), message: format!("header '(PROJ-1) feat: add lexer' must be formatted as '{HEADER_FORMAT}'") })
+    )]
+    #[case::prefix_not_at_start(
+        r"\[[A-Z]+-\d+\]",
+        "feat [PROJ-1]: add lexer",
+        multi_error!(ValidationError::InvalidFieldValue("type".into(), anyhow!("'feat [PROJ-1]' must only contain letters, digits, '-' or '_'")))
+    )]
+    fn test_rejects_commit_with_disallowed_prefix(#[case] pattern: &str, #[case] message: &str, #[case] expect: ValidationErrors) {
+        let errs = validation_errors(Commit::parse_with_options(&prefix_options(pattern), message));
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+
+    #[rstest]
+    #[case::default_limit(None, &"a".repeat(72), true)]
+    #[case::over_default_limit(None, &"a".repeat(73), false)]
+    #[case::classic_git_limit(Some(50), &"a".repeat(51), false)]
+    #[case::loose_limit(Some(100), &"a".repeat(100), true)]
+    fn test_limits_description_length(#[case] max_description_length: Option<usize>, #[case] description: &str, #[case] expect: bool) {
+        let options = ParseOptions {
+            max_description_length,
+            ..ParseOptions::default()
+        };
+
+        assert_eq!(expect, Commit::parse_with_options(&options, &format!("feat: {description}")).is_ok());
+    }
+
+    #[test]
+    fn test_parses_commit_from_bytes() {
+        assert_eq!(commit("feat: add lexer"), Commit::from_bytes(b"feat: add lexer").expect("should have parsed the commit"));
+    }
+
+    #[rstest]
+    #[case::invalid_utf8(b"feat: add \xFF lexer", "malformed commit message, it is not valid UTF-8, invalid utf-8 sequence of 1 bytes from index 10")]
+    #[case::invalid_commit(b"add lexer", "error(s):\n  header 'add lexer' must be formatted as '<type>[(<scope>)][!]: <description>'")]
+    fn test_fails_to_parse_commit_from_bytes(#[case] message: &[u8], #[case] expect: &str) {
+        assert_eq!(expect, Commit::from_bytes(message).expect_err("should have failed").to_string());
+    }
+
+    #[test]
+    fn test_parses_structure_without_limiting_description() {
+        let description = "a".repeat(100);
+        assert_eq!(
+            description,
+            Commit::parse_structure(&format!("feat: {description}"))
+                .expect("should have parsed the commit")
+                .description()
+                .as_str()
+        );
+    }
+
+    #[test]
+    fn test_rejects_malformed_structure() {
+        assert_eq!(
+            multi_error!(ValidationError::RuleViolation {
+                rule: "header-format".into(),
+                message: format!("header 'add lexer' must be formatted as '{HEADER_FORMAT}'")
+            }),
+            validation_errors(Commit::parse_structure("add lexer"))
+        );
+    }
+
+    #[rstest]
+    #[case::within_both("feat(parser): add lexer", vec![], vec![])]
+    #[case::scope_missing("feat: add lexer", vec![], vec!["scope-required"])]
+    #[case::custom_type("wip(parser): try things", vec!["type-invalid"], vec![])]
+    #[case::long_description(&format!("feat(parser): {}", "a".repeat(60)), vec!["description-max-length"], vec![])]
+    fn test_validates_structure_against_several_option_sets(#[case] message: &str, #[case] strict: Vec<&str>, #[case] scoped: Vec<&str>) {
+        let commit = Commit::parse_structure(message).expect("should have parsed the commit");
+        let rules = |options: &LintOptions| {
+            commit
+                .validate_with(options)
+                .err()
+                .unwrap_or_else(Errors::new)
+                .iter()
+                .map(ValidationError::rule)
+                .collect::<Vec<_>>()
+        };
+
+        let strict_options = LintOptions {
+            types: Some(CommitTypeSet::default()),
+            max_description_length: Some(Description::RECOMMENDED_LENGTH),
+            ..LintOptions::default()
+        };
+        let scoped_options = LintOptions {
+            scope_policy: ScopePolicy::Required,
+            ..LintOptions::default()
+        };
+
+        assert_eq!(strict, rules(&strict_options));
+        assert_eq!(scoped, rules(&scoped_options));
+    }
+
+    #[test]
+    fn test_parse_rejects_prefix() {
+        assert!(Commit::parse("[PROJ-1] feat: add lexer").is_err());
+    }
+
+    #[test]
+    fn test_parse_accepts_any_type() {
+        assert_eq!(&CommitType::Custom("wip".into()), commit("wip: try things").commit_type());
+    }
+
+    #[rstest]
+    #[case::default_config(RuleConfig::default(), "wip(anything): try things", vec![])]
+    #[case::allowed_type(RuleConfig { type_enum: Some(vec!["feat".into(), "fix".into()]), ..RuleConfig::default() }, "feat: add lexer", vec![])]
+    #[case::disallowed_type_case(RuleConfig { type_enum: Some(vec!["wip".into()]), ..RuleConfig::default() }, "WIP: try things", vec!["type-enum"])]
+    #[case::disallowed_type(RuleConfig { type_enum: Some(vec!["feat".into(), "fix".into()]), ..RuleConfig::default() }, "docs: add lexer", vec!["type-enum"])]
+    #[case::disallowed_scopes(
+        RuleConfig { scope_enum: Some(vec!["parser".into()]), ..RuleConfig::default() },
+        "feat(parser,lexer,model): add tokens",
+        vec!["scope-enum", "scope-enum"]
+    )]
+    #[case::disallowed_scope_case(RuleConfig { scope_enum: Some(vec!["parser".into()]), ..RuleConfig::default() }, "feat(Parser): add lexer", vec!["scope-enum"])]
+    #[case::no_scope_with_scope_enum(RuleConfig { scope_enum: Some(vec!["parser".into()]), ..RuleConfig::default() }, "feat: add lexer", vec![])]
+    #[case::header_at_max_length(RuleConfig { header_max_length: Some(15), ..RuleConfig::default() }, "feat: add lexer", vec![])]
+    #[case::header_too_long(RuleConfig { header_max_length: Some(14), ..RuleConfig::default() }, "feat: add lexer", vec!["header-max-length"])]
+    #[case::body_lines_too_long(
+        RuleConfig { body_max_line_length: Some(10), ..RuleConfig::default() },
+        "feat: add lexer\n\nSplits the header\ninto\ntokens of the header",
+        vec!["body-max-line-length", "body-max-line-length"]
+    )]
+    #[case::trailer_exists(RuleConfig { trailer_exists: vec!["Refs".into()], ..RuleConfig::default() }, "feat: add lexer\n\nrefs: PROJ-1", vec![])]
+    #[case::missing_footers(
+        RuleConfig { trailer_exists: vec!["Refs".into(), "Signed-off-by".into()], ..RuleConfig::default() },
+        "feat: add lexer\n\nRefs: PROJ-1",
+        vec!["trailer-exists"]
+    )]
+    fn test_checks_rule_config(#[case] config: RuleConfig, #[case] message: &str, #[case] expect: Vec<&str>) {
+        let rules = commit(message)
+            .check(&config)
+            .err()
+            .unwrap_or_else(Errors::new)
+            .iter()
+            .map(ValidationError::rule)
+            .collect::<Vec<_>>();
+        assert_eq!(expect, rules);
+    }
+
+    #[test]
+    fn test_reports_missing_footer() {
+        let config = RuleConfig {
+            trailer_exists: vec!["Signed-off-by".into()],
+            ..RuleConfig::default()
+        };
+
+        assert_eq!(
+            Err(multi_error!(ValidationError::RuleViolation {
+                rule: "trailer-exists".into(),
+                message: "footers must include a 'Signed-off-by' footer".into()
+            })),
+            commit("feat: add lexer").check(&config)
+        );
+    }
+
+    #[rstest]
+    #[case::not_breaking("feat: add lexer", false)]
+    #[case::marker("feat!: add lexer", true)]
+    #[case::space_footer("feat: add lexer\n\nBREAKING CHANGE: drops v1", true)]
+    #[case::hyphen_footer("feat: add lexer\n\nBREAKING-CHANGE: drops v1", true)]
+    fn test_detects_breaking_change(#[case] message: &str, #[case] expect: bool) {
+        assert_eq!(expect, commit(message).is_breaking());
+    }
+
+    #[rstest]
+    #[case::not_breaking("feat: add lexer", false, None)]
+    #[case::marker_only("feat!: drop v1", true, None)]
+    #[case::space_footer("feat: drop v1\n\nBREAKING CHANGE: the v1 endpoints are gone", true, Some("the v1 endpoints are gone"))]
+    #[case::hyphen_footer("feat!: drop v1\n\nRefs: PROJ-1\nBREAKING-CHANGE: the v1 endpoints are gone", true, Some("the v1 endpoints are gone"))]
+    #[case::first_of_several(
+        "feat!: drop v1\n\nBREAKING CHANGE: the v1 endpoints are gone\nBREAKING CHANGE: so is the v1 client",
+        true,
+        Some("the v1 endpoints are gone")
+    )]
+    #[case::other_footers("feat!: drop v1\n\nRefs: PROJ-1", true, None)]
+    #[case::two_lines(
+        "feat: drop v1\n\nBREAKING CHANGE: the v1 endpoints are gone,\nuse the v2 ones instead\nRefs: PROJ-1",
+        true,
+        Some("the v1 endpoints are gone,\nuse the v2 ones instead")
+    )]
+    fn test_returns_breaking_change_description(#[case] message: &str, #[case] breaking: bool, #[case] expect: Option<&str>) {
+        let commit = commit(message);
+
+        assert_eq!(expect, commit.breaking_change_description());
+        assert_eq!(breaking, commit.is_breaking());
+    }
+
+    #[rstest]
+    #[case::marker_without_description("feat!: drop v1", Err(multi_error!(ValidationError::MissingRequiredField("breaking change".into()))))]
+    #[case::marker_with_space_footer("feat!: drop v1\n\nBREAKING CHANGE: the v1 endpoints are gone", Ok(()))]
+    #[case::marker_with_hyphen_footer("feat!: drop v1\n\nBREAKING-CHANGE: the v1 endpoints are gone", Ok(()))]
+    #[case::marker_with_other_footers("feat!: drop v1\n\nRefs: PROJ-1", Err(multi_error!(ValidationError::MissingRequiredField("breaking change".into()))))]
+    #[case::footer_without_marker("feat: drop v1\n\nBREAKING CHANGE: the v1 endpoints are gone", Ok(()))]
+    #[case::not_breaking("feat: add lexer", Ok(()))]
+    fn test_requires_breaking_description_when_enabled(#[case] message: &str, #[case] expect: Result<(), ValidationErrors>) {
+        let options = LintOptions {
+            require_breaking_description: true,
+            ..LintOptions::default()
+        };
+        assert_eq!(expect, commit(message).validate_with(&options));
+    }
+
+    #[rstest]
+    #[case::trailing_period("feat: add lexer.", Err(multi_error!(ValidationError::RuleViolation { rule: "description-trailing-period".into(), message: "description 'add lexer.' must not end with a full stop".into() })))]
+    #[case::internal_period("feat: support v1.2 headers", Ok(()))]
+    #[case::no_period("feat: add lexer", Ok(()))]
+    fn test_forbids_trailing_period_when_enabled(#[case] message: &str, #[case] expect: Result<(), ValidationErrors>) {
+        let options = LintOptions {
+            forbid_trailing_period: true,
+            ..LintOptions::default()
+        };
+        assert_eq!(expect, commit(message).validate_with(&options));
+    }
+
+    #[rstest]
+    #[case::optional_with_scope(ScopePolicy::Optional, "feat(parser): add lexer", Ok(()))]
+    #[case::optional_without_scope(ScopePolicy::Optional, "feat: add lexer", Ok(()))]
+    #[case::forbidden_with_scope(
+        ScopePolicy::Forbidden,
+        "feat(parser): add lexer",
+        Err(multi_error!(ValidationError::RuleViolation { rule: "scope-forbidden".into(), message: "scope 'parser' is not allowed, scopes are forbidden".into() }))
+    )]
+    #[case::forbidden_without_scope(ScopePolicy::Forbidden, "feat: add lexer", Ok(()))]
+    #[case::required_with_scope(ScopePolicy::Required, "feat(parser): add lexer", Ok(()))]
+    #[case::required_without_scope(ScopePolicy::Required, "feat: add lexer", Err(multi_error!(ValidationError::MissingRequiredField("scope".into()))))]
+    fn test_enforces_scope_policy(#[case] scope_policy: ScopePolicy, #[case] message: &str, #[case] expect: Result<(), ValidationErrors>) {
+        let options = LintOptions {
+            scope_policy,
+            ..LintOptions::default()
+        };
+        assert_eq!(expect, commit(message).validate_with(&options));
+    }
+
+    #[rstest]
+    #[case::already_normal("feat(parser): add lexer\n\nBody.\n\nRefs: PROJ-1", "feat(parser): add lexer\n\nBody.\n\nRefs: PROJ-1")]
+    #[case::trailing_period("feat: add lexer.", "feat: add lexer")]
+    #[case::trailing_ellipsis("feat: add lexer ...", "feat: add lexer")]
+    #[case::only_periods("feat: ...", "feat: ...")]
+    #[case::uppercase_type("FIX: handle tabs", "fix: handle tabs")]
+    #[case::uppercase_custom_type("WIP!: try things.", "wip!: try things")]
+    fn test_normalizes_commit(#[case] message: &str, #[case] expect: &str) {
+        assert_eq!(expect, commit(message).normalized().to_string());
+    }
+
+    #[rstest]
+    #[case::curly_quotes("fix: handle \u{201C}quoted\u{201D} and \u{2018}single\u{2019} names", "fix: handle \"quoted\" and 'single' names")]
+    #[case::dashes("feat: add lexer \u{2013} first pass \u{2014} no errors", "feat: add lexer - first pass - no errors")]
+    #[case::body_untouched("fix: don\u{2019}t crash\n\nIt\u{2019}s fixed.", "fix: don't crash\n\nIt\u{2019}s fixed.")]
+    fn test_normalizes_smart_punctuation_when_enabled(#[case] message: &str, #[case] expect: &str) {
+        let options = NormalizeOptions { ascii_punctuation: true };
+        assert_eq!(expect, commit(message).normalized_with(&options).to_string());
+    }
+
+    fn footer(line: &str) -> Footer {
+        line.parse().expect("should have parsed the footer")
+    }
+
+    #[test]
+    fn test_appends_footer_last() {
+        let commit = commit("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1").with_footer(footer("Reviewed-by: Alice"));
+
+        assert_eq!("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1\nReviewed-by: Alice", commit.to_string());
+    }
+
+    #[rstest]
+    #[case::every_match("feat: add lexer\n\nRefs: PROJ-1\nReviewed-by: Alice\nrefs: PROJ-2", "Refs", "feat: add lexer\n\nReviewed-by: Alice")]
+    #[case::last_footer("feat: add lexer\n\nBody.\n\nRefs: PROJ-1", "Refs", "feat: add lexer\n\nBody.")]
+    #[case::no_match("feat: add lexer\n\nRefs: PROJ-1", "Closes", "feat: add lexer\n\nRefs: PROJ-1")]
+    fn test_removes_footers_by_token(#[case] message: &str, #[case] token: &str, #[case] expect: &str) {
+        assert_eq!(expect, commit(message).without_footer(token).to_string());
+    }
+
+    #[rstest]
+    #[case::shuffled(
+        "feat!: add lexer\n\nSigned-off-by: Alice\nRefs: PROJ-1\nPR-URL: #4\nBREAKING CHANGE: drops tabs\nco-authored-by: Bob\nCloses #2",
+        "feat!: add lexer\n\nBREAKING CHANGE: drops tabs\nRefs: PROJ-1\nCloses #2\nPR-URL: #4\nSigned-off-by: Alice\nco-authored-by: Bob"
+    )]
+    #[case::already_ordered("fix: handle tabs\n\nFixes #1\nReviewed-by: Alice", "fix: handle tabs\n\nFixes #1\nReviewed-by: Alice")]
+    #[case::no_footers("fix: handle tabs", "fix: handle tabs")]
+    fn test_normalizes_footer_order(#[case] message: &str, #[case] expect: &str) {
+        assert_eq!(expect, commit(message).normalize_footers().to_string());
+    }
+
+    #[rstest]
+    #[case::in_place("feat: add lexer\n\nRefs: PROJ-1\nReviewed-by: Alice", "Refs", "PROJ-2", "feat: add lexer\n\nRefs: PROJ-2\nReviewed-by: Alice")]
+    #[case::keeps_separator("fix: handle tabs\n\nCloses #1", "closes", "#2", "fix: handle tabs\n\nCloses #2")]
+    #[case::no_match("feat: add lexer\n\nRefs: PROJ-1", "Closes", "#2", "feat: add lexer\n\nRefs: PROJ-1")]
+    fn test_replaces_footer_value(#[case] message: &str, #[case] token: &str, #[case] value: &str, #[case] expect: &str) {
+        let commit = commit(message).replace_footer(token, value).expect("should have replaced the footer");
+        assert_eq!(expect, commit.to_string());
+    }
+
+    #[test]
+    fn test_rejects_invalid_replacement_value() {
+        let errs = commit("fix: handle tabs\n\nCloses #1").replace_footer("Closes", "PROJ-2").expect_err("should have failed");
+
+        assert_eq!(
+            multi_error!(ValidationError::InvalidFieldValue(
+                "value".into(),
+                anyhow!("'PROJ-2' must start with '#' to be separated from the token by ' #'")
+            )),
+            errs
+        );
+    }
+
+    #[test]
+    fn test_keeps_smart_punctuation_by_default() {
+        assert_eq!("fix: don\u{2019}t crash", commit("fix: don\u{2019}t crash").normalized().to_string());
+    }
+
+    #[rstest]
+    #[case::past_the_cap(Some(1), 1, 1)]
+    #[case::at_the_cap(Some(2), 2, 0)]
+    #[case::unlimited(None, 2, 0)]
+    fn test_caps_reported_errors(#[case] max_errors: Option<usize>, #[case] reported: usize, #[case] omitted: usize) {
+        let options = LintOptions {
+            require_breaking_description: true,
+            forbid_trailing_period: true,
+            max_errors,
+            ..LintOptions::default()
+        };
+
+        let errs = commit("feat!: drop v1.").validate_with(&options).expect_err("should have failed");
+
+        assert_eq!(reported, errs.len());
+        assert_eq!(omitted, errs.omitted());
+    }
+
+    #[test]
+    fn test_accepts_undescribed_breaking_marker_by_default() {
+        assert_eq!(Ok(()), commit("feat!: drop v1").validate_with(&LintOptions::default()));
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer")]
+    #[case::scope_and_marker("feat(api)!: drop v1")]
+    #[case::body_and_footers("fix: handle tabs\n\nTabs were ignored.\n\nRefs: PROJ-1\nBREAKING-CHANGE: tabs are significant")]
+    #[case::hash_footers("fix: handle tabs\n\nCloses #42\nReviewed-by: a@b.io")]
+    fn test_displays_parsed_commit_unchanged(#[case] message: &str) {
+        assert_eq!(message, format!("{}", commit(message)));
+    }
+
+    #[rstest]
+    #[case::feat("feat: add lexer", VersionBump::Minor)]
+    #[case::fix("fix(parser): handle tabs", VersionBump::Patch)]
+    #[case::docs("docs: explain footers", VersionBump::None)]
+    #[case::custom("wip: try things", VersionBump::None)]
+    #[case::breaking_marker("fix!: reject tabs", VersionBump::Major)]
+    #[case::breaking_footer("docs: drop v1 guide\n\nBREAKING CHANGE: v1 is no longer documented", VersionBump::Major)]
+    #[case::hyphenated_breaking_footer("feat: add lexer\n\nBREAKING-CHANGE: tokens changed", VersionBump::Major)]
+    fn test_returns_version_bump(#[case] message: &str, #[case] expect: VersionBump) {
+        assert_eq!(expect, commit(message).version_bump());
+    }
+
+    #[rstest]
+    #[case::interleaved_comments(
+        "feat: add lexer\n# Please enter the commit message\n\nSplits the header.\n# Lines starting with '#' are ignored\nKeeps tabs.\n\nRefs: PROJ-1\n",
+        None,
+        "feat: add lexer\n\nSplits the header.\nKeeps tabs.\n\nRefs: PROJ-1\n"
+    )]
+    #[case::custom_comment_char(
+        "feat: add lexer\n; Please enter the commit message\n\n#1 is fixed.\n; Lines starting with ';' are ignored\n",
+        Some(';'),
+        "feat: add lexer\n\n#1 is fixed.\n"
+    )]
+    #[case::blank_lines("\n\nfeat: add lexer  \n\n\n\nSplits the header.\n\n\n", None, "feat: add lexer\n\nSplits the header.\n")]
+    #[case::scissors(
+        "feat: add lexer\n\n# ------------------------ >8 ------------------------\n# Do not modify or remove the line above.\ndiff --git a/lib.rs b/lib.rs\n",
+        None,
+        "feat: add lexer\n"
+    )]
+    #[case::custom_scissors("feat: add lexer\n; ------------------------ >8 ------------------------\ndiff\n", Some(';'), "feat: add lexer\n")]
+    #[case::only_comments("\n# Please enter the commit message\n", None, "")]
+    fn test_cleans_git_message(#[case] message: &str, #[case] comment_char: Option<char>, #[case] expect: &str) {
+        assert_eq!(expect, Commit::clean_git_message(message, comment_char));
+    }
+
+    #[test]
+    fn test_parses_git_message_with_custom_comment_char() {
+        let message = "fix: handle tabs\n; Please enter the commit message\n\n# is no longer a comment.\n; Lines starting with ';' are ignored\n\nRefs: PROJ-1\n\n";
+
+        let commit = Commit::parse_git_message(message, Some(';')).expect("should have parsed the message");
+
+        assert_eq!(Some("# is no longer a comment."), commit.body());
+        assert_eq!("fix: handle tabs\n\n# is no longer a comment.\n\nRefs: PROJ-1", commit.to_string());
+    }
+
+    #[rstest]
+    #[case::pr_url("feat: add lexer\n\nPR-URL: https://github.com/org/repo/pull/42", Some("https://github.com/org/repo/pull/42"))]
+    #[case::pull_request("feat: add lexer\n\nRefs: PROJ-1\nPull-request: #42", Some("#42"))]
+    #[case::case_insensitive("feat: add lexer\n\npr-url: https://github.com/org/repo/pull/42", Some("https://github.com/org/repo/pull/42"))]
+    #[case::first_wins("feat: add lexer\n\nPR-URL: #1\nPR-URL: #2", Some("#1"))]
+    #[case::absent("feat: add lexer\n\nRefs: PROJ-1", None)]
+    #[case::no_footers("feat: add lexer", None)]
+    fn test_returns_pull_request(#[case] message: &str, #[case] expect: Option<&str>) {
+        assert_eq!(expect, commit(message).pull_request());
+    }
+
+    #[rstest]
+    #[case::one("feat: add lexer\n\nCloses #12\nReviewed-by: Alice", "Closes", vec!["Closes #12"])]
+    #[case::case_insensitive("feat: add lexer\n\ncloses #12\nCLOSES: #13", "Closes", vec!["closes #12", "CLOSES: #13"])]
+    #[case::other_token("feat: add lexer\n\nFixes #12", "Closes", vec![])]
+    #[case::no_footers("feat: add lexer", "Closes", vec![])]
+    fn test_returns_footers_by_token(#[case] message: &str, #[case] token: &str, #[case] expect: Vec<&str>) {
+        let commit = commit(message);
+        assert_eq!(expect, commit.footers_by_token(token).map(ToString::to_string).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::same_case("Co-authored-by", vec!["Co-authored-by: Bob"])]
+    #[case::mixed_case("co-AUTHORED-by", vec![])]
+    #[case::title_case("Co-Authored-By", vec!["Co-Authored-By: Alice"])]
+    fn test_returns_footers_by_exact_token(#[case] token: &str, #[case] expect: Vec<&str>) {
+        let commit = commit("feat: add lexer\n\nCo-Authored-By: Alice\nCo-authored-by: Bob");
+        assert_eq!(expect, commit.footers_by_token_exact(token).map(ToString::to_string).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::hash_separator("fix: handle tabs\n\nCloses #12", vec!["12"])]
+    #[case::colon_separator("fix: handle tabs\n\nRefs: PROJ-1", vec!["PROJ-1"])]
+    #[case::every_keyword(
+        "fix: handle tabs\n\nClose #1\nClosed #2\nFix #3\nFixed #4\nFixes #5\nResolve #6\nResolved #7\nResolves #8",
+        vec!["1", "2", "3", "4", "5", "6", "7", "8"]
+    )]
+    #[case::case_insensitive("fix: handle tabs\n\nfixes #12\nrefs: PROJ-1", vec!["12", "PROJ-1"])]
+    #[case::several_per_footer("fix: handle tabs\n\nCloses: #12, #13 #14", vec!["12", "13", "14"])]
+    #[case::repeated("fix: handle tabs\n\nFixes #12\nRefs: #12, PROJ-1", vec!["12", "PROJ-1"])]
+    #[case::other_footers("fix: handle tabs\n\nReviewed-by: Alice\nPR-URL: #42", vec![])]
+    #[case::no_footers("fix: handle tabs", vec![])]
+    fn test_returns_issue_references(#[case] message: &str, #[case] expect: Vec<&str>) {
+        assert_eq!(expect, commit(message).issue_references());
+    }
+
+    #[rstest]
+    #[case::valid("feat(parser): add lexer\n\nSplits the header.\n\nRefs: PROJ-1", "feat(parser): add lexer\n\nSplits the header.\n\nRefs: PROJ-1", vec![])]
+    #[case::unknown_type("wip: try things", "wip: try things", vec!["type-invalid"])]
+    #[case::no_colon("Update the README", "chore: Update the README", vec!["header-format"])]
+    #[case::misplaced_marker("feat!(api): drop v1", "chore: feat!(api): drop v1", vec!["header-breaking-marker"])]
+    #[case::long_description(&format!("feat: {}", "a".repeat(80)), &format!("feat: {}", "a".repeat(80)), vec!["description-max-length"])]
+    #[case::missing_blank_line("feat: add lexer\nSplits the header.", "feat: add lexer\n\nSplits the header.", vec!["body-invalid"])]
+    #[case::invalid_footer("feat: add lexer\n\nRefs: PROJ-1\nBreaking Change: drops v1", "feat: add lexer\n\nBreaking Change: drops v1\n\nRefs: PROJ-1", vec!["token-invalid"])]
+    fn test_parses_leniently(#[case] message: &str, #[case] expect: &str, #[case] rules: Vec<&str>) {
+        let (commit, warnings) = Commit::parse_lenient(message).expect("should have parsed the commit");
+
+        assert_eq!(expect, commit.to_string());
+        assert_eq!(rules, warnings.iter().map(ValidationError::rule).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_moves_only_unparsable_footers_to_body_when_parsing_leniently() {
+        let (commit, _) = Commit::parse_lenient("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1\nBreaking Change: drops v1").expect("should have parsed the commit");
+
+        assert_eq!(Some("Splits the header.\n\nBreaking Change: drops v1"), commit.body());
+        assert_eq!(vec!["Refs: PROJ-1"], commit.footers().iter().map(ToString::to_string).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::blank_header("  \n\nSplits the header.")]
+    fn test_fails_to_parse_leniently_without_header(#[case] message: &str) {
+        assert_eq!(
+            multi_error!(ValidationError::MissingRequiredField("header".into())),
+            validation_errors(Commit::parse_lenient(message))
+        );
+    }
+
+    #[rstest]
+    #[case::abbreviated_sha("feat(parser): add lexer", "676104e", "revert: feat(parser): add lexer\n\nThis reverts commit 676104e.\n\nRefs: 676104e")]
+    #[case::full_sha(
+        "fix!: drop v1",
+        "676104e0f5b5a7e2c1d9c3b4a5f6e7d8c9b0a1f2",
+        "revert: fix!: drop v1\n\nThis reverts commit 676104e0f5b5a7e2c1d9c3b4a5f6e7d8c9b0a1f2.\n\nRefs: 676104e0f5b5a7e2c1d9c3b4a5f6e7d8c9b0a1f2"
+    )]
+    #[case::drops_body_and_footers(
+        "feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1",
+        "a215868",
+        "revert: feat: add lexer\n\nThis reverts commit a215868.\n\nRefs: a215868"
+    )]
+    fn test_reverts_commit(#[case] message: &str, #[case] sha: &str, #[case] expect: &str) {
+        let revert = Commit::revert(&commit(message), sha).expect("should have created the revert commit");
+
+        assert_eq!(CommitType::Revert, *revert.commit_type());
+        assert_eq!(expect, revert.to_string());
+    }
+
+    #[test]
+    fn test_round_trips_revert_of_long_header() {
+        let original = commit(&format!("feat(parser): {}", "a".repeat(Description::DEFAULT_MAX_LENGTH)));
+        let revert = Commit::revert(&original, "676104e").expect("should have created the revert commit");
+
+        assert_eq!(
+            Description::DEFAULT_MAX_LENGTH + "revert: feat(parser): ".len() - "revert: ".len(),
+            revert.description().char_count()
+        );
+        assert!(Commit::parse(&revert.to_string()).is_err(), "should have exceeded the default description limit");
+        assert_eq!(revert, Commit::parse_structure(&revert.to_string()).expect("should have parsed the revert commit"));
+    }
+
+    #[rstest]
+    #[case::body("revert: feat: add lexer\n\nThis reverts commit 676104e.", vec!["676104e"])]
+    #[case::footer("revert: feat: add lexer\n\nRefs: 676104e, a215868", vec!["676104e", "a215868"])]
+    #[case::body_and_footer("revert: feat: add lexer\n\nThis reverts commit 676104e.\n\nRefs: 676104e", vec!["676104e"])]
+    #[case::ticket_footer("revert: feat: add lexer\n\nRefs: PROJ-1", vec![])]
+    #[case::not_a_revert("feat: add lexer\n\nThis reverts commit 676104e.", vec![])]
+    #[case::no_reference("revert: feat: add lexer", vec![])]
+    fn test_returns_reverted_shas(#[case] message: &str, #[case] expect: Vec<&str>) {
+        assert_eq!(expect, commit(message).reverted_shas());
+    }
+
+    #[rstest]
+    #[case::too_short("676104")]
+    #[case::too_long(&"a".repeat(41))]
+    #[case::not_hexadecimal("676104g")]
+    #[case::empty("")]
+    fn test_rejects_invalid_revert_sha(#[case] sha: &str) {
+        assert_eq!(
+            multi_error!(ValidationError::InvalidFieldValue("sha".into(), anyhow!("'{sha}' must be 7 to 40 hexadecimal characters"))),
+            Commit::revert(&commit("feat: add lexer"), sha).expect_err("should have failed")
+        );
+    }
+
+    #[rstest]
+    #[case::release_scope("chore(release): 1.2.0", Some("1.2.0"))]
+    #[case::release_word("chore: release 1.2.0", Some("1.2.0"))]
+    #[case::v_prefix("chore(release): v1.2.0", Some("1.2.0"))]
+    #[case::release_scope_and_word("chore(release): release 1.2.0", Some("1.2.0"))]
+    #[case::pre_release("chore(release): 2.0.0-rc.1", Some("2.0.0-rc.1"))]
+    #[case::build_metadata("chore(release): 2.0.0+build.5", Some("2.0.0+build.5"))]
+    #[case::normal_chore("chore: bump dependencies", None)]
+    #[case::other_scope("chore(deps): 1.2.0", None)]
+    #[case::not_a_version("chore(release): prepare notes", None)]
+    #[case::partial_version("chore(release): 1.2", None)]
+    #[case::empty_suffix("chore(release): 1.2.0-", None)]
+    #[case::other_type("feat(release): 1.2.0", None)]
+    fn test_returns_release_version(#[case] message: &str, #[case] expect: Option<&str>) {
+        assert_eq!(expect, commit(message).release_version());
+    }
+
+    #[rstest]
+    #[case::short_body("fix: handle tabs\n\nTabs were ignored.", 20, "fix: handle tabs\n\nTabs were ignored.")]
+    #[case::long_body_line(
+        "fix: handle tabs\n\nTabs were ignored by the lexer when reading headers.",
+        20,
+        "fix: handle tabs\n\nTabs were ignored by\nthe lexer when\nreading headers."
+    )]
+    #[case::paragraphs_kept(
+        "fix: handle tabs\n\nTabs were ignored by the lexer.\n\nThey are now tokens.",
+        20,
+        "fix: handle tabs\n\nTabs were ignored by\nthe lexer.\n\nThey are now tokens."
+    )]
+    #[case::long_url_not_broken(
+        "docs: link spec\n\nSee https://www.conventionalcommits.org/en/v1.0.0/ for details.",
+        20,
+        "docs: link spec\n\nSee\nhttps://www.conventionalcommits.org/en/v1.0.0/\nfor details."
+    )]
+    #[case::indented_line_not_wrapped(
+        "docs: add sample\n\n    let commit = Commit::parse(message).expect(\"valid\");",
+        20,
+        "docs: add sample\n\n    let commit = Commit::parse(message).expect(\"valid\");"
+    )]
+    #[case::header_and_footers_not_wrapped(
+        "feat(parser): add a lexer for the header\n\nSplits the header into tokens.\n\nReviewed-by: Alice Bob <alice.bob@test.io>",
+        20,
+        "feat(parser): add a lexer for the header\n\nSplits the header\ninto tokens.\n\nReviewed-by: Alice Bob <alice.bob@test.io>"
+    )]
+    #[case::long_trailer_value_not_wrapped(
+        "fix: handle tabs\n\nTabs were ignored by the lexer.\n\nRefs: https://github.com/HibiscusCollective/git-toolkit/issues/42",
+        20,
+        "fix: handle tabs\n\nTabs were ignored by\nthe lexer.\n\nRefs: https://github.com/HibiscusCollective/git-toolkit/issues/42"
+    )]
+    fn test_renders_wrapped_body(#[case] message: &str, #[case] width: usize, #[case] expect: &str) {
+        assert_eq!(expect, commit(message).render_wrapped(width));
+    }
+
+    #[test]
+    fn test_renders_wrapped_body_without_breaking_trailers() {
+        let trailers = "Refs: https://github.com/HibiscusCollective/git-toolkit/issues/42\nBreaking Change: drops the v1 api";
+        let commit = Commit::builder(CommitType::Feat, Description::parse("add lexer").expect("should have parsed the description"))
+            .body(format!("Splits the header into tokens.\n\n{trailers}"))
+            .build()
+            .expect("should have built the commit");
+
+        assert_eq!(format!("feat: add lexer\n\nSplits the header\ninto tokens.\n\n{trailers}"), commit.render_wrapped(20));
+    }
+
+    #[rstest]
+    #[case::fixup("fixup! feat: add lexer", CommitKind::Fixup("feat: add lexer".into()))]
+    #[case::squash("squash! fix: handle tabs\n\nAlso handle CRLF.", CommitKind::Squash("fix: handle tabs".into()))]
+    #[case::amend("amend! feat: add lexer\n\nfeat: add a lexer", CommitKind::Amend("feat: add lexer".into()))]
+    #[case::non_conventional_target("fixup! Update README", CommitKind::Fixup("Update README".into()))]
+    #[case::nested("fixup! fixup! feat: add lexer", CommitKind::Fixup("fixup! feat: add lexer".into()))]
+    #[case::conventional("feat: add lexer", CommitKind::Conventional(commit("feat: add lexer")))]
+    fn test_parses_commit_kind(#[case] message: &str, #[case] expect: CommitKind) {
+        assert_eq!(expect, Commit::parse_kind(message).expect("should have parsed the commit kind"));
+    }
+
+    #[rstest]
+    #[case::not_conventional("Update README")]
+    #[case::prefix_without_space("fixup!feat: add lexer")]
+    #[case::capitalised_prefix("Fixup! feat: add lexer")]
+    fn test_rejects_invalid_commit_kind(#[case] message: &str) {
+        assert_eq!(validation_errors(Commit::parse(message)), validation_errors(Commit::parse_kind(message)));
+    }
+
+    #[rstest]
+    #[case::type_only("feat: add lexer", "feat: add lexer")]
+    #[case::scope_and_marker("feat(parser)!: add lexer", "feat(parser)!: add lexer")]
+    #[case::body_and_footers("fix: handle tabs\n\nTabs were ignored.\n\nRefs: PROJ-1", "fix: handle tabs")]
+    fn test_returns_subject(#[case] message: &str, #[case] expect: &str) {
+        let commit = commit(message);
+
+        assert_eq!(expect, commit.subject());
+        assert_eq!(commit.subject(), commit.header());
+    }
+
+    #[rstest]
+    #[case::header_only("feat: add lexer", 9, 0, 0)]
+    #[case::scope_and_marker("feat(parser)!: add lexer", 9, 0, 0)]
+    #[case::multibyte("fix: gérer les tabulations", 21, 0, 0)]
+    #[case::body_and_footers("fix: handle tabs\n\nTabs were\nignored.\n\nRefs: PROJ-1\nReviewed-by: Alice", 11, 3, 2)]
+    fn test_counts_metrics(#[case] message: &str, #[case] chars: usize, #[case] words: usize, #[case] footers: usize) {
+        let commit = commit(message);
+
+        assert_eq!(chars, commit.char_count());
+        assert_eq!(words, commit.word_count());
+        assert_eq!(footers, commit.footer_count());
+    }
+
+    #[test]
+    fn test_display_does_not_wrap() {
+        let message = "fix: handle tabs\n\nTabs were ignored by the lexer when reading headers.";
+        assert_eq!(message, format!("{}", commit(message)));
+    }
+
+    #[test]
+    fn test_builds_commit_with_chosen_breaking_change_token() {
+        let commit = Commit::builder(CommitType::Feat, Description::parse("drop v1").expect("should have parsed the description"))
+            .footer(
+                Footer::breaking_change("v1 endpoints are gone")
+                    .token(BreakingChangeToken::Hyphen)
+                    .build()
+                    .expect("should have built a footer"),
+            )
+            .build()
+            .expect("should have built a commit");
+
+        assert!(commit.is_breaking());
+        assert_eq!("feat: drop v1\n\nBREAKING-CHANGE: v1 endpoints are gone", format!("{commit}"));
+    }
+
+    /// Checks that a message never makes the parser panic, and that a successful parse renders a message that parses to
+    /// the same rendering again.
+    fn assert_parses_without_panicking(message: &str) {
+        if let Ok(commit) = Commit::parse(message) {
+            let rendered = format!("{commit}");
+            let reparsed = Commit::parse(&rendered).unwrap_or_else(|e| panic!("rendered commit {rendered:?} should parse again: {e}"));
+            assert_eq!(rendered, format!("{reparsed}"));
+        }
+    }
+
+    #[rstest]
+    #[case::only_colon(":")]
+    #[case::colon_and_space(": ")]
+    #[case::only_marker("!: x")]
+    #[case::only_parentheses("(): x")]
+    #[case::only_line_breaks("\n\n\n")]
+    #[case::embedded_nul("feat: a\0b\n\n\0\n\nRefs: \0")]
+    #[case::nul_type("fe\0at: x")]
+    #[case::unicode("feat(日本): ajouter l'analyseur 🎉\n\nCorps\u{2028}texte\n\nRéviseur: Zoë")]
+    #[case::crlf("feat: add lexer\r\n\r\nbody\r\n\r\nRefs: PROJ-1\r\n")]
+    #[case::carriage_return_only("feat: a\rb")]
+    #[case::megabyte_description(&format!("feat: {}", "a".repeat(1024 * 1024)))]
+    #[case::megabyte_of_colons(&":".repeat(1024 * 1024))]
+    #[case::megabyte_body_line(&format!("feat: add lexer\n\n{}", "word ".repeat(200 * 1024)))]
+    fn test_parses_unusual_input_without_panicking(#[case] message: &str) {
+        assert_parses_without_panicking(message);
+    }
+
+    /// Generates the types of the specification, and custom ones.
+    fn arb_commit_type() -> impl Strategy<Value = CommitType> {
+        prop_oneof![
+            proptest::sample::select(KNOWN_TYPES.to_vec()),
+            "[a-z][a-z0-9_-]{0,8}".prop_map(|t| t.parse().expect("should have parsed the type")),
+        ]
+    }
+
+    /// Generates a scope, or a comma-separated list of scopes.
+    fn arb_scope() -> impl Strategy<Value = Scope> {
+        proptest::collection::vec("[a-z][a-z0-9-]{0,8}", 1..3).prop_map(|scopes| scopes.join(",").parse().expect("should have parsed the scope"))
+    }
+
+    /// Generates a description within the default length limit.
+    fn arb_description() -> impl Strategy<Value = Description> {
+        "[a-zA-Z0-9]([a-zA-Z0-9 ,.'-]{0,60}[a-zA-Z0-9.])?".prop_map(|d| Description::parse(&d).expect("should have parsed the description"))
+    }
+
+    /// Generates a body of paragraphs whose lines never read as footers.
+    fn arb_body() -> impl Strategy<Value = String> {
+        let line = "[a-zA-Z]([a-zA-Z0-9 ,.()-]{0,30}[a-zA-Z0-9.)])?";
+        let paragraph = proptest::collection::vec(line, 1..3).prop_map(|lines| lines.join("\n"));
+        proptest::collection::vec(paragraph, 1..3).prop_map(|paragraphs| paragraphs.join("\n\n"))
+    }
+
+    /// Generates a footer, with either separator and a value that may continue on further lines.
+    fn arb_footer() -> impl Strategy<Value = Footer> {
+        let token = "Refs|Closes|Reviewed-by|BREAKING CHANGE|BREAKING-CHANGE|[A-Z][a-z]{1,8}(-[a-z]{1,6})?";
+        let value = prop_oneof![
+            (
+                "[a-zA-Z0-9]([a-zA-Z0-9 @.<>]{0,20}[a-zA-Z0-9>])?",
+                proptest::collection::vec("[a-z]([a-z ]{0,20}[a-z])?", 0..2)
+            )
+                .prop_map(|(first, rest)| [vec![first], rest].concat().join("\n")),
+            "#[0-9]{1,4}",
+        ];
+
+        (token, value).prop_map(|(token, value)| Footer::builder(token).value(value).build().expect("should have built the footer"))
+    }
+
+    prop_compose! {
+        /// Generates a valid commit, from its components.
+        fn arb_commit()(
+            commit_type in arb_commit_type(),
+            scope in proptest::option::of(arb_scope()),
+            breaking in any::<bool>(),
+            description in arb_description(),
+            body in proptest::option::of(arb_body()),
+            footers in proptest::collection::vec(arb_footer(), 0..4),
+        ) -> Commit {
+            let mut builder = Commit::builder(commit_type, description);
+            builder.breaking(breaking);
+            if let Some(scope) = scope {
+                builder.scope(scope);
+            }
+            if let Some(body) = body {
+                builder.body(body);
+            }
+            for footer in footers {
+                builder.footer(footer);
+            }
+
+            builder.build().expect("should have built the commit")
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parse_never_panics_on_arbitrary_input(message in any::<String>()) {
+            assert_parses_without_panicking(&message);
+        }
+
+        #[test]
+        fn prop_parse_never_panics_on_commit_like_input(message in "[a-zA-Z!():#\\- \n\r\t\x00é日]{0,64}") {
+            assert_parses_without_panicking(&message);
+        }
+
+        #[test]
+        fn prop_is_conventional_agrees_with_parse_on_headers(header in "[a-zA-Z!():,\\- \r\t\x00é日]{0,64}") {
+            prop_assert_eq!(Commit::parse(&header).is_ok(), Commit::is_conventional(&header));
+        }
+
+        #[test]
+        fn prop_is_conventional_agrees_with_parse_around_description_limit(header in "(feat|fix)(\\([a-z]{1,8}\\))?!?: [a-zé日][a-z é日]{60,80}") {
+            prop_assert_eq!(Commit::parse(&header).is_ok(), Commit::is_conventional(&header));
+        }
+
+        #[test]
+        fn prop_parse_round_trips_generated_messages(
+            header in "(feat|fix|docs|wip)(\\([a-z]{1,8}\\))?!?: [a-z][a-z ]{0,20}[a-z]",
+            body in proptest::option::of("[a-zA-Z:#\\- .]{1,40}(\n{1,2}[a-zA-Z:#\\- .]{1,40}){0,3}"),
+            footers in proptest::collection::vec("(Refs|Reviewed-by|BREAKING CHANGE|BREAKING-CHANGE): [a-zA-Z#@. -]{0,10}[a-z]", 0..4),
+        ) {
+            let mut message = header;
+            if let Some(body) = body {
+                message = format!("{message}\n\n{body}");
+            }
+            if !footers.is_empty() {
+                message = format!("{message}\n\n{}", footers.join("\n"));
+            }
+
+            assert_parses_without_panicking(&message);
+        }
+
+        #[test]
+        fn prop_display_round_trips_through_parse(commit in arb_commit()) {
+            let message = commit.to_string();
+            prop_assert_eq!(Ok(commit), Commit::parse(&message).map_err(|e| e.to_string()), "message: {:?}", message);
+        }
+
+        #[test]
+        fn prop_normalize_footers_ignores_their_initial_order(
+            footers in Just(vec!["BREAKING CHANGE: drops tabs", "Refs: PROJ-1", "PR-URL: #4", "Signed-off-by: Alice"]).prop_shuffle(),
+        ) {
+            let commit = Commit::parse(&format!("feat!: add lexer\n\n{}", footers.join("\n"))).expect("should have parsed the commit");
+
+            prop_assert_eq!(
+                "feat!: add lexer\n\nBREAKING CHANGE: drops tabs\nRefs: PROJ-1\nPR-URL: #4\nSigned-off-by: Alice",
+                commit.normalize_footers().to_string()
+            );
+        }
+
+        #[test]
+        fn prop_subject_is_first_line_of_display(
+            header in "(feat|fix|docs|wip)(\\([a-z]{1,8}\\))?!?: [a-z][a-z ]{0,20}[a-z]",
+            body in proptest::option::of("[a-zA-Z .]{1,40}"),
+        ) {
+            let message = body.map_or_else(|| header.clone(), |body| format!("{header}\n\n{body}"));
+            let commit = Commit::parse(&message).expect("should have parsed the commit");
+
+            let rendered = commit.to_string();
+            prop_assert_eq!(rendered.lines().next().map(str::to_string), Some(commit.subject()));
+        }
+    }
+}
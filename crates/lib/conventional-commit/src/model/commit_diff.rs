@@ -0,0 +1,185 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Field by field comparisons of commits, ex: to preview how a hook rewrote a message.
+
+use crate::model::{Commit, Footer};
+use std::fmt::{Display, Formatter};
+
+/// The fields that differ between two commits, returned by [`Commit::diff`].
+///
+/// Footers are compared as a whole, token, separator and value, so a footer whose value changed is both removed and
+/// added. Footers only moved within the trailer block are not reported.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::Commit;
+/// let before = Commit::parse("feat: add lexer\n\nRefs: PROJ-1").unwrap();
+/// let after = Commit::parse("feat: add lexer\n\nRefs: PROJ-2").unwrap();
+///
+/// assert_eq!(before.diff(&after).to_string(), "- Refs: PROJ-1\n+ Refs: PROJ-2\n");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommitDiff {
+    header: Option<(String, String)>,
+    body_changed: bool,
+    removed_footers: Vec<Footer>,
+    added_footers: Vec<Footer>,
+}
+
+impl CommitDiff {
+    /// Compares the fields of a commit with the ones of the commit it became.
+    pub(crate) fn between(before: &Commit, after: &Commit) -> CommitDiff {
+        let (before_header, after_header) = (before.header(), after.header());
+
+        CommitDiff {
+            header: (before_header != after_header).then_some((before_header, after_header)),
+            body_changed: before.body() != after.body(),
+            removed_footers: missing(before.footers(), after.footers()),
+            added_footers: missing(after.footers(), before.footers()),
+        }
+    }
+
+    /// Returns whether the commits have the same fields.
+    ///
+    /// # Returns
+    /// `true` if neither the header, the body nor the footers changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == CommitDiff::default()
+    }
+
+    /// Returns the header before and after, if it changed.
+    ///
+    /// # Returns
+    /// * `Some((before, after))` with both headers, ex: `feat: add lexer` and `feat(parser): add lexer`.
+    /// * `None` if the type, scope, breaking marker and description are the same.
+    #[must_use]
+    pub fn header(&self) -> Option<(&str, &str)> {
+        self.header.as_ref().map(|(before, after)| (before.as_str(), after.as_str()))
+    }
+
+    /// Returns whether the body changed, including when it was added or removed.
+    #[must_use]
+    pub fn body_changed(&self) -> bool {
+        self.body_changed
+    }
+
+    /// Returns the footers of the first commit missing from the second one.
+    ///
+    /// # Returns
+    /// The removed footers, in the order they were written.
+    #[must_use]
+    pub fn removed_footers(&self) -> &[Footer] {
+        &self.removed_footers
+    }
+
+    /// Returns the footers of the second commit missing from the first one.
+    ///
+    /// # Returns
+    /// The added footers, in the order they were written.
+    #[must_use]
+    pub fn added_footers(&self) -> &[Footer] {
+        &self.added_footers
+    }
+}
+
+/// Returns the footers of `from` that `to` doesn't have, a footer written twice only matching one footer of `to`.
+fn missing(from: &[Footer], to: &[Footer]) -> Vec<Footer> {
+    let mut remaining: Vec<&Footer> = to.iter().collect();
+    from.iter()
+        .filter(|footer| match remaining.iter().position(|f| f == footer) {
+            Some(i) => {
+                remaining.swap_remove(i);
+                false
+            }
+            None => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Writes one line per difference: `header: 'before' -> 'after'`, `body changed`, then `- footer` for each removed
+/// footer and `+ footer` for each added one. Nothing is written when the commits have the same fields.
+impl Display for CommitDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some((before, after)) = self.header() {
+            writeln!(f, "header: '{before}' -> '{after}'")?;
+        }
+        if self.body_changed {
+            writeln!(f, "body changed")?;
+        }
+        for footer in &self.removed_footers {
+            writeln!(f, "- {footer}")?;
+        }
+        for footer in &self.added_footers {
+            writeln!(f, "+ {footer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use rstest::rstest;
+
+    fn commit(message: &str) -> Commit {
+        Commit::parse(message).expect("should have parsed the commit")
+    }
+
+    #[rstest]
+    #[case::same("feat: add lexer\n\nRefs: PROJ-1", "feat: add lexer\n\nRefs: PROJ-1")]
+    #[case::reordered_footers("feat: add lexer\n\nRefs: PROJ-1\nCloses: #2", "feat: add lexer\n\nCloses: #2\nRefs: PROJ-1")]
+    fn test_finds_no_difference(#[case] before: &str, #[case] after: &str) {
+        let diff = commit(before).diff(&commit(after));
+
+        assert!(diff.is_empty(), "unexpected difference:\n{diff}");
+        assert_eq!("", diff.to_string());
+    }
+
+    #[rstest]
+    #[case::added_footer("feat: add lexer", "feat: add lexer\n\nRefs: PROJ-1", "+ Refs: PROJ-1\n")]
+    #[case::removed_footer("feat: add lexer\n\nRefs: PROJ-1\nRefs: PROJ-1", "feat: add lexer\n\nRefs: PROJ-1", "- Refs: PROJ-1\n")]
+    #[case::changed_separator("fix: handle tabs\n\nCloses #1", "fix: handle tabs\n\nCloses: #1", "- Closes #1\n+ Closes: #1\n")]
+    #[case::body_added("feat: add lexer", "feat: add lexer\n\nTokens are read lazily.", "body changed\n")]
+    #[case::everything(
+        "feat: add lexer\n\nTokens are read eagerly.\n\nRefs: PROJ-1",
+        "feat(parser)!: add lexer\n\nTokens are read lazily.\n\nRefs: PROJ-2",
+        indoc! {"
+            header: 'feat: add lexer' -> 'feat(parser)!: add lexer'
+            body changed
+            - Refs: PROJ-1
+            + Refs: PROJ-2
+        "}
+    )]
+    fn test_describes_differences(#[case] before: &str, #[case] after: &str, #[case] expect: &str) {
+        let diff = commit(before).diff(&commit(after));
+
+        assert!(!diff.is_empty(), "should have found a difference");
+        assert_eq!(expect, diff.to_string());
+    }
+
+    #[test]
+    fn test_lists_footers_changed() {
+        let diff = commit("feat: add lexer\n\nRefs: PROJ-1\nSigned-off-by: Alice").diff(&commit("feat: add lexer\n\nSigned-off-by: Alice\nCloses: #3"));
+
+        assert_eq!(None, diff.header());
+        assert!(!diff.body_changed());
+        assert_eq!(vec!["Refs: PROJ-1"], diff.removed_footers().iter().map(ToString::to_string).collect::<Vec<_>>());
+        assert_eq!(vec!["Closes: #3"], diff.added_footers().iter().map(ToString::to_string).collect::<Vec<_>>());
+    }
+}
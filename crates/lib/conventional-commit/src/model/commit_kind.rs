@@ -0,0 +1,102 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The kinds of commit messages, telling conventional commits apart from the ones `git rebase --autosquash` folds.
+
+use crate::model::Commit;
+
+/// A commit message, either a conventional commit or one that `git rebase --autosquash` folds into an earlier commit.
+///
+/// Autosquash commits are not conventional commits, their subject is the subject of the commit they target prefixed by
+/// `fixup! `, `squash! ` or `amend! `, so they only keep that subject. Returned by [`Commit::parse_kind`].
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Commit, CommitKind};
+/// let kind = Commit::parse_kind("fixup! feat: add lexer").unwrap();
+///
+/// assert_eq!(kind, CommitKind::Fixup("feat: add lexer".into()));
+/// assert_eq!(kind.target(), Some("feat: add lexer"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommitKind {
+    /// A conventional commit.
+    Conventional(Commit),
+    /// A `fixup! <subject>` commit, whose changes are folded into the target and its message dropped.
+    Fixup(String),
+    /// A `squash! <subject>` commit, whose changes are folded into the target and its message appended to the target's.
+    Squash(String),
+    /// An `amend! <subject>` commit, whose changes are folded into the target and its message replaces the target's.
+    Amend(String),
+}
+
+impl CommitKind {
+    /// Recognises an autosquash commit from its subject, prefixed by `git commit --fixup` or `--squash`.
+    ///
+    /// # Returns
+    /// * `Some(CommitKind)` with the subject written after the prefix, if the subject starts with one.
+    /// * `None` otherwise.
+    pub(crate) fn autosquash(subject: &str) -> Option<CommitKind> {
+        if let Some(target) = subject.strip_prefix("fixup! ") {
+            Some(CommitKind::Fixup(target.to_string()))
+        } else if let Some(target) = subject.strip_prefix("squash! ") {
+            Some(CommitKind::Squash(target.to_string()))
+        } else {
+            subject.strip_prefix("amend! ").map(|target| CommitKind::Amend(target.to_string()))
+        }
+    }
+
+    /// Returns the subject of the commit an autosquash commit targets.
+    ///
+    /// # Returns
+    /// * `Some(&str)` with the subject written after the prefix, ex: `feat: add lexer` in `fixup! feat: add lexer`.
+    /// * `None` for a conventional commit.
+    #[must_use]
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            CommitKind::Conventional(_) => None,
+            CommitKind::Fixup(subject) | CommitKind::Squash(subject) | CommitKind::Amend(subject) => Some(subject),
+        }
+    }
+
+    /// Returns the conventional commit, if the message is one.
+    ///
+    /// # Returns
+    /// * `Some(&Commit)` for a conventional commit.
+    /// * `None` for an autosquash commit.
+    #[must_use]
+    pub fn commit(&self) -> Option<&Commit> {
+        match self {
+            CommitKind::Conventional(commit) => Some(commit),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::fixup(CommitKind::Fixup("feat: add lexer".into()), Some("feat: add lexer"), false)]
+    #[case::squash(CommitKind::Squash("fix: handle tabs".into()), Some("fix: handle tabs"), false)]
+    #[case::amend(CommitKind::Amend("Update README".into()), Some("Update README"), false)]
+    #[case::conventional(CommitKind::Conventional(Commit::parse("feat: add lexer").expect("should have parsed the commit")), None, true)]
+    fn test_returns_target_or_commit(#[case] kind: CommitKind, #[case] target: Option<&str>, #[case] conventional: bool) {
+        assert_eq!(target, kind.target());
+        assert_eq!(conventional, kind.commit().is_some());
+    }
+}
@@ -0,0 +1,510 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Commit type representation for conventional commits.
+//!
+//! The type is the first token of the commit header, ex: `feat` in `feat(parser): add lexer`. It communicates the intent
+//! of the change. The types recommended by the conventional commits specification have their own variants, any other
+//! type is kept as a custom type.
+
+use crate::model::ValidationError;
+use anyhow::anyhow;
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The types recommended by the specification, each with its own `CommitType` variant.
+pub(crate) const KNOWN_TYPES: [CommitType; 11] = [
+    CommitType::Feat,
+    CommitType::Fix,
+    CommitType::Build,
+    CommitType::Chore,
+    CommitType::Ci,
+    CommitType::Docs,
+    CommitType::Style,
+    CommitType::Refactor,
+    CommitType::Perf,
+    CommitType::Test,
+    CommitType::Revert,
+];
+
+/// The type of a conventional commit, ex: `feat` or `fix`.
+///
+/// Parsing is case-insensitive as required by the specification, and the type is always displayed in lowercase.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommitType {
+    /// A new feature.
+    Feat,
+    /// A bug fix.
+    Fix,
+    /// A change to the build system or dependencies.
+    Build,
+    /// A maintenance change that doesn't touch the source or tests.
+    Chore,
+    /// A change to the continuous integration configuration.
+    Ci,
+    /// A documentation only change.
+    Docs,
+    /// A change that doesn't affect the meaning of the code (formatting, white-space, etc).
+    Style,
+    /// A change that neither fixes a bug nor adds a feature.
+    Refactor,
+    /// A change that improves performance.
+    Perf,
+    /// A change adding or correcting tests.
+    Test,
+    /// A change reverting a previous commit.
+    Revert,
+    /// Any other type, stored as written.
+    Custom(String),
+}
+
+impl CommitType {
+    /// Returns the type as it appears in the commit header.
+    ///
+    /// # Returns
+    /// The type token, ex: `feat`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            CommitType::Feat => "feat",
+            CommitType::Fix => "fix",
+            CommitType::Build => "build",
+            CommitType::Chore => "chore",
+            CommitType::Ci => "ci",
+            CommitType::Docs => "docs",
+            CommitType::Style => "style",
+            CommitType::Refactor => "refactor",
+            CommitType::Perf => "perf",
+            CommitType::Test => "test",
+            CommitType::Revert => "revert",
+            CommitType::Custom(custom) => custom,
+        }
+    }
+
+    /// Returns a human-readable title for the type, ex: `Features` for `feat`, as used for changelog sections.
+    ///
+    /// Custom types are title-cased, reading `-` and `_` as spaces, ex: `Work In Progress` for `work-in-progress`.
+    ///
+    /// # Returns
+    /// The English title of the type, borrowed for the types recommended by the specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::CommitType;
+    /// assert_eq!(CommitType::Fix.section_title(), "Bug Fixes");
+    /// assert_eq!(CommitType::Custom("wip".into()).section_title(), "Wip");
+    /// ```
+    #[must_use]
+    pub fn section_title(&self) -> Cow<'static, str> {
+        Cow::Borrowed(match self {
+            CommitType::Feat => "Features",
+            CommitType::Fix => "Bug Fixes",
+            CommitType::Build => "Build System",
+            CommitType::Chore => "Chores",
+            CommitType::Ci => "Continuous Integration",
+            CommitType::Docs => "Documentation",
+            CommitType::Style => "Styles",
+            CommitType::Refactor => "Code Refactoring",
+            CommitType::Perf => "Performance Improvements",
+            CommitType::Test => "Tests",
+            CommitType::Revert => "Reverts",
+            CommitType::Custom(custom) => return Cow::Owned(title_case(custom)),
+        })
+    }
+}
+
+/// Returns the position of the type in the changelog ordering, custom types coming last.
+fn rank(commit_type: &CommitType) -> u8 {
+    match commit_type {
+        CommitType::Feat => 0,
+        CommitType::Fix => 1,
+        CommitType::Perf => 2,
+        CommitType::Revert => 3,
+        CommitType::Refactor => 4,
+        CommitType::Docs => 5,
+        CommitType::Style => 6,
+        CommitType::Test => 7,
+        CommitType::Build => 8,
+        CommitType::Ci => 9,
+        CommitType::Chore => 10,
+        CommitType::Custom(_) => 11,
+    }
+}
+
+/// Capitalises each word of a custom type, splitting words on `-` and `_`, ex: `Release Candidate` for
+/// `release-candidate`.
+fn title_case(custom: &str) -> String {
+    custom
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Implementation of the `FromStr` trait for `CommitType`.
+///
+/// This implementation validates that:
+/// - The type is not empty
+/// - The type only contains ASCII letters, digits, `-` or `_`
+impl FromStr for CommitType {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ValidationError::MissingRequiredField("type".into()));
+        }
+
+        if !s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(ValidationError::InvalidFieldValue(
+                "type".into(),
+                anyhow!("'{s}' must only contain letters, digits, '-' or '_'"),
+            ));
+        }
+
+        Ok(KNOWN_TYPES
+            .iter()
+            .find(|t| t.as_str().eq_ignore_ascii_case(s))
+            .cloned()
+            .unwrap_or_else(|| CommitType::Custom(s.to_string())))
+    }
+}
+
+/// Parses a type like [`FromStr`] does, for APIs expecting `TryFrom`, ex: serde's `try_from` attribute.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::CommitType;
+/// assert_eq!(CommitType::try_from("feat").unwrap(), CommitType::Feat);
+/// assert!(CommitType::try_from("fe at").is_err());
+/// ```
+impl TryFrom<&str> for CommitType {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Parses a type like [`FromStr`] does, for APIs expecting `TryFrom`, ex: serde's `try_from` attribute.
+impl TryFrom<String> for CommitType {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Orders types by their priority in a changelog, most relevant to readers first.
+///
+/// The order is `feat`, `fix`, `perf`, `revert`, `refactor`, `docs`, `style`, `test`, `build`, `ci`, `chore`, then
+/// custom types alphabetically, ignoring case. Custom types only differing in case are ordered as written, so the
+/// ordering stays consistent with equality.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::CommitType;
+/// let mut types = vec![CommitType::Chore, CommitType::Custom("wip".into()), CommitType::Fix, CommitType::Feat];
+/// types.sort();
+///
+/// assert_eq!(types, [CommitType::Feat, CommitType::Fix, CommitType::Chore, CommitType::Custom("wip".into())]);
+/// ```
+impl Ord for CommitType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (CommitType::Custom(a), CommitType::Custom(b)) => a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(b)),
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+}
+
+impl PartialOrd for CommitType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Borrows the type as [`CommitType::as_str`] does, the inner string of a custom type as it was written.
+///
+/// This is an infallible view of a type validated when it was parsed, not a way to parse one: a
+/// `CommitType::Custom` built directly is returned unchecked.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::CommitType;
+/// assert_eq!(CommitType::Feat.as_ref(), "feat");
+/// assert_eq!(CommitType::Custom("wip".into()).as_ref(), "wip");
+/// ```
+impl AsRef<str> for CommitType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for CommitType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The commit types a team allows, ex: to mirror a commitlint `type-enum` rule.
+///
+/// The default set holds every type with its own `CommitType` variant. Types are compared case-insensitively, as they
+/// are when parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{CommitType, CommitTypeSet};
+/// let types = CommitTypeSet::default().with(CommitType::Custom("wip".into())).without(&CommitType::Style);
+///
+/// assert!(types.contains(&CommitType::Custom("WIP".into())));
+/// assert!(!types.contains(&CommitType::Style));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitTypeSet(Vec<CommitType>);
+
+impl CommitTypeSet {
+    /// Creates a set that allows no type, to be filled with `CommitTypeSet::with`.
+    ///
+    /// # Returns
+    /// An empty `CommitTypeSet`.
+    #[must_use]
+    pub fn empty() -> Self {
+        CommitTypeSet(Vec::new())
+    }
+
+    /// Allows a type, if it isn't already.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type to allow.
+    ///
+    /// # Returns
+    /// The updated set, for chaining.
+    #[must_use]
+    pub fn with(mut self, commit_type: CommitType) -> Self {
+        if !self.contains(&commit_type) {
+            self.0.push(commit_type);
+        }
+        self
+    }
+
+    /// Disallows a type, if it was allowed.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type to disallow.
+    ///
+    /// # Returns
+    /// The updated set, for chaining.
+    #[must_use]
+    pub fn without(mut self, commit_type: &CommitType) -> Self {
+        self.0.retain(|t| !t.as_str().eq_ignore_ascii_case(commit_type.as_str()));
+        self
+    }
+
+    /// Returns whether the type is allowed.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type to look up.
+    ///
+    /// # Returns
+    /// `true` if the set holds the type, ignoring case.
+    #[must_use]
+    pub fn contains(&self, commit_type: &CommitType) -> bool {
+        self.0.iter().any(|t| t.as_str().eq_ignore_ascii_case(commit_type.as_str()))
+    }
+
+    /// Returns the allowed types, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &CommitType> {
+        self.0.iter()
+    }
+
+    /// Checks that the type is allowed.
+    ///
+    /// # Arguments
+    /// * `commit_type` - The type to check.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the type is allowed.
+    /// * `Err(ValidationError)` listing the allowed types otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidFieldValue` for the `type` field if the set doesn't hold the type.
+    pub fn validate(&self, commit_type: &CommitType) -> Result<(), ValidationError> {
+        if self.contains(commit_type) {
+            return Ok(());
+        }
+
+        let allowed = self.0.iter().map(CommitType::as_str).collect::<Vec<_>>().join(", ");
+
+        Err(ValidationError::InvalidFieldValue(
+            "type".into(),
+            anyhow!("'{commit_type}' is not allowed, expected one of: {allowed}"),
+        ))
+    }
+}
+
+/// Allows the standard types, those of the Angular convention recommended by the specification: `build`, `chore`, `ci`,
+/// `docs`, `feat`, `fix`, `perf`, `refactor`, `revert`, `style` and `test`. Any other type is custom and must be added
+/// with `CommitTypeSet::with`.
+impl Default for CommitTypeSet {
+    fn default() -> Self {
+        CommitTypeSet(KNOWN_TYPES.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::feat("feat", CommitType::Feat)]
+    #[case::uppercase("FIX", CommitType::Fix)]
+    #[case::mixed_case("Refactor", CommitType::Refactor)]
+    #[case::revert("revert", CommitType::Revert)]
+    #[case::custom("wip", CommitType::Custom("wip".into()))]
+    #[case::custom_with_separators("release-candidate_1", CommitType::Custom("release-candidate_1".into()))]
+    fn test_parses_commit_type(#[case] input: &str, #[case] expect: CommitType) {
+        assert_eq!(expect, input.parse::<CommitType>().expect("should have parsed the type"));
+        assert_eq!(Ok(&expect), CommitType::try_from(input).as_ref());
+        assert_eq!(Ok(expect), CommitType::try_from(input.to_string()));
+    }
+
+    #[rstest]
+    #[case::empty("", ValidationError::MissingRequiredField("type".into()))]
+    #[case::whitespace("fe at", ValidationError::InvalidFieldValue("type".into(), anyhow!("'fe at' must only contain letters, digits, '-' or '_'")))]
+    #[case::marker("feat!", ValidationError::InvalidFieldValue("type".into(), anyhow!("'feat!' must only contain letters, digits, '-' or '_'")))]
+    fn test_rejects_invalid_commit_type(#[case] input: &str, #[case] expect: ValidationError) {
+        assert_eq!(expect, input.parse::<CommitType>().expect_err("should have failed"));
+        assert_eq!(Err(&expect), CommitType::try_from(input).as_ref());
+        assert_eq!(Err(expect), CommitType::try_from(input.to_string()));
+    }
+
+    #[rstest]
+    #[case::feat(CommitType::Feat, "Features")]
+    #[case::fix(CommitType::Fix, "Bug Fixes")]
+    #[case::perf(CommitType::Perf, "Performance Improvements")]
+    #[case::custom(CommitType::Custom("wip".into()), "Wip")]
+    #[case::custom_with_separators(CommitType::Custom("release-candidate_1".into()), "Release Candidate 1")]
+    #[case::custom_uppercase(CommitType::Custom("WIP".into()), "WIP")]
+    fn test_returns_section_title(#[case] commit_type: CommitType, #[case] expect: &str) {
+        assert_eq!(expect, commit_type.section_title());
+    }
+
+    #[test]
+    fn test_orders_types_by_changelog_priority() {
+        let mut types = vec![
+            CommitType::Custom("wip".into()),
+            CommitType::Chore,
+            CommitType::Ci,
+            CommitType::Build,
+            CommitType::Test,
+            CommitType::Style,
+            CommitType::Docs,
+            CommitType::Refactor,
+            CommitType::Revert,
+            CommitType::Perf,
+            CommitType::Fix,
+            CommitType::Custom("Release".into()),
+            CommitType::Feat,
+        ];
+        types.sort();
+
+        assert_eq!(
+            vec![
+                CommitType::Feat,
+                CommitType::Fix,
+                CommitType::Perf,
+                CommitType::Revert,
+                CommitType::Refactor,
+                CommitType::Docs,
+                CommitType::Style,
+                CommitType::Test,
+                CommitType::Build,
+                CommitType::Ci,
+                CommitType::Chore,
+                CommitType::Custom("Release".into()),
+                CommitType::Custom("wip".into()),
+            ],
+            types
+        );
+    }
+
+    #[rstest]
+    #[case::same_type(CommitType::Feat, CommitType::Feat, Ordering::Equal)]
+    #[case::same_custom(CommitType::Custom("wip".into()), CommitType::Custom("wip".into()), Ordering::Equal)]
+    #[case::custom_case(CommitType::Custom("WIP".into()), CommitType::Custom("wip".into()), Ordering::Less)]
+    #[case::custom_after_known(CommitType::Custom("a".into()), CommitType::Chore, Ordering::Greater)]
+    fn test_compares_commit_types(#[case] a: CommitType, #[case] b: CommitType, #[case] expect: Ordering) {
+        assert_eq!(expect, a.cmp(&b));
+        assert_eq!(a == b, expect == Ordering::Equal);
+    }
+
+    #[rstest]
+    #[case::standard(CommitType::Docs, "docs")]
+    #[case::custom(CommitType::Custom("Wip".into()), "Wip")]
+    fn test_displays_commit_type(#[case] commit_type: CommitType, #[case] expect: &str) {
+        assert_eq!(expect, format!("{commit_type}"));
+        assert_eq!(expect, AsRef::<str>::as_ref(&commit_type));
+    }
+
+    #[rstest]
+    #[case::spec_type(CommitTypeSet::default(), CommitType::Feat, true)]
+    #[case::custom_type(CommitTypeSet::default(), CommitType::Custom("wip".into()), false)]
+    #[case::added_type(CommitTypeSet::default().with(CommitType::Custom("wip".into())), CommitType::Custom("WIP".into()), true)]
+    #[case::removed_type(CommitTypeSet::default().without(&CommitType::Style), CommitType::Style, false)]
+    #[case::empty(CommitTypeSet::empty(), CommitType::Feat, false)]
+    fn test_contains_commit_type(#[case] types: CommitTypeSet, #[case] commit_type: CommitType, #[case] expect: bool) {
+        assert_eq!(expect, types.contains(&commit_type));
+    }
+
+    #[test]
+    fn test_defaults_to_exactly_the_standard_types() {
+        let defaults = CommitTypeSet::default();
+        let mut types: Vec<&str> = defaults.iter().map(CommitType::as_str).collect();
+        types.sort_unstable();
+
+        assert_eq!(vec!["build", "chore", "ci", "docs", "feat", "fix", "perf", "refactor", "revert", "style", "test"], types);
+        assert!(defaults.contains(&CommitType::Revert), "some implementations omit revert, it is standard");
+    }
+
+    #[test]
+    fn test_does_not_add_commit_type_twice() {
+        let types = CommitTypeSet::empty().with(CommitType::Custom("wip".into())).with(CommitType::Custom("Wip".into()));
+        assert_eq!(1, types.iter().count());
+    }
+
+    #[test]
+    fn test_rejects_commit_type_outside_set() {
+        let types = CommitTypeSet::empty().with(CommitType::Feat).with(CommitType::Fix);
+
+        assert_eq!(
+            ValidationError::InvalidFieldValue("type".into(), anyhow!("'chore' is not allowed, expected one of: feat, fix")),
+            types.validate(&CommitType::Chore).expect_err("should have failed")
+        );
+    }
+}
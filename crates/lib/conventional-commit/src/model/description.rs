@@ -0,0 +1,207 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Description representation for conventional commits.
+//!
+//! The description is the short summary following the colon in the commit header, ex: `add lexer` in
+//! `feat(parser): add lexer`.
+
+use crate::model::ValidationError;
+use anyhow::anyhow;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The description of a conventional commit, ex: `add lexer`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Description(String);
+
+impl Description {
+    /// The maximum length of a description, in characters, when no other limit is given.
+    pub const DEFAULT_MAX_LENGTH: usize = 72;
+
+    /// The length, in characters, git conventionally recommends keeping subjects under.
+    pub const RECOMMENDED_LENGTH: usize = 50;
+
+    /// Parses and validates a description, limiting it to `DEFAULT_MAX_LENGTH` characters.
+    ///
+    /// # Returns
+    /// * `Ok(Description)` if the description is valid.
+    /// * `Err(ValidationError)` if the description is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the description is blank, has surrounding white-space, spans several lines or is
+    /// longer than `DEFAULT_MAX_LENGTH` characters.
+    pub fn parse(text: &str) -> Result<Self, ValidationError> {
+        Description::parse_with_limit(text, Description::DEFAULT_MAX_LENGTH)
+    }
+
+    /// Parses and validates a description, limiting it to the given length.
+    ///
+    /// # Arguments
+    /// * `text` - The description.
+    /// * `limit` - The maximum length of the description, in characters.
+    ///
+    /// # Returns
+    /// * `Ok(Description)` if the description is valid.
+    /// * `Err(ValidationError)` if the description is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the description is blank, has surrounding white-space, spans several lines or is
+    /// longer than `limit` characters.
+    pub fn parse_with_limit(text: &str, limit: usize) -> Result<Self, ValidationError> {
+        if text.trim().is_empty() {
+            return Err(ValidationError::MissingRequiredField("description".into()));
+        }
+
+        if text.trim() != text {
+            return Err(ValidationError::InvalidFieldValue(
+                "description".into(),
+                anyhow!("'{text}' must not start or end with white-space"),
+            ));
+        }
+
+        if text.contains(['\n', '\r']) {
+            return Err(ValidationError::InvalidFieldValue("description".into(), anyhow!("must be a single line")));
+        }
+
+        let length = text.chars().count();
+        if length > limit {
+            return Err(ValidationError::RuleViolation {
+                rule: "description-max-length".into(),
+                message: format!("description '{text}' is {length} characters long, it must be at most {limit}"),
+            });
+        }
+
+        Ok(Description(text.to_string()))
+    }
+
+    /// Returns whether the description is longer than the `RECOMMENDED_LENGTH`, a softer limit than the one enforced.
+    ///
+    /// # Returns
+    /// `true` if the description has more than `RECOMMENDED_LENGTH` characters.
+    #[must_use]
+    pub fn exceeds_recommended_length(&self) -> bool {
+        self.char_count() > Description::RECOMMENDED_LENGTH
+    }
+
+    /// Returns the length of the description in characters, as measured against the `description-max-length` limit.
+    ///
+    /// # Returns
+    /// The number of Unicode scalar values in the description, not its size in bytes.
+    #[must_use]
+    pub fn char_count(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    /// Returns the description text.
+    ///
+    /// # Returns
+    /// The description as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Description {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Description::parse(s)
+    }
+}
+
+/// Borrows the description as [`Description::as_str`] does, for APIs taking `impl AsRef<str>`.
+///
+/// This is an infallible view of a description validated when it was parsed, not a way to parse one.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::Description;
+/// let description = Description::parse("add lexer").unwrap();
+///
+/// assert_eq!(description.as_ref(), "add lexer");
+/// ```
+impl AsRef<str> for Description {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Description {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::word("init")]
+    #[case::sentence("add a lexer for the header grammar")]
+    #[case::internal_colon("support `token: value` footers")]
+    fn test_parses_description(#[case] input: &str) {
+        let description = Description::parse(input).expect("should have parsed the description");
+        assert_eq!(input, description.as_str());
+        assert_eq!(input, AsRef::<str>::as_ref(&description));
+        assert_eq!(input, format!("{description}"));
+    }
+
+    #[rstest]
+    #[case::empty("", ValidationError::MissingRequiredField("description".into()))]
+    #[case::blank("   ", ValidationError::MissingRequiredField("description".into()))]
+    #[case::leading_space(" add", ValidationError::InvalidFieldValue("description".into(), anyhow!("' add' must not start or end with white-space")))]
+    #[case::multi_line("add\nlexer", ValidationError::InvalidFieldValue("description".into(), anyhow!("must be a single line")))]
+    fn test_rejects_invalid_description(#[case] input: &str, #[case] expect: ValidationError) {
+        assert_eq!(expect, Description::parse(input).expect_err("should have failed"));
+    }
+
+    #[rstest]
+    #[case::at_the_limit(&"a".repeat(50), 50)]
+    #[case::multi_byte(&"é".repeat(50), 50)]
+    #[case::above_the_default(&"a".repeat(100), 100)]
+    fn test_parses_description_within_limit(#[case] input: &str, #[case] limit: usize) {
+        assert!(Description::parse_with_limit(input, limit).is_ok());
+    }
+
+    #[rstest]
+    #[case::default_limit(&"a".repeat(73), Description::DEFAULT_MAX_LENGTH, 73)]
+    #[case::custom_limit(&"a".repeat(51), 50, 51)]
+    fn test_rejects_description_over_limit(#[case] input: &str, #[case] limit: usize, #[case] length: usize) {
+        assert_eq!(
+            ValidationError::RuleViolation {
+                rule: "description-max-length".into(),
+                message: format!("description '{input}' is {length} characters long, it must be at most {limit}")
+            },
+            Description::parse_with_limit(input, limit).expect_err("should have failed")
+        );
+    }
+
+    #[rstest]
+    #[case::short("add lexer", false)]
+    #[case::at_the_recommendation(&"a".repeat(50), false)]
+    #[case::over_the_recommendation(&"a".repeat(51), true)]
+    fn test_detects_description_over_recommended_length(#[case] input: &str, #[case] expect: bool) {
+        let description = Description::parse(input).expect("should have parsed the description");
+        assert_eq!(expect, description.exceeds_recommended_length());
+    }
+}
@@ -10,47 +10,780 @@
  * You should have received a copy of the GNU Affero General Public License along with this program.
  * If not, see https://www.gnu.org/licenses/.
  */
-use crate::model::person::Person;
+
+//! Footer representation for conventional commits.
+//!
+//! A footer is a `token: value` or `token #value` line at the end of the commit message, similar to git trailers, ex:
+//! `Refs: PROJ-123` or `Closes #42`. Tokens use `-` in place of white-space, with the exception of the breaking change
+//! token which may be written either `BREAKING CHANGE` or `BREAKING-CHANGE`. Both spellings are recognised as the same
+//! token through `BreakingChangeToken`, but the one written is kept, see there for why.
+
+use crate::{
+    errors::Errors,
+    model::{Build, Validate, ValidationError, ValidationErrors},
+};
+use anyhow::{Error as AnyError, anyhow};
 use derive_builder::Builder;
-use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The separator between a footer's token and its value, in the `token: value` form.
+const COLON_SEPARATOR: &str = ": ";
+
+/// The separator between a footer's token and its value, in the `token #value` form. The `#` is kept in the value.
+const HASH_SEPARATOR: &str = " #";
+
+/// The forms a footer can separate its token from its value with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FooterSeparator {
+    /// `token: value`, ex: `Reviewed-by: alice@example.com`.
+    #[default]
+    Colon,
+    /// `token #value`, ex: `Closes #42`. The value keeps its leading `#`, as it is part of the issue reference.
+    Hash,
+}
+
+/// The spellings of the breaking change footer token.
+///
+/// The specification treats both spellings as synonyms. Unlike other tokens, they are case-sensitive and must be uppercase.
+///
+/// Parsing normalizes them to the same meaning rather than the same text: `Footer::breaking_change_token` tells which
+/// spelling a footer uses, and `Footer::is_breaking` and `Commit::is_breaking` treat both alike. The token itself is kept
+/// as written so a parsed message is displayed exactly as it was, which rewriting a message in place, ex: in a git hook,
+/// or diffing it relies on. A footer only gets the other spelling when it is built with it, by passing a
+/// `BreakingChangeToken` to `FooterBuilder::token`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{BreakingChangeToken, Build, Footer};
+/// let parsed: Footer = "BREAKING-CHANGE: drops v1".parse().unwrap();
+/// let built = Footer::breaking_change("drops v1").token(BreakingChangeToken::Hyphen).build().unwrap();
+///
+/// assert_eq!(Some(BreakingChangeToken::Hyphen), parsed.breaking_change_token());
+/// assert_eq!(parsed, built);
+/// assert_eq!("BREAKING-CHANGE: drops v1", parsed.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BreakingChangeToken {
+    /// `BREAKING CHANGE`, the spelling used by default.
+    #[default]
+    Space,
+    /// `BREAKING-CHANGE`, the git trailer friendly spelling.
+    Hyphen,
+}
+
+impl BreakingChangeToken {
+    /// Returns the token as it appears in the footer.
+    ///
+    /// # Returns
+    /// Either `BREAKING CHANGE` or `BREAKING-CHANGE`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BreakingChangeToken::Space => "BREAKING CHANGE",
+            BreakingChangeToken::Hyphen => "BREAKING-CHANGE",
+        }
+    }
+
+    /// Recognises a footer token as a breaking change token.
+    ///
+    /// # Arguments
+    /// * `token` - The footer token to recognise.
+    ///
+    /// # Returns
+    /// * `Some(BreakingChangeToken)` if the token is one of the uppercase spellings.
+    /// * `None` for any other token, including differently cased spellings.
+    #[must_use]
+    pub fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "BREAKING CHANGE" => Some(BreakingChangeToken::Space),
+            "BREAKING-CHANGE" => Some(BreakingChangeToken::Hyphen),
+            _ => None,
+        }
+    }
+}
 
-#[derive(Builder)]
-struct Footer {
-    #[builder(setter(into, strip_option), default)]
-    breaking_change: Option<String>,
-    #[builder(try_setter, setter(custom), default)]
-    people: Vec<Person>,
+impl From<BreakingChangeToken> for String {
+    fn from(token: BreakingChangeToken) -> Self {
+        token.as_str().to_string()
+    }
+}
+
+impl Display for BreakingChangeToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The keywords GitHub and GitLab close the referenced issue with once the commit reaches the default branch, ex:
+/// `Closes` in `Closes #42`.
+///
+/// Forges also accept other tenses, ex: `Fixed`, these are the ones written by default. Keywords are parsed
+/// case-insensitively and written capitalized.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Build, ClosingKeyword, Footer};
+/// let keyword: ClosingKeyword = "fixes".parse().unwrap();
+/// let footer = Footer::closing(keyword, "PROJ-45").build().unwrap();
+///
+/// assert_eq!(footer.to_string(), "Fixes: PROJ-45");
+/// assert!("Refs".parse::<ClosingKeyword>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ClosingKeyword {
+    /// `Closes`, ex: `Closes #42`.
+    Closes,
+    /// `Fixes`, ex: `Fixes PROJ-45`.
+    Fixes,
+    /// `Resolves`, ex: `Resolves #42`.
+    Resolves,
+}
+
+impl ClosingKeyword {
+    /// Every supported closing keyword, in the order they are listed in errors.
+    pub const ALL: [ClosingKeyword; 3] = [ClosingKeyword::Closes, ClosingKeyword::Fixes, ClosingKeyword::Resolves];
+
+    /// Returns the keyword as it appears as a footer token.
+    ///
+    /// # Returns
+    /// One of `Closes`, `Fixes` or `Resolves`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ClosingKeyword::Closes => "Closes",
+            ClosingKeyword::Fixes => "Fixes",
+            ClosingKeyword::Resolves => "Resolves",
+        }
+    }
+
+    /// Returns every tense of the keyword forges close the referenced issue with, ex: `Fix`, `Fixes` and `Fixed`.
+    ///
+    /// # Returns
+    /// The base form, the keyword as written by default, then the past tense.
+    #[must_use]
+    pub fn tenses(self) -> [&'static str; 3] {
+        match self {
+            ClosingKeyword::Closes => ["Close", "Closes", "Closed"],
+            ClosingKeyword::Fixes => ["Fix", "Fixes", "Fixed"],
+            ClosingKeyword::Resolves => ["Resolve", "Resolves", "Resolved"],
+        }
+    }
+}
+
+/// Parses a closing keyword, ignoring case.
+///
+/// # Errors
+///
+/// Returns `ValidationError::InvalidFieldValue` if the keyword is not one of `Closes`, `Fixes` or `Resolves`.
+impl FromStr for ClosingKeyword {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ClosingKeyword::ALL.into_iter().find(|k| k.as_str().eq_ignore_ascii_case(s)).ok_or_else(|| {
+            let supported = ClosingKeyword::ALL.map(ClosingKeyword::as_str).join(", ");
+            ValidationError::InvalidFieldValue("closing keyword".into(), anyhow!("'{s}' must be one of: {supported}"))
+        })
+    }
+}
+
+/// Parses a closing keyword like [`FromStr`] does, for serde's `try_from` attribute.
+impl TryFrom<String> for ClosingKeyword {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ClosingKeyword> for String {
+    fn from(keyword: ClosingKeyword) -> Self {
+        keyword.as_str().to_string()
+    }
+}
+
+impl Display for ClosingKeyword {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Represents a footer (ex: `Refs: PROJ-123`) in a conventional commit.
+///
+/// A `Footer` consists of a token, a separator and a value. The token must only contain ASCII letters, digits and `-`,
+/// unless it is a breaking change token. The value must not be empty.
+///
+/// The separator is remembered so parsed footers are displayed exactly as written. When building a footer without
+/// choosing one, values starting with `#`, ex: `#42`, use the `token #value` form and any other value `token: value`.
+#[derive(Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(build_fn(skip))]
+pub struct Footer {
+    /// The token identifying the footer, ex: `Refs`.
+    #[builder(setter(into))]
+    pub(crate) token: String,
+    /// How the token is separated from the value.
+    #[builder(default)]
+    pub(crate) separator: FooterSeparator,
+    /// The value of the footer, ex: `PROJ-123`.
+    #[builder(setter(into))]
+    pub(crate) value: String,
 }
 
 impl Footer {
-    fn builder() -> FooterBuilder {
-        FooterBuilder::default()
+    /// Creates a new `FooterBuilder` for constructing a `Footer`.
+    ///
+    /// # Arguments
+    /// * `token` - The token of the footer.
+    ///
+    /// # Returns
+    /// A `FooterBuilder` instance for further configuration.
+    pub fn builder(token: impl Into<String>) -> FooterBuilder {
+        FooterBuilder {
+            token: Some(token.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new `FooterBuilder` for a breaking change footer.
+    ///
+    /// The token defaults to `BREAKING CHANGE`, pass a `BreakingChangeToken` to `FooterBuilder::token` to pick the
+    /// hyphenated spelling instead.
+    ///
+    /// # Arguments
+    /// * `description` - The description of the breaking change.
+    ///
+    /// # Returns
+    /// A `FooterBuilder` instance for further configuration.
+    pub fn breaking_change(description: impl Into<String>) -> FooterBuilder {
+        FooterBuilder {
+            token: Some(BreakingChangeToken::default().into()),
+            value: Some(description.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new `FooterBuilder` for a footer closing an issue on the forge, ex: `Closes #42`.
+    ///
+    /// As with any footer, references starting with `#` use the `token #value` form and others `token: value`.
+    ///
+    /// # Arguments
+    /// * `keyword` - The closing keyword, used as the token.
+    /// * `reference` - The issue to close, ex: `#42` or `PROJ-45`.
+    ///
+    /// # Returns
+    /// A `FooterBuilder` instance for further configuration.
+    pub fn closing(keyword: ClosingKeyword, reference: impl Into<String>) -> FooterBuilder {
+        FooterBuilder {
+            token: Some(keyword.into()),
+            value: Some(reference.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the token of the footer.
+    ///
+    /// # Returns
+    /// The token as a string slice, ex: `Refs`.
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns how the token is separated from the value.
+    ///
+    /// # Returns
+    /// The `FooterSeparator` the footer is displayed with.
+    #[must_use]
+    pub fn separator(&self) -> FooterSeparator {
+        self.separator
+    }
+
+    /// Returns the value of the footer.
+    ///
+    /// # Returns
+    /// The value as a string slice, ex: `PROJ-123`, or `#42` in the `token #value` form.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the spelling of the breaking change token, if this is a breaking change footer.
+    ///
+    /// # Returns
+    /// * `Some(BreakingChangeToken)` if the token is `BREAKING CHANGE` or `BREAKING-CHANGE`.
+    /// * `None` otherwise.
+    #[must_use]
+    pub fn breaking_change_token(&self) -> Option<BreakingChangeToken> {
+        BreakingChangeToken::from_token(&self.token)
+    }
+
+    /// Checks whether the footer describes a breaking change.
+    ///
+    /// Only the uppercase `BREAKING CHANGE` and `BREAKING-CHANGE` tokens do, as the specification requires, whichever
+    /// separator follows them.
+    ///
+    /// # Returns
+    /// `true` if the token is one of the breaking change tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Footer;
+    /// assert!("BREAKING CHANGE: drops v1".parse::<Footer>().unwrap().is_breaking());
+    /// assert!(!"Refs: PROJ-123".parse::<Footer>().unwrap().is_breaking());
+    /// ```
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        self.breaking_change_token().is_some()
+    }
+}
+
+impl FooterBuilder {
+    /// Returns the chosen separator, or the one matching the value if none was chosen.
+    fn separator_for(&self, value: &str) -> FooterSeparator {
+        match self.separator {
+            Some(separator) => separator,
+            None if value.starts_with('#') => FooterSeparator::Hash,
+            None => FooterSeparator::Colon,
+        }
     }
 }
 
-impl FooterBuilder {}
+/// Implementation of the `Build` trait for `FooterBuilder`.
+///
+/// This implementation validates that:
+/// - The token is not empty and follows the footer token grammar
+/// - The token is not a differently cased spelling of the breaking change token, ex: `breaking-change`
+/// - The value is not blank
+/// - The value starts with `#` when the `token #value` form is chosen
+impl Build<Footer> for FooterBuilder {
+    /// Validates and builds a `Footer` instance.
+    ///
+    /// # Returns
+    /// * `Ok(Footer)` if validation passes.
+    /// * `Err(ValidationErrors)` if validation fails.
+    fn build(&mut self) -> Result<Footer, ValidationErrors> {
+        let token = self.token.clone().unwrap_or_default();
+        let value = self.value.clone().unwrap_or_default();
+        let separator = self.separator_for(&value);
 
+        check_parts(&token, separator, &value).into_result(Footer { token, separator, value })
+    }
+}
+
+/// Implementation of the `Validate` trait for `Footer`.
+///
+/// The same rules as the builder apply: the token must follow the footer token grammar, where only the breaking change
+/// token may contain a space, and the value must not be blank. Values may span several lines, as the specification
+/// allows. Errors name the fields `footer.token` and `footer.value`.
+impl Validate for Footer {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let errs = check_parts(&self.token, self.separator, &self.value).map(|e| match e {
+            ValidationError::MissingRequiredField(field) => ValidationError::MissingRequiredField(format!("footer.{field}")),
+            ValidationError::InvalidFieldValue(field, reason) => ValidationError::InvalidFieldValue(format!("footer.{field}"), reason),
+            e @ ValidationError::RuleViolation { .. } => e,
+        });
+
+        errs.into_result(())
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Footer`.
+///
+/// Parses a `token: value` or `token #value` footer, splitting on the first separator. The value may continue on the
+/// following lines, which are kept as written. Either spelling of the breaking change token is accepted and kept as
+/// written.
+impl FromStr for Footer {
+    type Err = ValidationErrors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split(s) {
+            Some((token, separator, value)) => check_parts(token, separator, value).into_result(()).map(|()| Footer {
+                token: token.to_string(),
+                separator,
+                value: value.to_string(),
+            }),
+            None => Err(Errors::from([ValidationError::InvalidFieldValue(
+                "footer".into(),
+                anyhow!("'{s}' must be formatted as 'token: value' or 'token #value'"),
+            )])),
+        }
+    }
+}
+
+/// Implementation of the `Display` trait for `Footer`.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::{Build, BreakingChangeToken, Footer};
+/// #
+/// # let refs = Footer::builder("Refs").value("PROJ-123").build().unwrap();
+/// # let breaking = Footer::breaking_change("drops support for v1").build().unwrap();
+/// # let hyphenated = Footer::breaking_change("drops support for v1").token(BreakingChangeToken::Hyphen).build().unwrap();
+///
+/// assert_eq!(format!("{}", refs), "Refs: PROJ-123");
+/// assert_eq!(format!("{}", breaking), "BREAKING CHANGE: drops support for v1");
+/// assert_eq!(format!("{}", hyphenated), "BREAKING-CHANGE: drops support for v1");
+/// assert_eq!(format!("{}", "Closes #42".parse::<Footer>().unwrap()), "Closes #42");
+/// ```
 impl Display for Footer {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(msg) = self.breaking_change.clone() {
-            writeln!(f, "BREAKING CHANGE: {msg}")?;
+        match self.separator {
+            FooterSeparator::Colon => write!(f, "{}{COLON_SEPARATOR}{}", self.token, self.value),
+            FooterSeparator::Hash => write!(f, "{} {}", self.token, self.value),
         }
+    }
+}
+
+/// Splits a footer line on the first separator preceded by a valid token, or the first separator if there is none,
+/// keeping the `#` in the value.
+fn split(line: &str) -> Option<(&str, FooterSeparator, &str)> {
+    let colon = line.find(COLON_SEPARATOR).map(|i| (i, FooterSeparator::Colon));
+    let hash = line.find(HASH_SEPARATOR).map(|i| (i, FooterSeparator::Hash));
+
+    let separators = match (colon, hash) {
+        (Some(colon), Some(hash)) if hash.0 < colon.0 => [Some(hash), Some(colon)],
+        _ => [colon, hash],
+    };
+
+    separators
+        .iter()
+        .flatten()
+        .find(|(i, _)| is_valid_token(&line[..*i]))
+        .or(separators.iter().flatten().next())
+        .map(|&(i, separator)| match separator {
+            FooterSeparator::Colon => (&line[..i], separator, &line[i + COLON_SEPARATOR.len()..]),
+            FooterSeparator::Hash => (&line[..i], separator, &line[i + 1..]),
+        })
+}
+
+/// Parses the lines of a footer paragraph into one footer per `token: ` or `token #` line, ex: `Refs: PROJ-123`.
+///
+/// The lines in between continue the value of the footer before them, as the specification lets a value span several
+/// lines until the next token. A paragraph not starting with a footer line is parsed, and reported, as one footer.
+///
+/// # Returns
+/// The lines of each footer in order, with the footer parsed from them or the errors making it invalid.
+pub(crate) fn parse_paragraph<'a>(lines: &'a [&'a str]) -> impl Iterator<Item = (&'a [&'a str], Result<Footer, ValidationErrors>)> + 'a {
+    lines.chunk_by(|_, next| !is_footer_line(next)).map(|footer| match footer {
+        [line] => (footer, line.parse()),
+        lines => (footer, lines.join("\n").parse()),
+    })
+}
+
+/// Checks whether a paragraph holds footers, which is when its first line starts one.
+///
+/// The following lines either start another footer or continue the value of the one before.
+pub(crate) fn is_footer_paragraph(paragraph: &str) -> bool {
+    paragraph.lines().next().is_some_and(is_footer_line)
+}
+
+/// Checks whether a line starts a footer, ex: `Refs: PROJ-123` or `Closes #42`.
+pub(crate) fn is_footer_line(line: &str) -> bool {
+    split(line).is_some_and(|(token, _, value)| (is_valid_token(token) || collides_with_breaking_change(token)) && !value.trim_start_matches('#').trim().is_empty())
+}
+
+/// Checks the parts of a footer against the rules the builder, `Validate` and `FromStr` share.
+///
+/// The token must follow the footer token grammar, and the value must not be blank and must start with `#` in the
+/// `token #value` form.
+///
+/// # Returns
+/// The problems found, naming the fields `token` and `value`.
+fn check_parts(token: &str, separator: FooterSeparator, value: &str) -> ValidationErrors {
+    let mut errs = Errors::new();
+
+    if token.is_empty() {
+        errs.append(ValidationError::MissingRequiredField("token".into()));
+    } else if let Some(e) = check_token(token) {
+        errs.append(ValidationError::InvalidFieldValue("token".into(), e));
+    }
+
+    if value.trim().is_empty() {
+        errs.append(ValidationError::MissingRequiredField("value".into()));
+    } else if let Some(e) = check_separator(separator, value) {
+        errs.append(ValidationError::InvalidFieldValue("value".into(), e));
+    }
+
+    errs
+}
+
+/// Checks a non-empty token, returning why it is invalid if it is.
+fn check_token(token: &str) -> Option<AnyError> {
+    if collides_with_breaking_change(token) {
+        Some(anyhow!(
+            "'{token}' collides with the reserved breaking change token, write it 'BREAKING CHANGE' or 'BREAKING-CHANGE'"
+        ))
+    } else if is_valid_token(token) {
+        None
+    } else {
+        Some(anyhow!("'{token}' must only contain letters, digits or '-'"))
+    }
+}
 
-        Ok(())
+/// Checks that a value can be separated from its token by the separator, returning why it can't if it can't.
+fn check_separator(separator: FooterSeparator, value: &str) -> Option<AnyError> {
+    match separator {
+        FooterSeparator::Hash if !value.starts_with('#') => Some(anyhow!("'{value}' must start with '#' to be separated from the token by ' #'")),
+        _ => None,
     }
 }
 
+/// Checks whether a token is a breaking change token in any casing other than the uppercase one, ex: `Breaking Change`.
+///
+/// Tokens are otherwise case-insensitive, so such a token would be ambiguous.
+fn collides_with_breaking_change(token: &str) -> bool {
+    BreakingChangeToken::from_token(token).is_none()
+        && [BreakingChangeToken::Space, BreakingChangeToken::Hyphen]
+            .iter()
+            .any(|t| t.as_str().eq_ignore_ascii_case(token))
+}
+
+/// Checks a token against the footer token grammar.
+pub(crate) fn is_valid_token(token: &str) -> bool {
+    BreakingChangeToken::from_token(token).is_some() || (!token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::multi_error;
     use rstest::rstest;
 
     #[rstest]
-    #[case::empty(Footer::builder().build(), "")]
-    #[case::breaking_change(Footer::builder().breaking_change("test breaking change message").build(), "BREAKING CHANGE: test breaking change message\n")]
-    fn test_displays_footer(#[case] footer: Result<Footer, FooterBuilderError>, #[case] expect: impl Into<String>) {
-        let footer = footer.expect("should have build a footer");
+    #[case::refs(Footer::builder("Refs").value("PROJ-123").build(), "Refs: PROJ-123")]
+    #[case::breaking_change(Footer::breaking_change("test breaking change message").build(), "BREAKING CHANGE: test breaking change message")]
+    #[case::breaking_change_with_space(
+        Footer::breaking_change("test breaking change message").token(BreakingChangeToken::Space).build(),
+        "BREAKING CHANGE: test breaking change message"
+    )]
+    #[case::breaking_change_with_hyphen(
+        Footer::breaking_change("test breaking change message").token(BreakingChangeToken::Hyphen).build(),
+        "BREAKING-CHANGE: test breaking change message"
+    )]
+    #[case::issue_reference(Footer::builder("Closes").value("#42").build(), "Closes #42")]
+    #[case::issue_reference_with_colon(Footer::builder("Closes").separator(FooterSeparator::Colon).value("#42").build(), "Closes: #42")]
+    fn test_displays_footer(#[case] footer: Result<Footer, ValidationErrors>, #[case] expect: impl Into<String>) {
+        let footer = footer.expect("should have built a footer");
         assert_eq!(expect.into(), format!("{footer}"));
     }
+
+    #[rstest]
+    #[case::missing_value(Footer::builder("Refs"), multi_error!(ValidationError::MissingRequiredField("value".into())))]
+    #[case::empty_token(Footer::builder("").value("x").clone(), multi_error!(ValidationError::MissingRequiredField("token".into())))]
+    #[case::token_with_space(
+        Footer::builder("Reviewed by").value("x").clone(),
+        multi_error!(ValidationError::InvalidFieldValue("token".into(), anyhow!("'Reviewed by' must only contain letters, digits or '-'")))
+    )]
+    #[case::lowercase_breaking_change(
+        Footer::builder("breaking change").value("x").clone(),
+        multi_error!(ValidationError::InvalidFieldValue("token".into(), anyhow!("'breaking change' collides with the reserved breaking change token, write it 'BREAKING CHANGE' or 'BREAKING-CHANGE'")))
+    )]
+    #[case::hash_without_issue_reference(
+        Footer::builder("Refs").separator(FooterSeparator::Hash).value("PROJ-123").clone(),
+        multi_error!(ValidationError::InvalidFieldValue("value".into(), anyhow!("'PROJ-123' must start with '#' to be separated from the token by ' #'")))
+    )]
+    #[case::empty_token_and_value(
+        Footer::builder("").value(" ").clone(),
+        multi_error!(ValidationError::MissingRequiredField("token".into()), ValidationError::MissingRequiredField("value".into()))
+    )]
+    fn test_returns_error_building_footer(#[case] mut footer: FooterBuilder, #[case] expect: ValidationErrors) {
+        let errs = footer.build().expect_err("should have failed");
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+
+    #[rstest]
+    #[case::space("BREAKING CHANGE: drops v1", Some(BreakingChangeToken::Space))]
+    #[case::hyphen("BREAKING-CHANGE: drops v1", Some(BreakingChangeToken::Hyphen))]
+    #[case::other_token("Refs: PROJ-123", None)]
+    fn test_recognises_breaking_change_token(#[case] input: &str, #[case] expect: Option<BreakingChangeToken>) {
+        let footer = input.parse::<Footer>().expect("should have parsed the footer");
+        assert_eq!(expect, footer.breaking_change_token());
+        assert_eq!(input, format!("{footer}"));
+    }
+
+    #[rstest]
+    #[case::closes("Closes", ClosingKeyword::Closes)]
+    #[case::lowercase("fixes", ClosingKeyword::Fixes)]
+    #[case::uppercase("RESOLVES", ClosingKeyword::Resolves)]
+    fn test_parses_closing_keyword(#[case] input: &str, #[case] expect: ClosingKeyword) {
+        assert_eq!(expect, input.parse::<ClosingKeyword>().expect("should have parsed the keyword"));
+        assert_eq!(Ok(expect), ClosingKeyword::try_from(input.to_string()));
+    }
+
+    #[rstest]
+    #[case::other_token("Refs")]
+    #[case::other_tense("Fixed")]
+    #[case::empty("")]
+    fn test_rejects_unsupported_closing_keyword(#[case] input: &str) {
+        assert_eq!(
+            ValidationError::InvalidFieldValue("closing keyword".into(), anyhow!("'{input}' must be one of: Closes, Fixes, Resolves")),
+            input.parse::<ClosingKeyword>().expect_err("should have failed")
+        );
+    }
+
+    #[rstest]
+    #[case::hash(ClosingKeyword::Closes, "#42", "Closes #42")]
+    #[case::colon(ClosingKeyword::Fixes, "PROJ-45", "Fixes: PROJ-45")]
+    fn test_builds_closing_footer(#[case] keyword: ClosingKeyword, #[case] reference: &str, #[case] expect: &str) {
+        let footer = Footer::closing(keyword, reference).build().expect("should have built the footer");
+        assert_eq!(expect, footer.to_string());
+    }
+
+    #[rstest]
+    #[case::space_colon(Footer { token: "BREAKING CHANGE".into(), separator: FooterSeparator::Colon, value: "drops v1".into() }, true)]
+    #[case::hyphen_colon(Footer { token: "BREAKING-CHANGE".into(), separator: FooterSeparator::Colon, value: "drops v1".into() }, true)]
+    #[case::space_hash(Footer { token: "BREAKING CHANGE".into(), separator: FooterSeparator::Hash, value: "#42".into() }, true)]
+    #[case::hyphen_hash(Footer { token: "BREAKING-CHANGE".into(), separator: FooterSeparator::Hash, value: "#42".into() }, true)]
+    #[case::lowercase(Footer { token: "breaking change".into(), separator: FooterSeparator::Colon, value: "drops v1".into() }, false)]
+    #[case::title_case(Footer { token: "Breaking-Change".into(), separator: FooterSeparator::Colon, value: "drops v1".into() }, false)]
+    #[case::other_token(Footer { token: "Refs".into(), separator: FooterSeparator::Colon, value: "PROJ-123".into() }, false)]
+    fn test_detects_breaking_footer(#[case] footer: Footer, #[case] expect: bool) {
+        assert_eq!(expect, footer.is_breaking());
+    }
+
+    #[rstest]
+    #[case::hash("Closes #42", "Closes", FooterSeparator::Hash, "#42")]
+    #[case::colon("Reviewed-by: a@b.io", "Reviewed-by", FooterSeparator::Colon, "a@b.io")]
+    #[case::colon_in_value("Refs: see: PROJ-1", "Refs", FooterSeparator::Colon, "see: PROJ-1")]
+    #[case::colon_before_hash("Refs: PROJ-1 #42", "Refs", FooterSeparator::Colon, "PROJ-1 #42")]
+    #[case::hash_before_colon("Fixes #42: crash on start", "Fixes", FooterSeparator::Hash, "#42: crash on start")]
+    #[case::colon_with_issue_reference("Closes: #42", "Closes", FooterSeparator::Colon, "#42")]
+    #[case::breaking_change_hash("BREAKING CHANGE #42", "BREAKING CHANGE", FooterSeparator::Hash, "#42")]
+    fn test_parses_footer(#[case] input: &str, #[case] token: &str, #[case] separator: FooterSeparator, #[case] value: &str) {
+        let footer = input.parse::<Footer>().expect("should have parsed the footer");
+
+        assert_eq!(token, footer.token());
+        assert_eq!(separator, footer.separator());
+        assert_eq!(value, footer.value());
+        assert_eq!(input, format!("{footer}"));
+    }
+
+    #[rstest]
+    #[case::no_separator("Refs PROJ-123", multi_error!(ValidationError::InvalidFieldValue("footer".into(), anyhow!("'Refs PROJ-123' must be formatted as 'token: value' or 'token #value'"))))]
+    #[case::invalid_token(
+        "Reviewed by: Alice",
+        multi_error!(ValidationError::InvalidFieldValue("token".into(), anyhow!("'Reviewed by' must only contain letters, digits or '-'")))
+    )]
+    fn test_returns_error_parsing_footer(#[case] input: &str, #[case] expect: ValidationErrors) {
+        let errs = input.parse::<Footer>().expect_err("should have failed");
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+
+    #[rstest]
+    #[case::mixed_case_space("Breaking Change")]
+    #[case::lowercase_hyphen("breaking-change")]
+    fn test_rejects_token_colliding_with_breaking_change(#[case] token: &str) {
+        let errs = Footer::builder(token).value("drops v1").build().expect_err("should have failed");
+
+        assert_eq!(
+            multi_error!(ValidationError::InvalidFieldValue(
+                "token".into(),
+                anyhow!("'{token}' collides with the reserved breaking change token, write it 'BREAKING CHANGE' or 'BREAKING-CHANGE'")
+            )),
+            errs
+        );
+    }
+
+    #[rstest]
+    #[case::normal_token("Breaking-Changes")]
+    #[case::space("BREAKING CHANGE")]
+    #[case::hyphen("BREAKING-CHANGE")]
+    fn test_accepts_token_not_colliding_with_breaking_change(#[case] token: &str) {
+        assert!(Footer::builder(token).value("drops v1").build().is_ok());
+    }
+
+    #[rstest]
+    #[case::valid(Footer { token: "Refs".into(), separator: FooterSeparator::Colon, value: "PROJ-123".into() }, Ok(()))]
+    #[case::multi_line_value(Footer { token: "BREAKING CHANGE".into(), separator: FooterSeparator::Colon, value: "drops v1\n  and v2".into() }, Ok(()))]
+    #[case::empty_value(
+        Footer { token: "Refs".into(), separator: FooterSeparator::Colon, value: String::new() },
+        Err(multi_error!(ValidationError::MissingRequiredField("footer.value".into())))
+    )]
+    #[case::token_with_space(
+        Footer { token: "Reviewed by".into(), separator: FooterSeparator::Colon, value: "Alice".into() },
+        Err(multi_error!(ValidationError::InvalidFieldValue("footer.token".into(), anyhow!("'Reviewed by' must only contain letters, digits or '-'"))))
+    )]
+    #[case::empty_token_and_hash_value(
+        Footer { token: String::new(), separator: FooterSeparator::Hash, value: "42".into() },
+        Err(multi_error!(
+            ValidationError::MissingRequiredField("footer.token".into()),
+            ValidationError::InvalidFieldValue("footer.value".into(), anyhow!("'42' must start with '#' to be separated from the token by ' #'"))
+        ))
+    )]
+    fn test_validates_footer(#[case] footer: Footer, #[case] expect: Result<(), ValidationErrors>) {
+        assert_eq!(expect, footer.validate());
+    }
+
+    #[test]
+    fn test_validates_all_footers() {
+        let footers = [
+            Footer {
+                token: "Refs".into(),
+                separator: FooterSeparator::Colon,
+                value: "PROJ-1".into(),
+            },
+            Footer {
+                token: "Refs".into(),
+                separator: FooterSeparator::Colon,
+                value: " ".into(),
+            },
+            Footer {
+                token: "Closed by".into(),
+                separator: FooterSeparator::Colon,
+                value: "Bob".into(),
+            },
+        ];
+
+        assert_eq!(
+            Err(multi_error!(
+                ValidationError::MissingRequiredField("footer.value".into()),
+                ValidationError::InvalidFieldValue("footer.token".into(), anyhow!("'Closed by' must only contain letters, digits or '-'"))
+            )),
+            crate::model::validate_all(&footers)
+        );
+    }
+
+    #[rstest]
+    #[case::same("Refs: PROJ-1", "Refs: PROJ-1", true)]
+    #[case::different_token("Refs: PROJ-1", "Closes: PROJ-1", false)]
+    #[case::different_value("Refs: PROJ-1", "Refs: PROJ-2", false)]
+    #[case::different_separator("Closes: #42", "Closes #42", false)]
+    fn test_compares_footers(#[case] a: &str, #[case] b: &str, #[case] expect: bool) {
+        let parse = |line: &str| line.parse::<Footer>().expect("should have parsed the footer");
+        assert_eq!(expect, parse(a) == parse(b));
+    }
+
+    #[rstest]
+    #[case::single_line(&["Refs: PROJ-1"], vec!["PROJ-1"])]
+    #[case::footers(&["Refs: PROJ-1", "Closes #42"], vec!["PROJ-1", "#42"])]
+    #[case::continued_value(&["BREAKING CHANGE: drops v1", "  and v2", "Refs: PROJ-1"], vec!["drops v1\n  and v2", "PROJ-1"])]
+    fn test_parses_paragraph(#[case] lines: &[&str], #[case] expect: Vec<&str>) {
+        let footers: Vec<Footer> = parse_paragraph(lines)
+            .map(|(_, footer)| footer)
+            .collect::<Result<_, _>>()
+            .expect("should have parsed the footers");
+        assert_eq!(expect, footers.iter().map(Footer::value).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::footer("Refs: PROJ-123", true)]
+    #[case::breaking_change("BREAKING CHANGE: drops v1", true)]
+    #[case::sentence("Note that the parser is now stricter: it rejects tabs", false)]
+    #[case::empty_value("Refs: ", false)]
+    #[case::issue_reference("Closes #42", true)]
+    #[case::bare_hash("Closes #", false)]
+    #[case::colliding_token("Breaking Change: drops v1", true)]
+    #[case::sentence_with_issue_reference("Related to #42", false)]
+    fn test_detects_footer_lines(#[case] line: &str, #[case] expect: bool) {
+        assert_eq!(expect, is_footer_line(line));
+    }
 }
@@ -0,0 +1,228 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! The block of footers closing a commit message.
+//!
+//! Footers are read from the last paragraph of a message, and only when it starts with a footer. The lines of the
+//! paragraph that don't start a footer continue the value of the one before. The first paragraph is the header, so it
+//! never holds footers even when it looks like one.
+//!
+//! Tokens are looked up case-insensitively, as git does for trailers, so `Co-Authored-By` finds a `Co-authored-by`
+//! footer, while the footers keep the casing they were written with. The `_exact` variants compare tokens as they are.
+//! The breaking change footer is the exception: a footer only describes a breaking change when its token is the
+//! uppercase `BREAKING CHANGE` or `BREAKING-CHANGE`, so check [`Footer::is_breaking`] rather than looking it up.
+
+use crate::model::footer::{self, Footer};
+use std::fmt::{Display, Formatter};
+
+/// The footers of a commit message, in the order they appear.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Footers(Vec<Footer>);
+
+impl Footers {
+    /// Reads the footers of a message, without requiring the rest of it to be a valid conventional commit.
+    ///
+    /// # Arguments
+    /// * `message` - The commit message, trailing white-space is ignored.
+    ///
+    /// # Returns
+    /// The footers in the last paragraph, or no footers if it doesn't start with a footer.
+    #[must_use]
+    pub fn parse(message: &str) -> Self {
+        let lines: Vec<&str> = message.trim_end().lines().collect();
+        let last_paragraph = lines.iter().rposition(|l| l.trim().is_empty()).map_or(lines.len(), |i| i + 1);
+        let paragraph = &lines[last_paragraph..];
+
+        if !paragraph.first().is_some_and(|l| footer::is_footer_line(l)) {
+            return Footers::default();
+        }
+
+        Footers(footer::parse_paragraph(paragraph).filter_map(|(_, footer)| footer.ok()).collect())
+    }
+
+    /// Returns the first footer using the given token, ignoring case.
+    ///
+    /// # Arguments
+    /// * `token` - The token to look for, ex: `Co-authored-by`.
+    ///
+    /// # Returns
+    /// * `Some(&Footer)` with the first footer whose token only differs in case, if any.
+    /// * `None` if no footer uses the token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::Footers;
+    /// let footers = Footers::parse("feat: add lexer\n\nCo-Authored-By: Alice <alice@test.io>");
+    ///
+    /// assert_eq!(Some("Co-Authored-By"), footers.get("Co-authored-by").map(|f| f.token()));
+    /// assert_eq!(None, footers.get_exact("Co-authored-by"));
+    /// ```
+    #[must_use]
+    pub fn get(&self, token: &str) -> Option<&Footer> {
+        self.0.iter().find(|f| f.token().eq_ignore_ascii_case(token))
+    }
+
+    /// Returns the first footer using exactly the given token.
+    ///
+    /// # Arguments
+    /// * `token` - The token to look for, with the casing it must be written with, ex: `Refs`.
+    ///
+    /// # Returns
+    /// * `Some(&Footer)` with the first footer using the token.
+    /// * `None` if no footer uses the token as written.
+    #[must_use]
+    pub fn get_exact(&self, token: &str) -> Option<&Footer> {
+        self.0.iter().find(|f| f.token() == token)
+    }
+
+    /// Checks whether any footer uses the given token, ignoring case.
+    ///
+    /// # Arguments
+    /// * `token` - The token to look for, ex: `Refs`.
+    ///
+    /// # Returns
+    /// `true` if at least one footer has this token, in any case.
+    #[must_use]
+    pub fn has_token(&self, token: &str) -> bool {
+        self.get(token).is_some()
+    }
+
+    /// Checks whether any footer uses exactly the given token.
+    ///
+    /// # Arguments
+    /// * `token` - The token to look for, ex: `Refs`.
+    ///
+    /// # Returns
+    /// `true` if at least one footer has exactly this token.
+    #[must_use]
+    pub fn has_token_exact(&self, token: &str) -> bool {
+        self.get_exact(token).is_some()
+    }
+
+    /// Checks whether a footer with the same token, ignoring case, and value is present.
+    ///
+    /// # Arguments
+    /// * `footer` - The footer to look for. The separator is not compared.
+    ///
+    /// # Returns
+    /// `true` if a footer has the same token, in any case, and value.
+    #[must_use]
+    pub fn contains(&self, footer: &Footer) -> bool {
+        self.0.iter().any(|f| f.token().eq_ignore_ascii_case(footer.token()) && f.value() == footer.value())
+    }
+
+    /// Appends a footer after the existing ones.
+    ///
+    /// # Arguments
+    /// * `footer` - The footer to append.
+    pub fn push(&mut self, footer: Footer) {
+        self.0.push(footer);
+    }
+
+    /// Returns an iterator over the footers, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Footer> {
+        self.0.iter()
+    }
+
+    /// Returns the number of footers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether there are no footers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<Footer>> for Footers {
+    fn from(footers: Vec<Footer>) -> Self {
+        Footers(footers)
+    }
+}
+
+/// Writes one footer per line, without a trailing line break.
+impl Display for Footers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, footer) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{footer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::model::Build;
+    use rstest::rstest;
+
+    fn footer(token: &str, value: &str) -> Footer {
+        Footer::builder(token).value(value).build().expect("should have built the footer")
+    }
+
+    #[rstest]
+    #[case::footers("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1\nCloses #42\n", vec!["Refs: PROJ-1", "Closes #42"])]
+    #[case::no_body("fix: handle tabs\n\nRefs: PROJ-1", vec!["Refs: PROJ-1"])]
+    #[case::not_conventional("Update README\n\nRefs: PROJ-1", vec!["Refs: PROJ-1"])]
+    #[case::header_only("Refs: PROJ-1", vec![])]
+    #[case::continued_value("feat: drop v1\n\nBREAKING CHANGE: drops v1\nand v2\nRefs: PROJ-1", vec!["BREAKING CHANGE: drops v1\nand v2", "Refs: PROJ-1"])]
+    #[case::last_paragraph_not_starting_with_footer("feat: add lexer\n\nnot a footer\nRefs: PROJ-1", vec![])]
+    #[case::empty("", vec![])]
+    fn test_parses_footers(#[case] message: &str, #[case] expect: Vec<&str>) {
+        assert_eq!(expect, Footers::parse(message).iter().map(ToString::to_string).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_finds_footers() {
+        let footers = Footers::parse("feat: add lexer\n\nRefs: PROJ-1\nReviewed-by: Alice");
+
+        assert!(footers.has_token("Refs"));
+        assert!(footers.has_token("refs"));
+        assert!(!footers.has_token("Closes"));
+        assert!(footers.contains(&footer("Refs", "PROJ-1")));
+        assert!(footers.contains(&footer("REFS", "PROJ-1")));
+        assert!(!footers.contains(&footer("Refs", "PROJ-2")));
+    }
+
+    #[rstest]
+    #[case::same_case("Co-authored-by", Some("Co-Authored-By: Alice"), Some("Co-authored-by: Bob"))]
+    #[case::mixed_case("co-AUTHORED-by", Some("Co-Authored-By: Alice"), None)]
+    #[case::title_case("Co-Authored-By", Some("Co-Authored-By: Alice"), Some("Co-Authored-By: Alice"))]
+    #[case::breaking_change("breaking change", Some("BREAKING CHANGE: drops v1"), None)]
+    #[case::absent("Refs", None, None)]
+    fn test_gets_footer_by_token(#[case] token: &str, #[case] expect: Option<&str>, #[case] expect_exact: Option<&str>) {
+        let footers = Footers::parse("feat: add lexer\n\nCo-Authored-By: Alice\nCo-authored-by: Bob\nBREAKING CHANGE: drops v1");
+
+        assert_eq!(expect.map(String::from), footers.get(token).map(ToString::to_string));
+        assert_eq!(expect_exact.map(String::from), footers.get_exact(token).map(ToString::to_string));
+        assert_eq!(expect.is_some(), footers.has_token(token));
+        assert_eq!(expect_exact.is_some(), footers.has_token_exact(token));
+    }
+
+    #[test]
+    fn test_displays_footers_one_per_line() {
+        let mut footers = Footers::parse("feat: add lexer\n\nRefs: PROJ-1");
+        footers.push(footer("Closes", "#42"));
+
+        assert_eq!(2, footers.len());
+        assert_eq!("Refs: PROJ-1\nCloses #42", footers.to_string());
+    }
+}
@@ -1,15 +1,74 @@
 //! Model types and traits for conventional commit parsing and validation.
 //!
 //! This module defines core data structures, builders, and validation traits for conventional commits
+//!
+//! Without the `std` feature, only the validation errors and traits are available, the components require `std`.
 
 use crate::errors::Errors;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 use anyhow::Error as AnyError;
 use thiserror::Error;
 
-// mod footer;
+#[cfg(feature = "std")]
+mod assembler;
+#[cfg(feature = "std")]
+mod body;
+#[cfg(feature = "std")]
+mod commit;
+#[cfg(feature = "std")]
+mod commit_diff;
+#[cfg(feature = "std")]
+mod commit_kind;
+#[cfg(feature = "std")]
+mod commit_type;
+#[cfg(feature = "std")]
+mod description;
+#[cfg(feature = "std")]
+mod footer;
+#[cfg(feature = "std")]
+mod footers;
+#[cfg(feature = "std")]
 mod person;
+#[cfg(feature = "std")]
+mod scope;
+#[cfg(feature = "std")]
+mod version_bump;
 
+#[cfg(feature = "std")]
+pub use assembler::CommitAssembler;
+#[cfg(feature = "std")]
+pub use body::Body;
+#[cfg(feature = "std")]
+pub use commit::{Commit, CommitBuilder, ParseOptions};
+#[cfg(feature = "std")]
+pub use commit_diff::CommitDiff;
+#[cfg(feature = "std")]
+pub use commit_kind::CommitKind;
+#[cfg(feature = "std")]
+pub(crate) use commit_type::KNOWN_TYPES;
+#[cfg(feature = "std")]
+pub use commit_type::{CommitType, CommitTypeSet};
+#[cfg(feature = "std")]
+pub use description::Description;
+#[cfg(feature = "std")]
+pub use footer::{BreakingChangeToken, ClosingKeyword, Footer, FooterBuilder, FooterSeparator};
+#[cfg(feature = "std")]
+pub use footers::Footers;
+#[cfg(feature = "std")]
+pub(crate) use person::CANONICAL_RELATIONSHIPS;
+#[cfg(feature = "std")]
 pub use person::{Person, PersonBuilder};
+#[cfg(feature = "std")]
+pub use scope::Scope;
+#[cfg(feature = "std")]
+pub use version_bump::VersionBump;
+
+/// The reason an email address is invalid, re-exported so callers can match on it without depending on `email_address`.
+#[cfg(feature = "std")]
+pub use email_address::Error as EmailError;
 
 type ValidationErrors = Errors<ValidationError>;
 
@@ -31,6 +90,49 @@ pub trait Build<T> {
     fn build(&mut self) -> Result<T, ValidationErrors>;
 }
 
+/// A trait for checking that an existing object follows the model's rules.
+///
+/// Builders validate what they build, so this is for re-checking values that may have been assembled some other way,
+/// and for checking a whole collection at once with [`validate_all`].
+pub trait Validate {
+    /// Checks the object against the model's rules.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the object is valid.
+    /// * `Err(ValidationErrors)` with every rule violation found otherwise.
+    ///
+    /// # Errors
+    ///
+    /// `ValidationErrors` name the offending field, prefixed with the kind of object, ex: `footer.token`.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// Validates every item, collecting all their errors.
+///
+/// # Arguments
+/// * `items` - The items to validate.
+///
+/// # Returns
+/// * `Ok(())` if every item is valid.
+/// * `Err(ValidationErrors)` with the errors of every invalid item, in order.
+///
+/// # Errors
+///
+/// Returns the merged `ValidationErrors` of the invalid items.
+pub fn validate_all<'a, T>(items: impl IntoIterator<Item = &'a T>) -> Result<(), ValidationErrors>
+where
+    T: Validate + 'a,
+{
+    let mut errs = Errors::new();
+    for item in items {
+        if let Err(e) = item.validate() {
+            errs.merge(e);
+        }
+    }
+
+    errs.into_result(())
+}
+
 /// Errors that can occur during validation of conventional commit components.
 ///
 /// These errors represent specific validation failures that can occur
@@ -56,6 +158,58 @@ pub enum ValidationError {
     /// * `1` - The reason it's invalid
     #[error("field '{0}' has invalid value: {1}")]
     InvalidFieldValue(String, #[source] AnyError),
+
+    /// Error indicating the commit breaks a rule that isn't about a single field's value, ex: a lint or commitlint rule.
+    ///
+    /// # Parameters
+    ///
+    /// * `rule` - The stable identifier of the rule, in `kebab-case`, ex: `subject-full-stop`
+    /// * `message` - What breaks the rule, displayed as the error message
+    #[error("{message}")]
+    RuleViolation {
+        /// The stable identifier of the rule, in `kebab-case`.
+        rule: String,
+        /// What breaks the rule.
+        message: String,
+    },
+}
+
+impl ValidationError {
+    /// Returns the identifier of the rule the error comes from, ex: `description-max-length`.
+    ///
+    /// `RuleViolation` errors report their rule. Otherwise the rule is named after the field, as `<field>-required` for a
+    /// missing field and `<field>-invalid` for an invalid value, ex: `scope-required`.
+    ///
+    /// # Returns
+    /// The rule identifier, in `kebab-case`.
+    #[must_use]
+    pub fn rule(&self) -> String {
+        match self {
+            ValidationError::MissingRequiredField(field) => format!("{}-required", field.replace(' ', "-")),
+            ValidationError::InvalidFieldValue(field, _) => format!("{}-invalid", field.replace(' ', "-")),
+            ValidationError::RuleViolation { rule, .. } => rule.clone(),
+        }
+    }
+}
+
+/// Converts an email address error into an `InvalidFieldValue` for the `email` field.
+///
+/// The original error is kept as the source, so callers can downcast it to give tailored hints, ex: a missing `@`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # use conventional_commit::model::{EmailError, ValidationError};
+/// let err = ValidationError::from(EmailError::MissingSeparator);
+///
+/// assert_eq!(Some(&EmailError::MissingSeparator), err.source().and_then(|e| e.downcast_ref::<EmailError>()));
+/// ```
+#[cfg(feature = "std")]
+impl From<EmailError> for ValidationError {
+    fn from(err: EmailError) -> Self {
+        ValidationError::InvalidFieldValue("email".into(), AnyError::from(err))
+    }
 }
 
 /// Implementation of `PartialEq` for `ValidationError` to enable comparison in tests.
@@ -63,11 +217,15 @@ pub enum ValidationError {
 /// Two `ValidationError` instances are considered equal if:
 /// - They are both `MissingRequiredField` errors with the same field name
 /// - They are both `InvalidFieldValue` errors with the same field name and error message
+/// - They are both `RuleViolation` errors with the same rule and message
 impl PartialEq for ValidationError {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (ValidationError::MissingRequiredField(a), ValidationError::MissingRequiredField(b)) => b == a,
             (ValidationError::InvalidFieldValue(a_str, a_err), ValidationError::InvalidFieldValue(b_str, b_err)) => a_str == b_str && a_err.to_string() == b_err.to_string(),
+            (ValidationError::RuleViolation { rule: a_rule, message: a_msg }, ValidationError::RuleViolation { rule: b_rule, message: b_msg }) => {
+                a_rule == b_rule && a_msg == b_msg
+            }
             (_, _) => false,
         }
     }
@@ -83,7 +241,28 @@ mod tests {
     #[rstest]
     #[case::missing_required_field(ValidationError::MissingRequiredField("test".into()), "field 'test' is required")]
     #[case::invalid_field_value(ValidationError::InvalidFieldValue("test".into(), anyhow!("boom")), "field 'test' has invalid value: boom")]
+    #[case::rule_violation(ValidationError::RuleViolation { rule: "trailer-exists".into(), message: "must include a 'Refs' footer".into() }, "must include a 'Refs' footer")]
     fn test_display_error(#[case] err: ValidationError, #[case] expect: impl Into<String>) {
         assert_eq!(expect.into(), format!("{err}"));
     }
+
+    #[rstest]
+    #[case::missing_field(ValidationError::MissingRequiredField("breaking change".into()), "breaking-change-required")]
+    #[case::invalid_value(ValidationError::InvalidFieldValue("footer.token".into(), anyhow!("boom")), "footer.token-invalid")]
+    #[case::rule_violation(ValidationError::RuleViolation { rule: "type-enum".into(), message: "boom".into() }, "type-enum")]
+    fn test_returns_rule_of_error(#[case] err: ValidationError, #[case] expect: &str) {
+        assert_eq!(expect, err.rule());
+    }
+
+    #[rstest]
+    #[case::missing_separator(EmailError::MissingSeparator)]
+    #[case::invalid_domain(EmailError::DomainInvalidSeparator)]
+    fn test_email_error_is_kept_as_source(#[case] email_err: EmailError) {
+        use std::error::Error as _;
+
+        let err = ValidationError::from(email_err.clone());
+
+        assert_eq!(ValidationError::InvalidFieldValue("email".into(), email_err.clone().into()), err);
+        assert_eq!(Some(&email_err), err.source().and_then(|e| e.downcast_ref::<EmailError>()));
+    }
 }
@@ -22,24 +22,47 @@
 
 use crate::{
     errors::Errors,
-    model::{Build, ValidationError, ValidationErrors},
+    model::{Build, ValidationError, ValidationErrors, footer},
 };
 use anyhow::anyhow;
 use derive_builder::Builder;
 use email_address::EmailAddress;
 use std::{
+    collections::HashSet,
     default::Default,
     fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
     str::FromStr,
 };
 
 /// The default relationship string used for co-authors in the commit message.
 const DEFAULT_RELATIONSHIP: &str = "Co-Authored-By";
 
+/// The canonical spellings of the well-known git trailers naming people, as git and GitHub write them.
+pub(crate) const CANONICAL_RELATIONSHIPS: [&str; 8] = [
+    "Co-authored-by",
+    "Signed-off-by",
+    "Reviewed-by",
+    "Acked-by",
+    "Tested-by",
+    "Reported-by",
+    "Suggested-by",
+    "Helped-by",
+];
+
+/// The format of a person written as a string, shown when it is malformed.
+const PERSON_FORMAT: &str = "[<relationship>: ]<name>[ <<email>>]";
+
 /// Represents a person (ex: author, co-author, or reviewer) in a Git commit.
 ///
 /// A `Person` consists of a name, a relationship to the commit, and an optional email address. The name is required,
-/// and if an email is provided, it must be a valid email address according to RFC 5322.
+/// and if an email is provided, it must be a valid email address according to RFC 5322. Comments in the email, ex:
+/// `alice@example.com (work)`, are stripped when building, so the trailer only ever shows the address.
+///
+/// Two people are equal when their name and relationship are exactly the same and their emails only differ in case, as
+/// email addresses are used case-insensitively in practice. Hashing lowercases the email the same way, so `Alice
+/// <A@X.io>` and `Alice <a@x.io>` are a single entry in a `HashSet`. Use [`Person::dedup`] to also treat differently
+/// cased relationships, or the same email under different names, as the same person.
 #[derive(Builder, Clone, Debug)]
 #[builder(build_fn(skip))]
 pub struct Person {
@@ -95,9 +118,174 @@ impl Person {
     pub fn email(&self) -> Option<&str> {
         self.email.as_deref()
     }
+
+    /// Returns the person with a well-known relationship spelled the canonical way, ex: `Co-authored-by`.
+    ///
+    /// Git recognises trailers in any case, but GitHub only links the avatars of co-authors named by a `Co-authored-by`
+    /// trailer, so this should be used when emitting trailers. The relationships known are `Co-authored-by`,
+    /// `Signed-off-by`, `Reviewed-by`, `Acked-by`, `Tested-by`, `Reported-by`, `Suggested-by` and `Helped-by`, any other
+    /// relationship is left as it is.
+    ///
+    /// # Returns
+    /// The `Person` with the same name and email, and the relationship in its canonical casing when it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Build, Person};
+    /// let person = Person::builder("Alice").email("alice@example.com").build().unwrap();
+    ///
+    /// assert_eq!("Co-Authored-By: Alice <alice@example.com>", person.to_string());
+    /// assert_eq!("Co-authored-by: Alice <alice@example.com>", person.normalized().to_string());
+    /// ```
+    #[must_use]
+    pub fn normalized(&self) -> Person {
+        let relationship = self.relationship.trim();
+        let relationship = CANONICAL_RELATIONSHIPS
+            .into_iter()
+            .find(|canonical| canonical.eq_ignore_ascii_case(relationship))
+            .map_or_else(|| self.relationship.clone(), str::to_string);
+
+        Person { relationship, ..self.clone() }
+    }
+
+    /// Removes duplicate people, ex: the repeated `Co-Authored-By` trailers of a squashed commit.
+    ///
+    /// Two people are duplicates if they have the same relationship and the same email, or the same name when neither
+    /// has an email. Emails and relationships are compared case-insensitively, and surrounding white-space is ignored.
+    ///
+    /// # Arguments
+    /// * `people` - The people to deduplicate.
+    ///
+    /// # Returns
+    /// The first occurrence of each person, in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Build, Person};
+    /// let people = [
+    ///     Person::builder("Alice").email("alice@example.com").build().unwrap(),
+    ///     Person::builder("Bob").build().unwrap(),
+    ///     Person::builder("Alice B.").email("Alice@Example.com").build().unwrap(),
+    /// ];
+    ///
+    /// let names: Vec<_> = Person::dedup(people).iter().map(|p| p.name().to_string()).collect();
+    /// assert_eq!(names, ["Alice", "Bob"]);
+    /// ```
+    pub fn dedup(people: impl IntoIterator<Item = Person>) -> Vec<Person> {
+        let mut seen = HashSet::new();
+
+        people.into_iter().filter(|person| seen.insert(person.identity())).collect()
+    }
+
+    /// Merges two records of the same person, ex: a co-author from the branch metadata with the git configuration.
+    ///
+    /// The fields of the person are kept, with the gaps filled from the other: the email when the person has none, and
+    /// the relationship when the person has the default `Co-Authored-By` one. Fields both set to different values
+    /// conflict. Names and relationships are compared ignoring surrounding white-space, and relationships and emails
+    /// ignoring case, like in [`Person::dedup`]. The merged person is validated again, like when built.
+    ///
+    /// # Arguments
+    /// * `other` - The record filling the gaps.
+    ///
+    /// # Returns
+    /// * `Ok(Person)` with the fields of both records.
+    /// * `Err(ValidationErrors)` if fields conflict or the merged person is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidFieldValue` for each of the `name`, `email` and `relationship` fields that conflict.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Build, Person};
+    /// let author = Person::builder("Alice").relationship("Signed-off-by").build().unwrap();
+    /// let identity = Person::builder("Alice").email("alice@example.com").build().unwrap();
+    ///
+    /// assert_eq!("Signed-off-by: Alice <alice@example.com>", author.clone().merge(identity).unwrap().to_string());
+    /// assert!(author.merge(Person::builder("Bob").build().unwrap()).is_err());
+    /// ```
+    pub fn merge(self, other: Person) -> Result<Person, ValidationErrors> {
+        let mut errs = Errors::new();
+        let conflict = |field: &str, a: &str, b: &str| ValidationError::InvalidFieldValue(field.into(), anyhow!("'{a}' conflicts with '{b}'"));
+
+        if self.name.trim() != other.name.trim() {
+            errs.append(conflict("name", &self.name, &other.name));
+        }
+        if let (Some(a), Some(b)) = (&self.email, &other.email)
+            && self.lowercase_email() != other.lowercase_email()
+        {
+            errs.append(conflict("email", a, b));
+        }
+
+        let is_default = |relationship: &str| relationship.trim().eq_ignore_ascii_case(DEFAULT_RELATIONSHIP);
+        if !is_default(&self.relationship) && !is_default(&other.relationship) && !self.relationship.trim().eq_ignore_ascii_case(other.relationship.trim()) {
+            errs.append(conflict("relationship", &self.relationship, &other.relationship));
+        }
+        errs.into_result(())?;
+
+        let relationship = if is_default(&self.relationship) { other.relationship } else { self.relationship };
+
+        let mut builder = Person::builder(self.name);
+        builder.relationship(relationship);
+        if let Some(email) = self.email.or(other.email) {
+            builder.email(email);
+        }
+        builder.build()
+    }
+
+    /// Returns the email in lowercase, as compared and hashed.
+    fn lowercase_email(&self) -> Option<String> {
+        self.email.as_deref().map(str::to_lowercase)
+    }
+
+    /// Returns the normalised relationship and email, or name when there is no email, identifying the person.
+    fn identity(&self) -> (String, String) {
+        let id = match self.email.as_deref() {
+            Some(email) => email.trim().to_lowercase(),
+            None => self.name.trim().to_string(),
+        };
+
+        (self.relationship.trim().to_lowercase(), id)
+    }
 }
 
 impl PersonBuilder {
+    /// Sets the name and email together from a `Name <email>` or bare `Name` spec, ex: a trailer value.
+    ///
+    /// The email is validated at build time, like when set on its own. When the angle brackets are malformed, ex:
+    /// `Alice <alice@x.io`, the text from the first bracket is kept as the email so building reports it as invalid.
+    ///
+    /// # Arguments
+    /// * `spec` - The name, optionally followed by the email in angle brackets.
+    ///
+    /// # Returns
+    /// The builder, for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use conventional_commit::model::{Build, Person};
+    /// let person = Person::builder("").from_spec("Alice <alice@x.io>").build().unwrap();
+    ///
+    /// assert_eq!(person.name(), "Alice");
+    /// assert_eq!(person.email(), Some("alice@x.io"));
+    /// assert!(Person::builder("").from_spec("Alice <alice@x.io").build().is_err());
+    /// ```
+    pub fn from_spec(&mut self, spec: &str) -> &mut Self {
+        let (name, email) = split_spec(spec).unwrap_or_else(|| {
+            let spec = spec.trim();
+            let bracket = spec.find(['<', '>']).unwrap_or_default();
+            (spec[..bracket].trim_end(), Some(&spec[bracket..]))
+        });
+
+        self.name = Some(name.to_string());
+        self.email = Some(email.map(str::to_string));
+        self
+    }
+
     /// Validates the name field for the `Person`.
     ///
     /// # Returns
@@ -115,14 +303,18 @@ impl PersonBuilder {
 
     /// Validates the email field for the `Person`.
     ///
+    /// RFC 5322 comments, ex: `(work)` in `alice@example.com (work)`, are stripped so only the address is kept.
+    ///
     /// # Returns
-    /// * `Ok(Some(String))` if the email is valid.
+    /// * `Ok(Some(String))` with the address if the email is valid.
     /// * `Ok(None)` if no email is provided.
-    /// * `Err(ValidationError)` if the email is invalid.
+    /// * `Err(ValidationError)` if the email is invalid, with the `email_address::Error` as its source.
     fn validate_email(&mut self) -> Result<Option<String>, ValidationError> {
         if let Some(Some(email)) = self.email.clone() {
+            let email = strip_comments(&email);
+
             if let Err(e) = EmailAddress::from_str(email.as_str()) {
-                Err(ValidationError::InvalidFieldValue("email".to_string(), anyhow!(e)))
+                Err(e.into())
             } else {
                 Ok(Some(email))
             }
@@ -131,6 +323,44 @@ impl PersonBuilder {
         }
     }
 
+    /// Checks that the name is not an email address, a common mistake when the email was meant to go in its own field.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the name is not an email address.
+    /// * `Err(ValidationError)` suggesting to set the email field instead.
+    fn validate_name_is_not_email(&self) -> Result<(), ValidationError> {
+        match self.name.as_deref() {
+            Some(name) if EmailAddress::is_valid(name) => Err(ValidationError::InvalidFieldValue(
+                "name".to_string(),
+                anyhow!("'{name}' looks like an email address, set it as the email instead"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validates and builds a `Person` instance, also rejecting a name that is an email address.
+    ///
+    /// This is an opt-in, stricter alternative to `Build::build`, which accepts any non-empty name.
+    ///
+    /// # Returns
+    /// * `Ok(Person)` if validation passes.
+    /// * `Err(ValidationErrors)` if validation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Build::build`, plus an `InvalidFieldValue` for the name if it is an email address.
+    pub fn build_strict(&mut self) -> Result<Person, ValidationErrors> {
+        match (self.build(), self.validate_name_is_not_email()) {
+            (Ok(person), Ok(())) => Ok(person),
+            (Ok(_), Err(e)) => Err(Errors::from([e])),
+            (Err(mut errs), Err(e)) => {
+                errs.append(e);
+                Err(errs)
+            }
+            (Err(errs), Ok(())) => Err(errs),
+        }
+    }
+
     /// Returns the relationship or the default if not set.
     ///
     /// # Returns
@@ -144,6 +374,50 @@ impl PersonBuilder {
     }
 }
 
+/// Splits a `Name <email>` or bare `Name` spec into the name and email, or `None` if the angle brackets are malformed.
+fn split_spec(spec: &str) -> Option<(&str, Option<&str>)> {
+    let spec = spec.trim();
+
+    if !spec.contains(['<', '>']) {
+        return Some((spec, None));
+    }
+
+    let (name, email) = spec.strip_suffix('>')?.split_once('<')?;
+    if email.contains(['<', '>']) {
+        return None;
+    }
+
+    Some((name.trim_end(), Some(email.trim())))
+}
+
+/// Removes the parenthesised comments from an email address, leaving quoted text untouched.
+fn strip_comments(email: &str) -> String {
+    let mut address = String::with_capacity(email.len());
+    let mut depth = 0usize;
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for c in email.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' if depth == 0 => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted && depth > 0 => {
+                depth -= 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        if depth == 0 {
+            address.push(c);
+        }
+    }
+
+    address.trim().to_string()
+}
+
 /// Implementation of the `Build` trait for `PersonBuilder`.
 ///
 /// This implementation validates that:
@@ -170,7 +444,64 @@ impl Build<Person> for PersonBuilder {
             None
         });
 
-        if errs.is_empty() { Ok(Person { name, relationship, email }) } else { Err(errs) }
+        errs.into_result(Person { name, email, relationship })
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Person`.
+///
+/// Parses the `Name <email>` and bare `Name` forms, optionally preceded by the relationship as in a trailer, ex:
+/// `Reviewed-by: Alice Bob <alice@bob.io>`, so a `Person` round-trips through its `Display`. The relationship defaults
+/// to `Co-Authored-By`, and the name and email are validated as when building.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::Person;
+/// let person: Person = "Alice Bob <alice@bob.io>".parse().unwrap();
+///
+/// assert_eq!(person.name(), "Alice Bob");
+/// assert_eq!(person.email(), Some("alice@bob.io"));
+/// assert_eq!(person.to_string().parse::<Person>().unwrap(), person);
+/// ```
+impl FromStr for Person {
+    type Err = ValidationErrors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || Errors::from([ValidationError::InvalidFieldValue("person".into(), anyhow!("'{s}' must be formatted as '{PERSON_FORMAT}'"))]);
+
+        let (relationship, person) = match s.trim().split_once(':') {
+            Some((token, person)) if footer::is_valid_token(token) => (Some(token), person.trim_start()),
+            _ => (None, s.trim()),
+        };
+
+        let (name, email) = split_spec(person).ok_or_else(malformed)?;
+
+        let mut builder = Person::builder(name);
+        if let Some(relationship) = relationship {
+            builder.relationship(relationship);
+        }
+        if let Some(email) = email {
+            builder.email(email);
+        }
+        builder.build()
+    }
+}
+
+impl PartialEq for Person {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.relationship == other.relationship && self.lowercase_email() == other.lowercase_email()
+    }
+}
+
+impl Eq for Person {}
+
+/// Hashes the name, relationship and lowercased email, consistently with `PartialEq`.
+impl Hash for Person {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.relationship.hash(state);
+        self.lowercase_email().hash(state);
     }
 }
 
@@ -229,6 +560,175 @@ mod test {
         assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
     }
 
+    #[rstest]
+    #[case::missing_separator("invalid", EmailError::MissingSeparator)]
+    #[case::missing_local_part("@test.io", EmailError::LocalPartEmpty)]
+    fn test_keeps_email_error_as_source(#[case] email: &str, #[case] expect: EmailError) {
+        use std::error::Error as _;
+
+        let errs = Person::builder("Alice Bob").email(email).build().expect_err("should have failed");
+        let source = errs.source().and_then(|e| e.source()).and_then(|e| e.downcast_ref::<EmailError>());
+
+        assert_eq!(Some(&expect), source);
+    }
+
+    #[rstest]
+    #[case::name_is_email(
+        Person::builder("alice@example.com"),
+        multi_error!(ValidationError::InvalidFieldValue("name".to_string(), anyhow!("'alice@example.com' looks like an email address, set it as the email instead")))
+    )]
+    #[case::name_is_email_and_email_invalid(
+        Person::builder("alice@example.com").email("invalid").clone(),
+        multi_error!(
+            ValidationError::InvalidFieldValue("email".to_string(), EmailError::MissingSeparator.into()),
+            ValidationError::InvalidFieldValue("name".to_string(), anyhow!("'alice@example.com' looks like an email address, set it as the email instead"))
+        )
+    )]
+    fn test_return_error_strictly_building_person(#[case] mut person: PersonBuilder, #[case] expect: ValidationErrors) {
+        let errs = person.build_strict().expect_err("should have failed");
+        assert_eq!(expect, errs, "expected: {expect}\n but got: {errs}");
+    }
+
+    #[rstest]
+    #[case::normal_name(Person::builder("Alice Bob"))]
+    #[case::normal_name_with_email(Person::builder("Alice Bob").email("alice@example.com").clone())]
+    fn test_strictly_builds_person(#[case] mut person: PersonBuilder) {
+        assert_eq!("Alice Bob", person.build_strict().expect("should have built a person").name());
+    }
+
+    #[test]
+    fn test_accepts_email_like_name_when_not_strict() {
+        let person = Person::builder("alice@example.com").build().expect("should have built a person");
+        assert_eq!("alice@example.com", person.name());
+    }
+
+    #[rstest]
+    #[case::trailing_comment("alice@example.com (work)", "alice@example.com")]
+    #[case::leading_comment("(work) alice@example.com", "alice@example.com")]
+    #[case::nested_comment("alice@example.com (work (main))", "alice@example.com")]
+    #[case::comment_around_separator("alice(work)@example.com", "alice@example.com")]
+    #[case::quoted_parentheses("\"alice(work)\"@example.com", "\"alice(work)\"@example.com")]
+    #[case::no_comment("alice@example.com", "alice@example.com")]
+    fn test_strips_comments_from_email(#[case] email: &str, #[case] expect: &str) {
+        let person = Person::builder("Alice").email(email).build().expect("should have built a person");
+
+        assert_eq!(Some(expect), person.email());
+        assert_eq!(format!("Co-Authored-By: Alice <{expect}>"), format!("{person}"));
+    }
+
+    #[test]
+    fn test_rejects_email_that_is_only_a_comment() {
+        let errs = Person::builder("Alice").email("(work)").build().expect_err("should have failed");
+        assert_eq!(
+            multi_error!(ValidationError::InvalidFieldValue("email".to_string(), EmailError::MissingSeparator.into())),
+            errs
+        );
+    }
+
+    #[rstest]
+    #[case::no_duplicates(vec![person("Alice", Some("alice@test.io")), person("Bob", None)], vec!["Co-Authored-By: Alice <alice@test.io>", "Co-Authored-By: Bob"])]
+    #[case::same_email_different_case(
+        vec![person("Alice", Some("alice@test.io")), person("Bob", None), person("Alice Bob", Some("ALICE@Test.io"))],
+        vec!["Co-Authored-By: Alice <alice@test.io>", "Co-Authored-By: Bob"]
+    )]
+    #[case::same_name_without_email(vec![person("Bob", None), person(" Bob ", None)], vec!["Co-Authored-By: Bob"])]
+    #[case::same_name_with_and_without_email(
+        vec![person("Bob", None), person("Bob", Some("bob@test.io"))],
+        vec!["Co-Authored-By: Bob", "Co-Authored-By: Bob <bob@test.io>"]
+    )]
+    #[case::different_relationship(
+        vec![person("Alice", Some("alice@test.io")), Person::builder("Alice").email("alice@test.io").relationship("Reviewed-By").build().expect("should have built a person")],
+        vec!["Co-Authored-By: Alice <alice@test.io>", "Reviewed-By: Alice <alice@test.io>"]
+    )]
+    #[case::relationship_case(
+        vec![person("Alice", Some("alice@test.io")), Person::builder("Alice").email("alice@test.io").relationship("Co-authored-by").build().expect("should have built a person")],
+        vec!["Co-Authored-By: Alice <alice@test.io>"]
+    )]
+    fn test_dedups_people(#[case] people: Vec<Person>, #[case] expect: Vec<&str>) {
+        let people: Vec<String> = Person::dedup(people).iter().map(ToString::to_string).collect();
+        assert_eq!(expect, people);
+    }
+
+    fn person(name: &str, email: Option<&str>) -> Person {
+        let mut builder = Person::builder(name);
+        if let Some(email) = email {
+            builder.email(email);
+        }
+        builder.build().expect("should have built a person")
+    }
+
+    #[rstest]
+    #[case::fills_email(person("Alice", None), person("Alice", Some("alice@test.io")), "Co-Authored-By: Alice <alice@test.io>")]
+    #[case::keeps_own_email(person("Alice", Some("alice@test.io")), person("Alice", None), "Co-Authored-By: Alice <alice@test.io>")]
+    #[case::same_email_different_case(person("Alice", Some("alice@test.io")), person(" Alice ", Some("ALICE@test.io")), "Co-Authored-By: Alice <alice@test.io>")]
+    #[case::fills_relationship(
+        person("Alice", Some("alice@test.io")),
+        Person::builder("Alice").relationship("Signed-off-by").build().expect("should have built a person"),
+        "Signed-off-by: Alice <alice@test.io>"
+    )]
+    #[case::keeps_own_relationship(
+        Person::builder("Alice").relationship("Reviewed-by").build().expect("should have built a person"),
+        Person::builder("Alice").relationship("reviewed-by").email("alice@test.io").build().expect("should have built a person"),
+        "Reviewed-by: Alice <alice@test.io>"
+    )]
+    fn test_merges_people(#[case] record: Person, #[case] other: Person, #[case] expect: &str) {
+        assert_eq!(expect, record.merge(other).expect("should have merged the people").to_string());
+    }
+
+    #[test]
+    fn test_rejects_conflicting_people() {
+        let alice = Person::builder("Alice")
+            .email("alice@test.io")
+            .relationship("Reviewed-by")
+            .build()
+            .expect("should have built a person");
+        let bob = Person::builder("Bob")
+            .email("bob@test.io")
+            .relationship("Signed-off-by")
+            .build()
+            .expect("should have built a person");
+
+        assert_eq!(
+            Err(multi_error!(
+                ValidationError::InvalidFieldValue("name".into(), anyhow!("'Alice' conflicts with 'Bob'")),
+                ValidationError::InvalidFieldValue("email".into(), anyhow!("'alice@test.io' conflicts with 'bob@test.io'")),
+                ValidationError::InvalidFieldValue("relationship".into(), anyhow!("'Reviewed-by' conflicts with 'Signed-off-by'"))
+            )),
+            alice.merge(bob)
+        );
+    }
+
+    #[rstest]
+    #[case::same(person("Alice", Some("alice@test.io")), person("Alice", Some("alice@test.io")), true)]
+    #[case::different_name(person("Alice", Some("alice@test.io")), person("Alicia", Some("alice@test.io")), false)]
+    #[case::different_email(person("Alice", Some("alice@test.io")), person("Alice", Some("alice@work.io")), false)]
+    #[case::differently_cased_email(person("Alice", Some("A@X.io")), person("Alice", Some("a@x.io")), true)]
+    #[case::differently_cased_name(person("Alice", Some("a@x.io")), person("alice", Some("a@x.io")), false)]
+    #[case::missing_email(person("Alice", Some("alice@test.io")), person("Alice", None), false)]
+    #[case::different_relationship(
+        person("Alice", None),
+        Person::builder("Alice").relationship("Reviewed-by").build().expect("should have built a person"),
+        false
+    )]
+    fn test_compares_people(#[case] a: Person, #[case] b: Person, #[case] expect: bool) {
+        assert_eq!(expect, a == b);
+    }
+
+    #[test]
+    fn test_hashes_differently_cased_emails_the_same() {
+        let people: HashSet<Person> = [
+            person("Alice", Some("A@X.io")),
+            person("Alice", Some("a@x.io")),
+            person("Alice", None),
+            person("Bob", Some("a@x.io")),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(3, people.len());
+        assert!(people.contains(&person("Alice", Some("a@X.IO"))));
+    }
+
     #[rstest]
     #[case::name_only(Person::builder("Alice Bob").build().expect("should have built a person"), "Co-Authored-By: Alice Bob")]
     #[case::name_and_email(Person::builder("Alice Bob").email("alice.bob@test.io").build().expect("should have built a person"), "Co-Authored-By: Alice Bob <alice.bob@test.io>")]
@@ -238,6 +738,104 @@ mod test {
         assert_eq!(expect.into(), format!("{person}"));
     }
 
+    #[rstest]
+    #[case::default_relationship(None, "Co-Authored-By: Alice <alice@test.io>", "Co-authored-by: Alice <alice@test.io>")]
+    #[case::canonical(Some("Co-authored-by"), "Co-authored-by: Alice <alice@test.io>", "Co-authored-by: Alice <alice@test.io>")]
+    #[case::uppercase(Some("SIGNED-OFF-BY"), "SIGNED-OFF-BY: Alice <alice@test.io>", "Signed-off-by: Alice <alice@test.io>")]
+    #[case::lowercase(Some("reviewed-by"), "reviewed-by: Alice <alice@test.io>", "Reviewed-by: Alice <alice@test.io>")]
+    #[case::freeform(Some("Reviewer"), "Reviewer: Alice <alice@test.io>", "Reviewer: Alice <alice@test.io>")]
+    #[case::freeform_casing(Some("PAIRED-WITH"), "PAIRED-WITH: Alice <alice@test.io>", "PAIRED-WITH: Alice <alice@test.io>")]
+    fn test_normalizes_relationship(#[case] relationship: Option<&str>, #[case] expect: &str, #[case] expect_normalized: &str) {
+        let mut builder = Person::builder("Alice");
+        builder.email("alice@test.io");
+        if let Some(relationship) = relationship {
+            builder.relationship(relationship);
+        }
+        let person = builder.build().expect("should have built a person");
+
+        assert_eq!(expect, person.to_string());
+        assert_eq!(expect_normalized, person.normalized().to_string());
+    }
+
+    #[rstest]
+    #[case::name_only("Alice Bob", "Alice Bob", None)]
+    #[case::name_and_email("Alice Bob <alice@bob.io>", "Alice Bob", Some("alice@bob.io"))]
+    #[case::surrounding_white_space("  Alice Bob  < alice@bob.io >  ", "Alice Bob", Some("alice@bob.io"))]
+    fn test_builds_person_from_spec(#[case] spec: &str, #[case] name: &str, #[case] email: Option<&str>) {
+        let person = Person::builder("Someone")
+            .email("someone@test.io")
+            .from_spec(spec)
+            .build()
+            .expect("should have built the person");
+
+        assert_eq!(name, person.name());
+        assert_eq!(email, person.email());
+        assert_eq!(DEFAULT_RELATIONSHIP, person.relationship());
+    }
+
+    #[rstest]
+    #[case::unclosed_bracket("Alice Bob <alice@bob.io")]
+    #[case::unopened_bracket("Alice Bob alice@bob.io>")]
+    #[case::text_after_email("Alice <alice@bob.io> Bob")]
+    #[case::nested_brackets("Alice <<alice@bob.io>>")]
+    #[case::invalid_email("Alice <invalid>")]
+    fn test_reports_malformed_spec_when_building(#[case] spec: &str) {
+        let errs = Person::builder("").from_spec(spec).build().expect_err("should have failed");
+        assert!(
+            errs.iter().all(|e| matches!(e, ValidationError::InvalidFieldValue(field, _) if field == "email")),
+            "unexpected errors: {errs}"
+        );
+    }
+
+    #[test]
+    fn test_reports_missing_name_in_spec_when_building() {
+        assert_eq!(
+            multi_error!(ValidationError::MissingRequiredField("name".into())),
+            Person::builder("Alice").from_spec("<alice@bob.io>").build().expect_err("should have failed")
+        );
+    }
+
+    #[rstest]
+    #[case::name_only("Alice Bob", Person::builder("Alice Bob").build().unwrap())]
+    #[case::name_and_email("Alice Bob <alice@bob.io>", Person::builder("Alice Bob").email("alice@bob.io").build().unwrap())]
+    #[case::surrounding_white_space("  Alice Bob  < alice@bob.io >  ", Person::builder("Alice Bob").email("alice@bob.io").build().unwrap())]
+    #[case::relationship("Reviewed-by: Alice Bob <alice@bob.io>", Person::builder("Alice Bob").email("alice@bob.io").relationship("Reviewed-by").build().unwrap())]
+    #[case::relationship_without_email("Reviewed-by: Alice Bob", Person::builder("Alice Bob").relationship("Reviewed-by").build().unwrap())]
+    #[case::colon_in_name("Dr. Bob: the Builder", Person::builder("Dr. Bob: the Builder").build().unwrap())]
+    fn test_parses_person(#[case] input: &str, #[case] expect: Person) {
+        let person = input.parse::<Person>().expect("should have parsed the person");
+
+        assert_eq!(expect, person);
+        assert_eq!(expect.relationship(), person.relationship());
+    }
+
+    #[rstest]
+    #[case::name_only(Person::builder("Alice Bob").build().unwrap())]
+    #[case::email_and_relationship(Person::builder("Charlie Delta").email("charlie@delta.io").relationship("Reviewer").build().unwrap())]
+    fn test_person_round_trips_through_display(#[case] person: Person) {
+        assert_eq!(person, person.to_string().parse::<Person>().expect("should have parsed the person"));
+    }
+
+    #[rstest]
+    #[case::unclosed_bracket("Alice Bob <alice@bob.io", "'Alice Bob <alice@bob.io' must be formatted as '[<relationship>: ]<name>[ <<email>>]'")]
+    #[case::unopened_bracket("Alice Bob alice@bob.io>", "'Alice Bob alice@bob.io>' must be formatted as '[<relationship>: ]<name>[ <<email>>]'")]
+    #[case::text_after_email("Alice <alice@bob.io> Bob", "'Alice <alice@bob.io> Bob' must be formatted as '[<relationship>: ]<name>[ <<email>>]'")]
+    #[case::nested_brackets("Alice <<alice@bob.io>>", "'Alice <<alice@bob.io>>' must be formatted as '[<relationship>: ]<name>[ <<email>>]'")]
+    fn test_rejects_malformed_person(#[case] input: &str, #[case] reason: &str) {
+        assert_eq!(
+            multi_error!(ValidationError::InvalidFieldValue("person".into(), anyhow!("{reason}"))),
+            input.parse::<Person>().expect_err("should have failed")
+        );
+    }
+
+    #[rstest]
+    #[case::empty("", multi_error!(ValidationError::MissingRequiredField("name".into())))]
+    #[case::email_only("<alice@bob.io>", multi_error!(ValidationError::MissingRequiredField("name".into())))]
+    #[case::invalid_email("Alice Bob <invalid>", multi_error!(ValidationError::InvalidFieldValue("email".into(), EmailError::MissingSeparator.into())))]
+    fn test_rejects_invalid_person(#[case] input: &str, #[case] expect: ValidationErrors) {
+        assert_eq!(expect, input.parse::<Person>().expect_err("should have failed"));
+    }
+
     #[rstest]
     #[case::name_only(Person::builder("Alice Bob").build().expect("should have built a person"), "Alice Bob")]
     #[case::name_and_email(Person::builder("Alice Bob").email("alice.bob@test.io").build().expect("should have built a person"), "Alice Bob")]
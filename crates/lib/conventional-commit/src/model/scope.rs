@@ -0,0 +1,151 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Scope representation for conventional commits.
+//!
+//! The scope is the optional section of the codebase a commit affects, written in parentheses after the type,
+//! ex: `parser` in `feat(parser): add lexer`. Teams touching several sections at once may list them separated by commas,
+//! ex: `feat(parser,lexer): add tokens`.
+
+use crate::model::ValidationError;
+use anyhow::anyhow;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The scope of a conventional commit, ex: `parser`, or a comma-separated list of scopes, ex: `parser,lexer`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Scope {
+    text: String,
+    scopes: Vec<String>,
+}
+
+impl Scope {
+    /// Returns the scope as it appears between the parentheses, with the scopes of a list joined by commas.
+    ///
+    /// # Returns
+    /// The scope as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the scopes of the list, ex: `parser` and `lexer` for `parser, lexer`.
+    ///
+    /// # Returns
+    /// The scopes in the order they were written, a single one for a scope without commas.
+    #[must_use]
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+/// Implementation of the `FromStr` trait for `Scope`.
+///
+/// This implementation validates that:
+/// - The scope is not blank
+/// - The scope does not contain parentheses or line breaks
+/// - No scope of a comma-separated list is blank
+///
+/// White-space around each scope of a list is trimmed.
+impl FromStr for Scope {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ValidationError::MissingRequiredField("scope".into()));
+        }
+
+        if s.contains(['(', ')', '\n', '\r']) {
+            return Err(ValidationError::InvalidFieldValue(
+                "scope".into(),
+                anyhow!("'{s}' must not contain parentheses or line breaks"),
+            ));
+        }
+
+        let scopes: Vec<String> = s.split(',').map(|scope| scope.trim().to_string()).collect();
+        if scopes.iter().any(String::is_empty) {
+            return Err(ValidationError::InvalidFieldValue(
+                "scope".into(),
+                anyhow!("'{s}' must not contain empty scopes between commas"),
+            ));
+        }
+
+        Ok(Scope { text: scopes.join(","), scopes })
+    }
+}
+
+/// Borrows the scope as [`Scope::as_str`] does, for APIs taking `impl AsRef<str>`.
+///
+/// This is an infallible view of a scope validated when it was parsed, not a way to parse one.
+///
+/// # Examples
+///
+/// ```
+/// # use conventional_commit::model::Scope;
+/// let scope: Scope = "parser, lexer".parse().unwrap();
+///
+/// assert_eq!(scope.as_ref(), "parser,lexer");
+/// ```
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::word("parser")]
+    #[case::hyphenated("conventional-commit")]
+    #[case::path("model/person")]
+    fn test_parses_scope(#[case] input: &str) {
+        let scope = input.parse::<Scope>().expect("should have parsed the scope");
+        assert_eq!(input, scope.as_str());
+        assert_eq!(input, AsRef::<str>::as_ref(&scope));
+        assert_eq!(input, format!("{scope}"));
+    }
+
+    #[rstest]
+    #[case::single("parser", "parser", vec!["parser"])]
+    #[case::list("parser,lexer", "parser,lexer", vec!["parser", "lexer"])]
+    #[case::spaced_list("parser, lexer ,  model/person", "parser,lexer,model/person", vec!["parser", "lexer", "model/person"])]
+    fn test_parses_scope_list(#[case] input: &str, #[case] expect: &str, #[case] scopes: Vec<&str>) {
+        let scope = input.parse::<Scope>().expect("should have parsed the scope");
+        assert_eq!(expect, format!("{scope}"));
+        assert_eq!(scopes, scope.scopes());
+    }
+
+    #[rstest]
+    #[case::empty("", ValidationError::MissingRequiredField("scope".into()))]
+    #[case::blank("  ", ValidationError::MissingRequiredField("scope".into()))]
+    #[case::parenthesis("a(b", ValidationError::InvalidFieldValue("scope".into(), anyhow!("'a(b' must not contain parentheses or line breaks")))]
+    #[case::line_break("a\nb", ValidationError::InvalidFieldValue("scope".into(), anyhow!("'a\nb' must not contain parentheses or line breaks")))]
+    #[case::empty_between_commas("a,,b", ValidationError::InvalidFieldValue("scope".into(), anyhow!("'a,,b' must not contain empty scopes between commas")))]
+    #[case::blank_between_commas("a, ,b", ValidationError::InvalidFieldValue("scope".into(), anyhow!("'a, ,b' must not contain empty scopes between commas")))]
+    #[case::trailing_comma("a,", ValidationError::InvalidFieldValue("scope".into(), anyhow!("'a,' must not contain empty scopes between commas")))]
+    fn test_rejects_invalid_scope(#[case] input: &str, #[case] expect: ValidationError) {
+        assert_eq!(expect, input.parse::<Scope>().expect_err("should have failed"));
+    }
+}
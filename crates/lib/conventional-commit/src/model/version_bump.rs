@@ -0,0 +1,35 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Semantic versioning impact of conventional commits.
+
+/// The semantic version component a commit requires to be incremented.
+///
+/// Variants are ordered by impact, from `None` to `Major`, so the bump for a release is the maximum over its commits:
+///
+/// ```
+/// # use conventional_commit::model::VersionBump;
+/// let release = [VersionBump::Patch, VersionBump::Minor, VersionBump::None].into_iter().max();
+/// assert_eq!(release, Some(VersionBump::Minor));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VersionBump {
+    /// The commit does not affect the released version, ex: `docs` or `chore`.
+    None,
+    /// The commit fixes a bug, ex: `fix`.
+    Patch,
+    /// The commit adds a feature, ex: `feat`.
+    Minor,
+    /// The commit introduces a breaking change.
+    Major,
+}
@@ -0,0 +1,136 @@
+/*
+ * Git Toolkit extends Git's user experience to be more friendly while integrating with conventional commits specification
+ * Copyright (c) 2025 Pierre Fouilloux, Hibiscus Collective
+ *
+ * This program is free software: you can redistribute it and/or modify it under the terms of the GNU Affero General Public License as published by the Free Software Foundation, either version 3 of the License, or (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.
+ * See the GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along with this program.
+ * If not, see https://www.gnu.org/licenses/.
+ */
+
+//! Helpers for the scissors line git writes in verbose commit messages.
+//!
+//! When committing with `git commit --verbose`, git appends the diff to the message file below a cut line. Everything
+//! from that line onwards is discarded by git, so tools editing the message must leave it alone:
+//!
+//! ```text
+//! feat: add lexer
+//!
+//! # ------------------------ >8 ------------------------
+//! # Do not modify or remove the line above.
+//! # Everything below it will be ignored.
+//! diff --git a/src/lexer.rs b/src/lexer.rs
+//! ```
+
+/// The cut line git uses to separate the message from the verbose diff.
+pub const SCISSORS: &str = "# ------------------------ >8 ------------------------";
+
+/// The guidance git writes below the cut line.
+pub const GUIDANCE: &str = "# Do not modify or remove the line above.\n# Everything below it will be ignored.\n";
+
+/// Splits a message at the scissors line.
+///
+/// # Arguments
+/// * `message` - The content of the commit message file.
+///
+/// # Returns
+/// A tuple of the message above the scissors line, and the scissors section starting at the cut line if there is one.
+/// Concatenating both parts gives back the original message.
+///
+/// # Examples
+///
+/// ```
+/// use conventional_commit::scissors;
+///
+/// let message = "feat: add lexer\n# ------------------------ >8 ------------------------\ndiff\n";
+///
+/// assert_eq!(scissors::split(message), ("feat: add lexer\n", Some("# ------------------------ >8 ------------------------\ndiff\n")));
+/// assert_eq!(scissors::split("feat: add lexer\n"), ("feat: add lexer\n", None));
+/// ```
+#[must_use]
+pub fn split(message: &str) -> (&str, Option<&str>) {
+    let mut offset = 0;
+
+    for line in message.split_inclusive('\n') {
+        if line.trim_end_matches(['\n', '\r']) == SCISSORS {
+            return (&message[..offset], Some(&message[offset..]));
+        }
+        offset += line.len();
+    }
+
+    (message, None)
+}
+
+/// Removes the scissors section from a message.
+///
+/// # Arguments
+/// * `message` - The content of the commit message file.
+///
+/// # Returns
+/// The message above the scissors line, or the whole message if there is none.
+#[must_use]
+pub fn strip(message: &str) -> &str {
+    split(message).0
+}
+
+/// Appends a scissors section to a message.
+///
+/// A line break is added before the cut line if the message does not already end with one.
+///
+/// # Arguments
+/// * `message` - The commit message.
+/// * `below` - The content to place below the cut line and its guidance, ex: the verbose diff.
+///
+/// # Returns
+/// The message followed by the cut line, the guidance and `below`.
+#[must_use]
+pub fn insert(message: &str, below: &str) -> String {
+    let separator = if message.is_empty() || message.ends_with('\n') { "" } else { "\n" };
+    format!("{message}{separator}{SCISSORS}\n{GUIDANCE}{below}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    const DIFF: &str = "diff --git a/src/lexer.rs b/src/lexer.rs\n+pub fn lex() {}\n";
+
+    #[rstest]
+    #[case::header_only("feat: add lexer\n")]
+    #[case::body_and_footers("feat: add lexer\n\nSplits the header.\n\nRefs: PROJ-1\n")]
+    #[case::with_comments("feat: add lexer\n# Please enter the commit message for your changes.\n")]
+    #[case::empty("")]
+    fn test_strips_inserted_scissors(#[case] message: &str) {
+        let with_scissors = insert(message, DIFF);
+
+        assert_eq!(message, strip(&with_scissors));
+        assert_eq!(Some(format!("{SCISSORS}\n{GUIDANCE}{DIFF}").as_str()), split(&with_scissors).1);
+    }
+
+    #[test]
+    fn test_inserts_line_break_before_scissors() {
+        assert_eq!(format!("feat: add lexer\n{SCISSORS}\n{GUIDANCE}{DIFF}"), insert("feat: add lexer", DIFF));
+    }
+
+    #[rstest]
+    #[case::no_scissors("feat: add lexer\n\nbody\n")]
+    #[case::scissors(&format!("feat: add lexer\n\n{SCISSORS}\n{GUIDANCE}{DIFF}"))]
+    #[case::crlf(&format!("feat: add lexer\r\n{SCISSORS}\r\n{DIFF}"))]
+    #[case::scissors_first(&format!("{SCISSORS}\n{DIFF}"))]
+    fn test_split_round_trips(#[case] message: &str) {
+        let (above, below) = split(message);
+        assert_eq!(message, format!("{above}{}", below.unwrap_or_default()));
+    }
+
+    #[rstest]
+    #[case::indented(&format!("feat: add lexer\n  {SCISSORS}\n"))]
+    #[case::inline(&format!("feat: add lexer {SCISSORS}\n"))]
+    fn test_ignores_scissors_not_on_their_own_line(#[case] message: &str) {
+        assert_eq!((message, None), split(message));
+    }
+}